@@ -0,0 +1,283 @@
+/*!
+Command line front-end to the library.
+
+Reads the given files (or the standard input) line by line and, depending on the mode:
+
+- replaces the numbers written in words by their digit representation (default);
+- prints the numbers found on each line as a JSON array (`--json`), one array per line;
+  each number is a [`TextOccurence`](text2num::TextOccurence) serialized as with the `serde` feature;
+- checks that each whole line is a valid number and prints its digits (`--check`).
+
+In check mode, the exit code is 1 if any line is not a number.
+*/
+
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::process::ExitCode;
+
+use text2num::{
+    ConversionOptions, Language, find_numbers_in_text, get_interpreter_for,
+    replace_numbers_in_text, text2digits,
+};
+
+const USAGE: &str = "\
+Usage: text2num --lang CODE [OPTIONS] [FILE]...
+
+Convert the numbers written in words in each line of FILEs (or of the standard input) into digits.
+
+Options:
+  -l, --lang CODE        language of the text (ar, ca, cs, da, de, el, en, en-IN, es, fr, hi,
+                         it, ja, nb/no, nl, pl, pt, ro, ru, sk, sv, tr, zh)
+  -t, --threshold VALUE  do not convert isolated numbers under VALUE (default 0)
+      --json             print the numbers found on each line as a JSON array
+      --check            check that each line is a number and print its digits;
+                         exit with code 1 if any is not
+  -h, --help             print this help
+";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    Replace,
+    Json,
+    Check,
+}
+
+#[derive(Debug, PartialEq)]
+struct Args {
+    lang: String,
+    threshold: f64,
+    mode: Mode,
+    files: Vec<String>,
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Option<Args>, String> {
+    let mut lang = None;
+    let mut threshold = 0.0;
+    let mut mode = Mode::Replace;
+    let mut files = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-l" | "--lang" => {
+                lang = Some(args.next().ok_or("missing language code")?);
+            }
+            "-t" | "--threshold" => {
+                let value = args.next().ok_or("missing threshold value")?;
+                threshold = value
+                    .parse()
+                    .map_err(|_| format!("invalid threshold: {value}"))?;
+            }
+            "--json" | "--check" if mode != Mode::Replace => {
+                return Err("--json and --check are exclusive".to_owned());
+            }
+            "--json" => mode = Mode::Json,
+            "--check" => mode = Mode::Check,
+            "-" => files.push(arg),
+            option if option.starts_with('-') => return Err(format!("unknown option: {option}")),
+            _ => files.push(arg),
+        }
+    }
+    Ok(Some(Args {
+        lang: lang.ok_or("the language is required")?,
+        threshold,
+        mode,
+        files,
+    }))
+}
+
+/// Process the lines of `input`. Return whether all lines are valid in check mode.
+fn process<R: BufRead, W: Write>(
+    input: R,
+    name: &str,
+    output: &mut W,
+    lang: &Language,
+    options: ConversionOptions,
+    mode: Mode,
+) -> io::Result<bool> {
+    let mut all_valid = true;
+    for (i, line) in input.lines().enumerate() {
+        let line = line?;
+        match mode {
+            Mode::Replace => writeln!(output, "{}", replace_numbers_in_text(&line, lang, options))?,
+            Mode::Json => {
+                serde_json::to_writer(&mut *output, &find_numbers_in_text(&line, lang, options))?;
+                writeln!(output)?;
+            }
+            Mode::Check => match text2digits(&line, lang) {
+                Ok(digits) => writeln!(output, "{digits}")?,
+                Err(error) => {
+                    all_valid = false;
+                    writeln!(output)?;
+                    eprintln!("{name}:{}: {error}: {line}", i + 1);
+                }
+            },
+        }
+    }
+    Ok(all_valid)
+}
+
+fn run(args: Args) -> io::Result<bool> {
+    let lang = get_interpreter_for(&args.lang).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("unsupported language: {}", args.lang),
+        )
+    })?;
+    let options = ConversionOptions::from(args.threshold);
+    let stdout = io::stdout();
+    let mut output = BufWriter::new(stdout.lock());
+    let mut all_valid = true;
+    if args.files.is_empty() {
+        all_valid = process(
+            io::stdin().lock(),
+            "<stdin>",
+            &mut output,
+            &lang,
+            options,
+            args.mode,
+        )?;
+    }
+    for path in &args.files {
+        let valid = if path == "-" {
+            process(
+                io::stdin().lock(),
+                "<stdin>",
+                &mut output,
+                &lang,
+                options,
+                args.mode,
+            )?
+        } else {
+            let file = File::open(path)
+                .map_err(|error| io::Error::new(error.kind(), format!("{path}: {error}")))?;
+            process(
+                BufReader::new(file),
+                path,
+                &mut output,
+                &lang,
+                options,
+                args.mode,
+            )?
+        };
+        all_valid &= valid;
+    }
+    output.flush()?;
+    Ok(all_valid)
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            print!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("text2num: {message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match run(args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("text2num: {error}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use text2num::TextOccurence;
+
+    fn args(line: &str) -> Result<Option<Args>, String> {
+        parse_args(line.split_whitespace().map(str::to_owned))
+    }
+
+    fn run_lines(input: &str, mode: Mode, threshold: f64) -> (String, bool) {
+        let mut output = Vec::new();
+        let valid = process(
+            input.as_bytes(),
+            "test",
+            &mut output,
+            &Language::english(),
+            threshold.into(),
+            mode,
+        )
+        .unwrap();
+        (String::from_utf8(output).unwrap(), valid)
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            args("-l fr --threshold 10 --json a.txt b.txt"),
+            Ok(Some(Args {
+                lang: "fr".to_owned(),
+                threshold: 10.0,
+                mode: Mode::Json,
+                files: vec!["a.txt".to_owned(), "b.txt".to_owned()],
+            }))
+        );
+        assert_eq!(args("--lang en --help"), Ok(None));
+        assert!(args("--check").is_err());
+        assert!(args("-l en --json --check").is_err());
+        assert!(args("-l en -t ten").is_err());
+        assert!(args("-l en --frobnicate").is_err());
+    }
+
+    #[test]
+    fn test_replace() {
+        let (output, valid) = run_lines(
+            "I have two apples\nand twenty-one pears\n",
+            Mode::Replace,
+            10.0,
+        );
+        assert_eq!(output, "I have two apples\nand 21 pears\n");
+        assert!(valid);
+    }
+
+    #[test]
+    fn test_json() {
+        let input = "twenty-one \"pears\"\nnothing\nthe third, one point five";
+        let (output, _) = run_lines(input, Mode::Json, 0.0);
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(
+            lines[0],
+            serde_json::json!([{"start": 0, "end": 1, "text": "21", "value": 21.0, "exact_value": "21",
+                                "numerator": null, "denominator": null, "is_ordinal": false, "kind": "cardinal",
+                                "byte_start": 0, "byte_end": 10, "char_start": 0, "char_end": 10}])
+        );
+        assert_eq!(lines[1], serde_json::json!([]));
+        assert_eq!(lines[2][0]["kind"], "ordinal");
+        assert_eq!(lines[2][1]["text"], "1.5");
+        // The same shape as the serialized occurences of the library
+        for (line, text) in output.lines().zip(input.lines()) {
+            let occurences: Vec<TextOccurence> = serde_json::from_str(line).unwrap();
+            let english = Language::english();
+            assert_eq!(
+                occurences,
+                find_numbers_in_text(text, &english, ConversionOptions::from(0.0))
+            );
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let (output, valid) = run_lines("one hundred fifty-seven\nforty two", Mode::Check, 0.0);
+        assert_eq!(output, "157\n42\n");
+        assert!(valid);
+        let (output, valid) = run_lines("twenty twelve\nseven", Mode::Check, 0.0);
+        assert_eq!(output, "\n7\n");
+        assert!(!valid);
+        let (output, valid) = run_lines("forty two\n\nseven", Mode::Check, 0.0);
+        assert_eq!(output, "42\n\n7\n");
+        assert!(!valid);
+    }
+}
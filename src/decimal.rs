@@ -0,0 +1,264 @@
+//! Exact numeric values
+//!
+//! A [`Decimal`] is an arbitrary-precision base 10 number made of an integer mantissa and a scale
+//! (the number of digits after the decimal point), so that nothing is lost from the spoken digits.
+
+use std::fmt;
+use std::ops::Neg;
+use std::str::FromStr;
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+
+/// Exact value of a number: `mantissa × 10^-scale`.
+///
+/// Trailing decimal zeroes are significant and kept: "*one point five zero*" has scale 2,
+/// so `1.5` and `1.50` are not equal as `Decimal`s even though they have the same numeric value.
+///
+/// With the `serde` feature, a `Decimal` is serialized as its string representation, like `"-12.50"`,
+/// so that no precision is lost.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    negative: bool,
+    /// ASCII digits, without leading zeroes (except for zero itself).
+    digits: String,
+    scale: usize,
+}
+
+impl Decimal {
+    /// Build the exact value of the number whose integral part is `int` and decimal part is `dec`.
+    ///
+    /// `dec` may be empty, in which case the value is an integer.
+    pub fn from_parts(int: &DigitString, dec: &DigitString) -> Self {
+        let mut digits = int.to_string();
+        let decimals = dec.to_string();
+        digits.push_str(&decimals);
+        Self::new(false, digits, decimals.len())
+    }
+
+    /// Exact value of the fraction `numerator`/`denominator`, if it has a finite base 10 representation.
+    ///
+    /// That is the case when the denominator has no other prime factors than 2 and 5, once the fraction reduced.
+    pub fn from_fraction(numerator: u128, denominator: u128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let divisor = gcd(numerator, denominator);
+        let (numerator, mut denominator) = (numerator / divisor, denominator / divisor);
+        let (mut twos, mut fives) = (0, 0);
+        while denominator % 2 == 0 {
+            denominator /= 2;
+            twos += 1;
+        }
+        while denominator % 5 == 0 {
+            denominator /= 5;
+            fives += 1;
+        }
+        if denominator != 1 {
+            return None;
+        }
+        // numerator / (2^twos × 5^fives) = numerator × 2^(scale - twos) × 5^(scale - fives) / 10^scale
+        let scale: u32 = twos.max(fives);
+        let mantissa = numerator
+            .checked_mul(2u128.checked_pow(scale - twos)?)?
+            .checked_mul(5u128.checked_pow(scale - fives)?)?;
+        Some(Self::new(false, mantissa.to_string(), scale as usize))
+    }
+
+    fn new(negative: bool, mut digits: String, scale: usize) -> Self {
+        let leading = digits.bytes().take_while(|&c| c == b'0').count();
+        digits.drain(..leading);
+        if digits.is_empty() {
+            digits.push('0');
+        }
+        Self {
+            negative: negative && digits != "0",
+            digits,
+            scale,
+        }
+    }
+
+    /// The base 10 digits of the mantissa, without sign nor leading zeroes.
+    pub fn mantissa(&self) -> &str {
+        &self.digits
+    }
+
+    /// The number of digits after the decimal point.
+    pub fn scale(&self) -> usize {
+        self.scale
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.digits[self.digits.len().saturating_sub(self.scale)..]
+            .bytes()
+            .all(|c| c == b'0')
+    }
+
+    /// Return the value as an `i128` if it is an integer that fits.
+    pub fn to_i128(&self) -> Option<i128> {
+        if !self.is_integer() {
+            return None;
+        }
+        let int_len = self.digits.len().saturating_sub(self.scale);
+        let int: i128 = if int_len == 0 {
+            0
+        } else {
+            self.digits[..int_len].parse().ok()?
+        };
+        Some(if self.negative { -int } else { int })
+    }
+
+    /// Approximate value as a floating point number.
+    pub fn to_f64(&self) -> f64 {
+        self.to_string().parse().unwrap()
+    }
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        if self.scale == 0 {
+            return f.write_str(&self.digits);
+        }
+        let padded = if self.digits.len() <= self.scale {
+            format!(
+                "{}{}",
+                "0".repeat(self.scale + 1 - self.digits.len()),
+                self.digits
+            )
+        } else {
+            self.digits.clone()
+        };
+        let (int, dec) = padded.split_at(padded.len() - self.scale);
+        write!(f, "{int}.{dec}")
+    }
+}
+
+impl Neg for Decimal {
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        self.negative = !self.negative && self.digits != "0";
+        self
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = Error;
+
+    /// Parse a plain decimal number like `-12.50`, using `.` as decimal separator.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (int, dec) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        if int.is_empty() || !int.bytes().chain(dec.bytes()).all(|c| c.is_ascii_digit()) {
+            return Err(Error::NaN);
+        }
+        Ok(Self::new(negative, format!("{int}{dec}"), dec.len()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Decimal {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Decimal {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = String::deserialize(deserializer)?;
+        repr.parse()
+            .map_err(|_| serde::de::Error::custom(format!("invalid decimal number: {repr}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_parts() -> Result<(), Error> {
+        let mut int = DigitString::new();
+        let mut dec = DigitString::new();
+        int.put(b"0")?;
+        int.put(b"0")?;
+        int.put(b"34")?;
+        let value = Decimal::from_parts(&int, &dec);
+        assert_eq!(value.to_string(), "34");
+        assert_eq!(value.to_i128(), Some(34));
+        dec.put(b"0")?;
+        dec.put(b"5")?;
+        let value = Decimal::from_parts(&int, &dec);
+        assert_eq!(value.to_string(), "34.05");
+        assert_eq!(value.scale(), 2);
+        assert_eq!(value.to_i128(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_fraction() {
+        let value = Decimal::from_fraction(5, 2).unwrap();
+        assert_eq!(value.to_string(), "2.5");
+        assert_eq!(Decimal::from_fraction(3, 4).unwrap().to_string(), "0.75");
+        assert_eq!(Decimal::from_fraction(6, 3).unwrap().to_string(), "2");
+        assert_eq!(
+            Decimal::from_fraction(1, 128).unwrap().to_string(),
+            "0.0078125"
+        );
+        assert!(Decimal::from_fraction(1, 3).is_none());
+        assert!(Decimal::from_fraction(1, 0).is_none());
+    }
+
+    #[test]
+    fn test_small_decimals() {
+        let value: Decimal = "0.005".parse().unwrap();
+        assert_eq!(value.mantissa(), "5");
+        assert_eq!(value.to_string(), "0.005");
+        assert_eq!(value.to_f64(), 0.005);
+    }
+
+    #[test]
+    fn test_precision() {
+        let value: Decimal = "53000243724000000000001".parse().unwrap();
+        assert_eq!(value.to_string(), "53000243724000000000001");
+        assert_eq!(value.to_i128(), Some(53000243724000000000001));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let value: Decimal = "-12.50".parse().unwrap();
+        let repr = serde_json::to_string(&value).unwrap();
+        assert_eq!(repr, r#""-12.50""#);
+        assert_eq!(serde_json::from_str::<Decimal>(&repr).unwrap(), value);
+        assert!(serde_json::from_str::<Decimal>(r#""1.2.3""#).is_err());
+    }
+
+    #[test]
+    fn test_sign() {
+        let value: Decimal = "-1.50".parse().unwrap();
+        assert!(value.is_negative());
+        assert_eq!(value.to_string(), "-1.50");
+        assert_eq!((-value).to_string(), "1.50");
+        assert!(!"-0".parse::<Decimal>().unwrap().is_negative());
+        assert!("1.2.3".parse::<Decimal>().is_err());
+        assert!(".5".parse::<Decimal>().is_err());
+    }
+}
@@ -5,12 +5,13 @@
 //!
 //! Everywhere, the term `position` refers to decimal positions: 0 is units, 1 is tens, etc…
 
+use std::fmt;
 use std::ops::Deref;
 
 use super::error::Error;
 use super::lang::MorphologicalMarker;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DigitString {
     buffer: Vec<u8>,
     leading_zeroes: usize,
@@ -179,6 +180,11 @@ impl DigitString {
         self.buffer.is_empty()
     }
 
+    /// check for leading zeroes before a significant digit, like in "007".
+    pub fn has_leading_zeroes(&self) -> bool {
+        (self.leading_zeroes > 0 && !self.buffer.is_empty()) || self.leading_zeroes > 1
+    }
+
     pub fn len(&self) -> usize {
         self.buffer.len() + self.leading_zeroes
     }
@@ -223,19 +229,22 @@ impl DigitString {
         }
     }
 
-    /// Formal base 10 string representation with leading zeroes
-    pub fn to_string(&self) -> String {
-        // we know that the string is valid.
-        let mut res = "0".repeat(self.leading_zeroes);
-        res.push_str(std::str::from_utf8(self.buffer.as_slice()).unwrap());
-        res
-    }
-
     pub fn is_ordinal(&self) -> bool {
         self.marker.is_ordinal()
     }
 }
 
+/// Formal base 10 string representation with leading zeroes
+impl fmt::Display for DigitString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // we know that the string is valid.
+        for _ in 0..self.leading_zeroes {
+            f.write_str("0")?;
+        }
+        f.write_str(std::str::from_utf8(self.buffer.as_slice()).unwrap())
+    }
+}
+
 impl Deref for DigitString {
     type Target = [u8];
 
@@ -380,6 +389,25 @@ mod tests {
         assert!(builder.shift(2).is_err());
     }
 
+    #[test]
+    fn test_shift_myriad() -> Result<(), Error> {
+        let mut builder = DigitString::new();
+        builder.fput(b"23")?;
+        builder.shift(4)?;
+        assert_eq!(builder.peek(6), b"230000");
+        builder.fput(b"4005")?;
+        assert!(builder.shift(4).is_err());
+        let mut builder = DigitString::new();
+        builder.fput(b"100002000")?;
+        builder.shift(4)?;
+        assert_eq!(builder.to_string(), "120000000");
+        let mut builder = DigitString::new();
+        builder.fput(b"100000005")?;
+        builder.shift(4)?;
+        assert_eq!(builder.to_string(), "100050000");
+        Ok(())
+    }
+
     #[test]
     fn test_shift_empty() {
         let mut builder = DigitString::new();
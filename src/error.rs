@@ -1,4 +1,11 @@
-#[derive(Debug)]
+//! Errors
+
+use std::fmt;
+
+use crate::digit_string::DigitString;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Number recognition errors.
 /// They are mostly used internally by the high level API.
 pub enum Error {
@@ -12,3 +19,55 @@ pub enum Error {
     /// The currently decoded number is complete and we don't accept new data
     Frozen,
 }
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Overlap => "overlaps the number being decoded",
+            Self::NaN => "not a number",
+            Self::Incomplete => "incomplete number",
+            Self::Frozen => "the number is already complete",
+        })
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Failure of [`text2digits`](crate::text2digits), with the context of the failure.
+#[derive(Debug, Clone)]
+pub struct ParseError {
+    /// The cause of the failure
+    pub error: Error,
+    /// The index of the offending word among the words of the text, or the number of words
+    /// if the text ended before the number was complete.
+    pub index: usize,
+    /// The offending word, lowercased, if any
+    pub word: Option<String>,
+    /// The number as it was decoded when the failure occurred
+    pub state: DigitString,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.word {
+            Some(word) => write!(f, "{} at word {} \"{word}\"", self.error, self.index)?,
+            None => write!(f, "{} after {} words", self.error, self.index)?,
+        }
+        if !self.state.is_empty() {
+            write!(f, " (decoded so far: {})", self.state)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        error.error
+    }
+}
@@ -0,0 +1,410 @@
+//! Arabic number interpreter
+//!
+//! Numbers are understood both in the Arabic script, with or without the diacritics, and in the common
+//! transliterations output by the speech-to-text engines.
+//!
+//! The units come before the tens, joined by the conjunction "*و*" that is written as a prefix: "*خمسة وعشرون*" is 25.
+//! The numbers from 3 to 10 take the opposite gender of the counted noun, so both "*ثلاثة*" and "*ثلاث*" are 3, and the
+//! dual has its own words: "*ألفان*" is 2000.
+//!
+//! The digits are Western by default, but the interpreter can output the Eastern Arabic ones, like "٢٥".
+
+use std::borrow::Cow;
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+
+mod vocabulary;
+
+use super::{LangInterpreter, MorphologicalMarker};
+use vocabulary::INSIGNIFICANT;
+
+#[derive(Default)]
+pub struct Arabic {
+    eastern_digits: bool,
+}
+
+impl Arabic {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Whether the Eastern Arabic digits are output, like "٢٥" for 25 (default `false`).
+    pub fn eastern_digits(mut self, yes: bool) -> Self {
+        self.eastern_digits = yes;
+        self
+    }
+
+    fn digits(&self, repr: &str) -> String {
+        if self.eastern_digits {
+            repr.chars()
+                .map(|c| match c {
+                    '0'..='9' => char::from_u32(c as u32 - '0' as u32 + 0x660).unwrap(),
+                    '.' => '٫',
+                    c => c,
+                })
+                .collect()
+        } else {
+            repr.to_owned()
+        }
+    }
+}
+
+/// The meaning of a number word.
+#[derive(Debug, Clone, Copy)]
+enum Numeral {
+    Zero,
+    /// A digit from 1 to 9, in either gender
+    Unit(u8),
+    /// "*عشرة*", either 10 or the tens of the numbers from 11 to 19
+    Ten,
+    /// The tens digit, from 2 to 9
+    Tens(u8),
+    /// "*مائة*", either alone or multiplied by the unit before it
+    Hundred,
+    /// The hundreds written in a single word, like "*ثلاثمائة*", and the dual "*مائتان*"
+    Hundreds(u8),
+    /// "*ألف*", "*مليون*" or "*مليار*", with their position, and whether they are the plural that needs a count
+    Multiplier(usize, bool),
+    /// The dual of the multipliers, like "*ألفان*", with their position
+    Dual(usize),
+}
+
+/// The conjunction "*و*" was just read.
+const CONJUNCTION: u64 = 1;
+/// The last word was a unit, that may be the count of the next multiplier, or the units of a number from 11 to 19.
+const UNIT: u64 = 1 << 1;
+
+/// Drop the diacritics and the tatweel, and unify the spelling of the alif, the ta marbuta and the alif maqsura.
+fn normalize(word: &str) -> Cow<'_, str> {
+    if !word.chars().any(|c| {
+        matches!(
+            c,
+            '\u{640}' | '\u{64b}'..='\u{65f}' | '\u{670}' | 'أ' | 'إ' | 'آ' | 'ٱ' | 'ة' | 'ى'
+        )
+    }) {
+        return Cow::Borrowed(word);
+    }
+    let normalized = word
+        .chars()
+        .filter_map(|c| match c {
+            '\u{640}' | '\u{64b}'..='\u{65f}' | '\u{670}' => None,
+            'أ' | 'إ' | 'آ' | 'ٱ' => Some('ا'),
+            'ة' => Some('ه'),
+            'ى' => Some('ي'),
+            c => Some(c),
+        })
+        .collect();
+    Cow::Owned(normalized)
+}
+
+/// The meaning of the normalized `word`.
+fn numeral(word: &str) -> Option<Numeral> {
+    let numeral = match word {
+        "صفر" | "sifr" => Numeral::Zero,
+        "واحد" | "واحده" | "احد" | "احدي" | "wahid" | "wahed" | "wahida" | "wahda" | "ahad"
+        | "ihda" => Numeral::Unit(b'1'),
+        "اثنان" | "اثنين" | "اثنتان" | "اثنتين" | "اثنا" | "اثني" | "اثنتا" | "اثنتي"
+        | "ithnan" | "ithnayn" | "ithnain" | "itnen" | "ithna" | "ithnay" => Numeral::Unit(b'2'),
+        "ثلاثه" | "ثلاث" | "thalatha" | "thalath" | "talata" => Numeral::Unit(b'3'),
+        "اربعه" | "اربع" | "arbaa" | "arba'a" | "arba" => Numeral::Unit(b'4'),
+        "خمسه" | "خمس" | "khamsa" | "khams" => Numeral::Unit(b'5'),
+        "سته" | "ست" | "sitta" | "sitt" => Numeral::Unit(b'6'),
+        "سبعه" | "سبع" | "sabaa" | "sab'a" | "saba" => Numeral::Unit(b'7'),
+        "ثمانيه" | "ثماني" | "ثمان" | "thamaniya" | "thamani" | "thaman" | "tamanya" => {
+            Numeral::Unit(b'8')
+        }
+        "تسعه" | "تسع" | "tisaa" | "tis'a" | "tisa" => Numeral::Unit(b'9'),
+        "عشره" | "عشر" | "ashara" | "'ashara" | "ashar" | "ashr" => Numeral::Ten,
+        "عشرون" | "عشرين" | "ishrun" | "ishrin" | "ishreen" | "eshreen" => {
+            Numeral::Tens(b'2')
+        }
+        "ثلاثون" | "ثلاثين" | "thalathun" | "thalathin" | "talatin" => {
+            Numeral::Tens(b'3')
+        }
+        "اربعون" | "اربعين" | "arbaun" | "arbain" | "arba'un" | "arba'in" => {
+            Numeral::Tens(b'4')
+        }
+        "خمسون" | "خمسين" | "khamsun" | "khamsin" => Numeral::Tens(b'5'),
+        "ستون" | "ستين" | "sittun" | "sittin" => Numeral::Tens(b'6'),
+        "سبعون" | "سبعين" | "sabun" | "sabin" | "sab'un" | "sab'in" => {
+            Numeral::Tens(b'7')
+        }
+        "ثمانون" | "ثمانين" | "thamanun" | "thamanin" | "tamanin" => {
+            Numeral::Tens(b'8')
+        }
+        "تسعون" | "تسعين" | "tisun" | "tisin" | "tis'un" | "tis'in" => {
+            Numeral::Tens(b'9')
+        }
+        "مائه" | "مئه" | "mia" | "mi'a" | "miya" | "mayya" => Numeral::Hundred,
+        "مائتان" | "مائتين" | "مئتان" | "مئتين" | "mitan" | "miatan" | "mitayn" | "miatayn" => {
+            Numeral::Hundreds(b'2')
+        }
+        "الف" | "الفا" | "alf" | "alfa" => Numeral::Multiplier(3, false),
+        "الاف" | "alaf" | "aalaf" => Numeral::Multiplier(3, true),
+        "الفان" | "الفين" | "alfan" | "alfayn" | "alfain" => Numeral::Dual(3),
+        "مليون" | "مليونا" | "milyun" | "milyon" | "million" => {
+            Numeral::Multiplier(6, false)
+        }
+        "ملايين" | "malayin" | "malayeen" => Numeral::Multiplier(6, true),
+        "مليونان" | "مليونين" | "milyunan" | "milyunayn" => Numeral::Dual(6),
+        "مليار" | "مليارا" | "milyar" => Numeral::Multiplier(9, false),
+        "مليارات" | "milyarat" => Numeral::Multiplier(9, true),
+        "ملياران" | "مليارين" | "milyaran" | "milyarayn" => Numeral::Dual(9),
+        _ => {
+            // "ثلاثمائة"
+            let stem = word
+                .strip_suffix("مائه")
+                .or_else(|| word.strip_suffix("مئه"))?;
+            return match numeral(stem)? {
+                Numeral::Unit(digit) if digit >= b'3' => Some(Numeral::Hundreds(digit)),
+                _ => None,
+            };
+        }
+    };
+    Some(numeral)
+}
+
+/// Apply the `numeral`, possibly introduced by the conjunction.
+fn apply_numeral(numeral: Numeral, conjunction: bool, b: &mut DigitString) -> Result<(), Error> {
+    let flags = b.flags;
+    let conjunction = conjunction || flags & CONJUNCTION != 0;
+    let after_unit = flags & UNIT != 0 && !conjunction;
+    // A new term can only start the number, or follow the conjunction
+    let term = b.is_null() || conjunction;
+    // The count of a multiplier comes right before it
+    let count = !b.is_null() && !conjunction;
+    match numeral {
+        Numeral::Zero if b.is_empty() || (b.is_null() && !conjunction) => b.put(b"0"),
+        Numeral::Unit(digit) if term => b.put(&[digit]),
+        Numeral::Ten if after_unit => b.put_digit_at(b'1', 1),
+        Numeral::Ten if term => b.put(b"10"),
+        // The tens follow the units: "خمسة وعشرون"
+        Numeral::Tens(digit) if term => b.put_digit_at(digit, 1),
+        Numeral::Hundred if after_unit => b.shift(2),
+        Numeral::Hundred if term => b.put(b"100"),
+        Numeral::Hundreds(digit) if term => b.put(&[digit, b'0', b'0']),
+        Numeral::Multiplier(position, _) if count && b.is_range_free(position, position + 2) => {
+            b.shift(position)
+        }
+        Numeral::Multiplier(position, false) if term => {
+            let mut digits = vec![b'0'; position + 1];
+            digits[0] = b'1';
+            b.put(&digits)
+        }
+        Numeral::Dual(position) if term => {
+            let mut digits = vec![b'0'; position + 1];
+            digits[0] = b'2';
+            b.put(&digits)
+        }
+        _ => Err(Error::NaN),
+    }?;
+    b.flags = if matches!(numeral, Numeral::Unit(_)) {
+        UNIT
+    } else {
+        0
+    };
+    Ok(())
+}
+
+impl LangInterpreter for Arabic {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        let word = normalize(num_func);
+        let word = word.as_ref();
+        if let Some(numeral) = numeral(word) {
+            return apply_numeral(numeral, false, b);
+        }
+        if matches!(word, "و" | "wa") && !b.is_null() && b.flags & CONJUNCTION == 0 {
+            b.flags = CONJUNCTION;
+            return Err(Error::Incomplete);
+        }
+        // The conjunction is written as a prefix: "وعشرون"
+        if let Some(rest) = word.strip_prefix('و').or_else(|| word.strip_prefix("wa-"))
+            && let Some(numeral) = numeral(rest)
+            && !b.is_null()
+            && b.flags & CONJUNCTION == 0
+        {
+            return apply_numeral(numeral, true, b);
+        }
+        Err(Error::NaN)
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        self.apply(decimal_func, b)
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        match normalize(word).as_ref() {
+            "فاصله" | "fasila" | "fasla" => Some('.'),
+            _ => None,
+        }
+    }
+
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match normalize(word).as_ref() {
+            "سالب" | "ناقص" | "salib" | "naqis" => Some('-'),
+            "موجب" | "زائد" | "mujab" | "zaid" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn format_and_value(&self, b: &DigitString) -> (String, f64) {
+        let repr = b.to_string();
+        let val: f64 = repr.parse().unwrap();
+        (self.digits(&repr), val)
+    }
+
+    fn format_decimal_and_value(
+        &self,
+        int: &DigitString,
+        dec: &DigitString,
+        sep: char,
+    ) -> (String, f64) {
+        let irepr = int.to_string();
+        let drepr = dec.to_string();
+        let frepr = format!("{irepr}{sep}{drepr}");
+        let val = format!("{irepr}.{drepr}").parse().unwrap();
+        (self.digits(&frepr), val)
+    }
+
+    fn get_morph_marker(&self, _word: &str) -> MorphologicalMarker {
+        MorphologicalMarker::None
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Arabic;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Arabic::new();
+            let res = text2digits($text, &f);
+            dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Arabic::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Arabic::new();
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("صفر", "0");
+        assert_text2digits!("سبعة", "7");
+        assert_text2digits!("عشرة", "10");
+        assert_text2digits!("عشرون", "20");
+        assert_text2digits!("مائة", "100");
+        assert_text2digits!("ثلاثمائة", "300");
+        assert_text2digits!("ثلاث مائة", "300");
+        assert_text2digits!("ألف", "1000");
+        assert_text2digits!("ثلاثة آلاف", "3000");
+        assert_text2digits!("عشرة آلاف", "10000");
+        assert_text2digits!("مائة ألف", "100000");
+        assert_text2digits!("خمسة ملايين", "5000000");
+        assert_text2digits!("ألف وتسعمائة وأربعة وثمانون", "1984");
+    }
+
+    #[test]
+    fn test_reversed_tens() {
+        assert_text2digits!("خمسة وعشرون", "25");
+        assert_text2digits!("خمسة و عشرون", "25");
+        assert_text2digits!("واحد وثلاثون", "31");
+        assert_text2digits!("مائة وخمسة وعشرون", "125");
+        assert_text2digits!("مائتان وعشرون", "220");
+        assert_text2digits!("خمسة وعشرون ألف", "25000");
+    }
+
+    #[test]
+    fn test_teens() {
+        assert_text2digits!("أحد عشر", "11");
+        assert_text2digits!("إحدى عشرة", "11");
+        assert_text2digits!("اثنا عشر", "12");
+        assert_text2digits!("ثلاثة عشر", "13");
+        assert_text2digits!("ثلاث عشرة", "13");
+        assert_text2digits!("أحد عشر ألفا", "11000");
+    }
+
+    #[test]
+    fn test_dual() {
+        assert_text2digits!("مئتان", "200");
+        assert_text2digits!("ألفان", "2000");
+        assert_text2digits!("ألفين وخمسمائة", "2500");
+        assert_text2digits!("مليونان", "2000000");
+        assert_text2digits!("ملياران", "2000000000");
+    }
+
+    #[test]
+    fn test_gender_polarity() {
+        assert_text2digits!("ثلاثة", "3");
+        assert_text2digits!("ثلاث", "3");
+        assert_text2digits!("ثمانية", "8");
+        assert_text2digits!("ثماني", "8");
+        assert_text2digits!("سبع وعشرون", "27");
+    }
+
+    #[test]
+    fn test_spelling() {
+        assert_text2digits!("ثَلَاثَةٌ وَعِشْرُونَ", "23");
+        assert_text2digits!("مئة", "100");
+        assert_text2digits!("خمسه", "5");
+    }
+
+    #[test]
+    fn test_transliterations() {
+        assert_text2digits!("khamsa wa ishrin", "25");
+        assert_text2digits!("thalatha alaf", "3000");
+        assert_text2digits!("alfayn wa mia", "2100");
+        assert_text2digits!("ahad ashar", "11");
+    }
+
+    #[test]
+    fn test_decimals() {
+        assert_replace_numbers!("ثلاثة فاصلة أربعة عشر", "3.14");
+    }
+
+    #[test]
+    fn test_eastern_digits() {
+        let f = Arabic::new().eastern_digits(true);
+        assert_eq!(text2digits("خمسة وعشرون", &f).unwrap(), "٢٥");
+        assert_eq!(
+            replace_numbers_in_text("ثلاثة فاصلة أربعة عشر", &f, 10.0),
+            "٣٫١٤"
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("عشرون خمسة");
+        assert_invalid!("ألف ألف");
+        assert_invalid!("آلاف");
+        assert_invalid!("مائة مائة");
+        assert_invalid!("خمسة وعشرون وثلاثون");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!("اشتريت خمسة وعشرين كتابا", "اشتريت 25 كتابا");
+        assert_replace_numbers!("عندي ثلاثة آلاف دينار", "عندي 3000 دينار");
+        assert_replace_numbers!("خمسة ستة سبعة", "5 6 7");
+        assert_replace_numbers!("ولد واحد", "ولد واحد");
+    }
+}
@@ -0,0 +1,5 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "و", "wa", "يعني", "yani", "ثم", "thumma", "اه", "ايوه", "نعم", "naam", "سالب", "ناقص", "زائد", "موجب"
+};
@@ -0,0 +1,463 @@
+//! Catalan number interpreter
+use crate::digit_string::DigitString;
+use crate::error::Error;
+
+mod vocabulary;
+
+use super::{LangInterpreter, MorphologicalMarker, ordinal_denominator};
+use vocabulary::INSIGNIFICANT;
+
+/// The ordinals that are not built on their cardinal, and can't end a compound.
+fn irregular_ordinal(word: &str) -> Option<(&'static str, &'static str)> {
+    match word {
+        "primer" => Some(("un", "r")),
+        "primera" => Some(("un", "a")),
+        "primers" => Some(("un", "rs")),
+        "primeres" => Some(("un", "es")),
+        // "segon" and "segons" are the unit of time
+        "segona" => Some(("dos", "a")),
+        "segones" => Some(("dos", "es")),
+        "tercer" => Some(("tres", "r")),
+        "tercera" => Some(("tres", "a")),
+        "tercers" => Some(("tres", "rs")),
+        "terceres" => Some(("tres", "es")),
+        "quart" => Some(("quatre", "t")),
+        "quarta" => Some(("quatre", "a")),
+        "quarts" => Some(("quatre", "ts")),
+        "quartes" => Some(("quatre", "es")),
+        _ => None,
+    }
+}
+
+/// Split an ordinal into the cardinal it's built on and its abbreviation marker.
+fn split_ordinal(word: &str) -> Option<(&str, &'static str)> {
+    irregular_ordinal(word).or_else(|| {
+        let (stem, marker) = if let Some(stem) = word.strip_suffix('è') {
+            (stem, "è")
+        } else if let Some(stem) = word.strip_suffix("ena") {
+            (stem, "a")
+        } else if let Some(stem) = word.strip_suffix("ens") {
+            (stem, "ns")
+        } else if let Some(stem) = word.strip_suffix("enes") {
+            (stem, "es")
+        } else {
+            return None;
+        };
+        let cardinal = match stem {
+            // in compounds only: "vint-i-unè", "trenta-dosè"…
+            "un" => "un",
+            "dos" => "dos",
+            "tres" => "tres",
+            "quatr" => "quatre",
+            "cinqu" => "cinc",
+            "sis" => "sis",
+            "set" => "set",
+            "vuit" | "huit" => "vuit",
+            "nov" => "nou",
+            "des" => "deu",
+            "onz" => "onze",
+            "dotz" => "dotze",
+            "tretz" => "tretze",
+            "catorz" => "catorze",
+            "quinz" => "quinze",
+            "setz" => "setze",
+            "disset" => "disset",
+            "divuit" => "divuit",
+            "dinov" => "dinou",
+            "vint" => "vint",
+            "trent" => "trenta",
+            "quarant" => "quaranta",
+            "cinquant" => "cinquanta",
+            "seixant" => "seixanta",
+            "setant" => "setanta",
+            "vuitant" | "huitant" => "vuitanta",
+            "norant" => "noranta",
+            "cent" => "cent",
+            "mil" => "mil",
+            "milion" => "milió",
+            _ => return None,
+        };
+        Some((cardinal, marker))
+    })
+}
+
+#[derive(Default)]
+pub struct Catalan {}
+
+impl Catalan {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl LangInterpreter for Catalan {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        // Compounds are hyphenated: "vint-i-dos", "trenta-cinc", "dues-centes"
+        if num_func.contains('-') {
+            return match self.exec_group(num_func.split('-')) {
+                Ok(ds) => {
+                    if ds.len() > 3 && ds.len() <= 6 && !b.is_range_free(3, 5) {
+                        return Err(Error::Overlap);
+                    }
+                    b.put(&ds)?;
+                    if ds.marker.is_ordinal() {
+                        b.marker = ds.marker;
+                        b.freeze()
+                    }
+                    Ok(())
+                }
+                Err(err) => Err(err),
+            };
+        }
+        // It's "vint-i-quatrè", not "vint quart"
+        if !b.is_empty() && irregular_ordinal(num_func).is_some() {
+            return Err(Error::Overlap);
+        }
+        let (lemma, marker) = match split_ordinal(num_func) {
+            Some((cardinal, marker)) => (cardinal, MorphologicalMarker::Ordinal(marker)),
+            None => (num_func, MorphologicalMarker::None),
+        };
+        let status = match lemma {
+            "zero" => b.put(b"0"),
+            "u" | "un" | "una" if b.peek(2) != b"10" => b.put(b"1"),
+            "dos" | "dues" if b.peek(2) != b"10" => b.put(b"2"),
+            "tres" if b.peek(2) != b"10" => b.put(b"3"),
+            "quatre" if b.peek(2) != b"10" => b.put(b"4"),
+            "cinc" if b.peek(2) != b"10" => b.put(b"5"),
+            "sis" if b.peek(2) != b"10" => b.put(b"6"),
+            "set" if b.peek(2) != b"10" => b.put(b"7"),
+            "vuit" | "huit" if b.peek(2) != b"10" => b.put(b"8"),
+            "nou" if b.peek(2) != b"10" => b.put(b"9"),
+            "deu" => b.put(b"10"),
+            "onze" => b.put(b"11"),
+            "dotze" => b.put(b"12"),
+            "tretze" => b.put(b"13"),
+            "catorze" => b.put(b"14"),
+            "quinze" => b.put(b"15"),
+            "setze" => b.put(b"16"),
+            "disset" | "dèsset" | "desset" => b.put(b"17"),
+            "divuit" | "devuit" | "díhuit" | "dihuit" => b.put(b"18"),
+            "dinou" | "dènou" | "dèneu" | "denou" => b.put(b"19"),
+            "vint" => b.put(b"20"),
+            "trenta" => b.put(b"30"),
+            "quaranta" => b.put(b"40"),
+            "cinquanta" => b.put(b"50"),
+            "seixanta" => b.put(b"60"),
+            "setanta" => b.put(b"70"),
+            "vuitanta" | "huitanta" => b.put(b"80"),
+            "noranta" => b.put(b"90"),
+            "cent" | "cents" | "centes" => {
+                // "dos-cents", "dues-centes": the multiplier follows a lone unit
+                let peek = b.peek(2);
+                let multiplicand = match peek {
+                    [unit] | [b'0', unit] => (b'2'..=b'9').contains(unit),
+                    _ => false,
+                };
+                if multiplicand {
+                    b.shift(2)
+                } else if lemma == "cent" {
+                    b.put(b"100")
+                } else {
+                    Err(Error::NaN)
+                }
+            }
+            "mil" if b.is_range_free(3, 5) => {
+                let peek = b.peek(2);
+                if peek == b"1" {
+                    Err(Error::Overlap)
+                } else {
+                    b.shift(3)
+                }
+            }
+            "milió" | "milio" | "milions" if b.is_range_free(6, 8) => b.shift(6),
+            "miliard" | "miliards" => b.shift(9),
+            "i" if b.len() >= 2 => Err(Error::Incomplete),
+
+            _ => Err(Error::NaN),
+        };
+        if status.is_ok() && marker.is_ordinal() {
+            b.marker = marker;
+            b.freeze();
+        }
+        status
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        self.apply(decimal_func, b)
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        match word {
+            "coma" => Some(','),
+            "punt" => Some('.'),
+            _ => None,
+        }
+    }
+
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "menys" => Some('-'),
+            "més" | "mes" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn apply_denominator(
+        &self,
+        frac_func: &str,
+        numerator: &DigitString,
+        b: &mut DigitString,
+    ) -> Result<(), Error> {
+        match frac_func {
+            "mig" | "mitja" | "mitjos" | "mitges" => b.put(b"2"),
+            "terç" | "terços" => b.put(b"3"),
+            _ => ordinal_denominator(self, frac_func, numerator, true, b),
+        }
+    }
+
+    fn is_fraction_link(&self, word: &str) -> bool {
+        matches!(word, "i")
+    }
+
+    fn format_and_value(&self, b: &DigitString) -> (String, f64) {
+        let repr = b.to_string();
+        let val: f64 = repr.parse().unwrap();
+        if let MorphologicalMarker::Ordinal(marker) = b.marker {
+            (format!("{repr}{marker}"), val)
+        } else {
+            (repr, val)
+        }
+    }
+
+    fn format_decimal_and_value(
+        &self,
+        int: &DigitString,
+        dec: &DigitString,
+        sep: char,
+    ) -> (String, f64) {
+        let sint = int.to_string();
+        let sdec = dec.to_string();
+        let val = format!("{sint}.{sdec}").parse().unwrap();
+        (format!("{sint}{sep}{sdec}"), val)
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        // Only the last part of a compound is ordinal
+        let last = word.rsplit('-').next().unwrap_or(word);
+        match split_ordinal(last) {
+            Some((_, marker)) => MorphologicalMarker::Ordinal(marker),
+            None => MorphologicalMarker::None,
+        }
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConversionOptions;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Catalan {};
+            let res = text2digits($text, &f);
+            dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Catalan {};
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_fractions {
+        ($text:expr, $res:expr) => {
+            assert_replace_fractions!($text, $res, 10.0)
+        };
+        ($text:expr, $res:expr, $threshold:expr) => {
+            let f = Catalan {};
+            let options = ConversionOptions::from($threshold).convert_fractions(true);
+            assert_eq!(replace_numbers_in_text($text, &f, options), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Catalan {};
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Catalan {};
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply_steps() {
+        let f = Catalan {};
+        let mut b = DigitString::new();
+        assert!(f.apply("trenta", &mut b).is_ok());
+        assert!(f.apply("quatre", &mut b).is_ok());
+        assert!(f.apply("vint", &mut b).is_err());
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("zero", "0");
+        assert_text2digits!("u", "1");
+        assert_text2digits!("nou", "9");
+        assert_text2digits!("deu", "10");
+        assert_text2digits!("onze", "11");
+        assert_text2digits!("dinou", "19");
+        assert_text2digits!("vint", "20");
+        assert_text2digits!("vint-i-u", "21");
+        assert_text2digits!("vint-i-dos", "22");
+        assert_text2digits!("vint i dos", "22");
+        assert_text2digits!("trenta-cinc", "35");
+        assert_text2digits!("noranta-nou", "99");
+        assert_text2digits!("cent", "100");
+        assert_text2digits!("cent u", "101");
+        assert_text2digits!("cent quinze", "115");
+        assert_text2digits!("dos-cents", "200");
+        assert_text2digits!("dos cents", "200");
+        assert_text2digits!("nou-cents noranta-nou", "999");
+        assert_text2digits!("mil", "1000");
+        assert_text2digits!("mil u", "1001");
+        assert_text2digits!("dos mil", "2000");
+        assert_text2digits!("setanta-cinc mil", "75000");
+        assert_text2digits!("mil nou-cents vint", "1920");
+        assert_text2digits!("nou-cents noranta-nou mil nou-cents noranta-nou", "999999");
+        assert_text2digits!(
+            "cinquanta-un milions cinc-cents setanta-vuit mil tres-cents dos",
+            "51578302"
+        );
+        assert_text2digits!("un milió", "1000000");
+        assert_text2digits!("dos miliards", "2000000000");
+    }
+
+    #[test]
+    fn test_variants() {
+        assert_text2digits!("dues", "2");
+        assert_text2digits!("dues-centes", "200");
+        assert_text2digits!("vint-i-una", "21");
+        assert_text2digits!("dues-centes trenta-dues", "232");
+        assert_text2digits!("huitanta-huit", "88");
+        assert_text2digits!("dèneu", "19");
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_text2digits!("primer", "1r");
+        assert_text2digits!("primera", "1a");
+        assert_text2digits!("primers", "1rs");
+        assert_text2digits!("segona", "2a");
+        assert_text2digits!("tercer", "3r");
+        assert_text2digits!("quart", "4t");
+        assert_text2digits!("cinquè", "5è");
+        assert_text2digits!("cinquena", "5a");
+        assert_text2digits!("cinquens", "5ns");
+        assert_text2digits!("cinquenes", "5es");
+        assert_text2digits!("desè", "10è");
+        assert_text2digits!("vintè", "20è");
+        assert_text2digits!("vint-i-unè", "21è");
+        assert_text2digits!("trenta-dosena", "32a");
+        assert_text2digits!("centè", "100è");
+        assert_text2digits!("cent vint-i-quatrè", "124è");
+        assert_text2digits!("dos-centè", "200è");
+        assert_text2digits!("milè", "1000è");
+    }
+
+    #[test]
+    fn test_fractions() {
+        assert_replace_fractions!("tres quarts i un terç", "3/4 i 1/3");
+        assert_replace_fractions!("dos i mig", "2 1/2");
+        assert_replace_fractions!("tres cinquens", "3/5");
+        assert_replace_fractions!("una cinquena part", "1 5a part", 0.0);
+    }
+
+    #[test]
+    fn test_zeroes() {
+        assert_text2digits!("zero u", "01");
+        assert_text2digits!("zero vuit", "08");
+        assert_text2digits!("zero zero cent vint-i-cinc", "00125");
+        assert_invalid!("cinc zero");
+        assert_invalid!("cinquanta zero tres");
+        assert_invalid!("cinquanta-tres zero");
+        assert_invalid!("deu zero");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("mil mil dos-cents");
+        assert_invalid!("seixanta quinze");
+        assert_invalid!("seixanta cent");
+        assert_invalid!("quinze cents");
+        assert_invalid!("vint quart");
+        assert_invalid!("deu quatre");
+        assert_invalid!("u mil");
+        assert_invalid!("dos-i-tres");
+    }
+
+    #[test]
+    fn test_replace_numbers_integers() {
+        assert_replace_numbers!(
+            "Vint-i-cinc vaques, dotze gallines i cent vint-i-cinc kg de patates.",
+            "25 vaques, 12 gallines i 125 kg de patates."
+        );
+        assert_replace_numbers!(
+            "tres-cents homes i cinc-centes dones",
+            "300 homes i 500 dones"
+        );
+        assert_replace_numbers!("Mil dos-cents seixanta-sis euros.", "1266 euros.");
+        assert_replace_numbers!("u dos tres quatre vint quinze.", "1 2 3 4 20 15.");
+        assert_replace_numbers!("Vint-i-u, trenta-u.", "21, 31.");
+    }
+
+    #[test]
+    fn test_replace_numbers_ordinals() {
+        assert_replace_numbers!(
+            "Quart cinquè tercer vint-i-unè centè.",
+            "4t 5è 3r 21è 100è."
+        );
+        assert_replace_numbers!(
+            "Espera un segon! La vint-i-dosena edició.",
+            "Espera un segon! La 22a edició."
+        );
+        assert_replace_all_numbers!("Ella ha quedat tercera", "Ella ha quedat 3a");
+        assert_replace_all_numbers!("Ells han quedat primers", "Ells han quedat 1rs");
+    }
+
+    #[test]
+    fn test_replace_numbers_decimals() {
+        assert_replace_numbers!(
+            "dotze coma noranta-nou, cent vint coma zero cinc, u coma dos-cents trenta-sis.",
+            "12,99, 120,05, 1,236."
+        );
+        assert_replace_numbers!("zero coma quinze", "0,15");
+        assert_replace_numbers!("u punt u", "1.1");
+    }
+
+    #[test]
+    fn test_isolates() {
+        assert_replace_numbers!(
+            "Un moment si us plau! trenta-un gats. U dos tres quatre!",
+            "Un moment si us plau! 31 gats. 1 2 3 4!"
+        );
+    }
+}
@@ -0,0 +1,5 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "doncs", "i", "vull", "dir", "o", "sigui", "llavors", "aleshores", "així", "que", "bé", "és", "això", "en", "fi", "després", "més", "menys", "però", "val", "eh", "ah", "escolta", "ja", "hum", "ok", "sí", "no", "amb", "són"
+};
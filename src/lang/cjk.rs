@@ -0,0 +1,288 @@
+//! Shared core of the Chinese and Japanese interpreters
+//!
+//! Both languages group the digits by four, in myriads: "*二十三万四千*" is 23 myriads and 4 thousands, 234000.
+//! Within a myriad, "*十*", "*百*" and "*千*" multiply the digit before them, and the myriad multipliers "*万*", "*亿*"
+//! and "*兆*" multiply the whole group before them.
+//!
+//! As both languages are written without spaces, the words are also segmented on the number words.
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+use crate::tokenizer::WordSplitter;
+
+use super::{LangInterpreter, MorphologicalMarker};
+
+/// The meaning of a number word.
+#[derive(Debug, Clone, Copy)]
+pub(super) enum Numeral {
+    Zero,
+    /// A digit from 1 to 9
+    Digit(u8),
+    /// "*十*", "*百*" or "*千*", with their position, and whether they can stand alone for one of them
+    Multiplier(usize, bool),
+    /// "*万*", "*亿*" or "*兆*", with their position
+    Myriad(usize),
+}
+
+/// Position of the last multiplier, or of the last digit if `PENDING`.
+const POSITION: u64 = 0xff;
+/// The last word was a multiplier.
+const MULTIPLIER: u64 = 1 << 8;
+/// A zero stands for the missing positions after a multiplier: "*一千零五*".
+const ZERO: u64 = 1 << 9;
+/// The digits were read one by one: "*二〇二四*".
+const SEQUENCE: u64 = 1 << 10;
+/// The last digit directly follows a multiplier, and was put just below it: "*三千五*" is 3500.
+const PENDING: u64 = 1 << 11;
+
+/// What Chinese or Japanese provides to the shared core.
+pub(super) trait Dialect: LangInterpreter {
+    /// A digit right after a multiplier counts the position below it, as in "*三千五*" (3500), unless a zero
+    /// stands between them, "*三千零五*" (3005).
+    const ABBREVIATED: bool;
+    /// Ordinary words written with number characters, like "*万一*" (just in case), unless a number comes before them.
+    const IDIOMS: &'static [&'static str];
+
+    fn word_splitter(&self) -> &WordSplitter;
+
+    /// The meaning of a number word.
+    fn numeral(&self, word: &str) -> Option<Numeral>;
+}
+
+/// Put the pending digit back in the units, as a multiplier follows it.
+fn unpend(b: &mut DigitString) -> Result<(), Error> {
+    let position = (b.flags & POSITION) as usize;
+    let mut digits = vec![b'0'; position + 1];
+    digits[position] = b.peek(position + 1)[0];
+    b.fput(&digits)
+}
+
+pub(super) fn apply<D: Dialect>(
+    lang: &D,
+    num_func: &str,
+    b: &mut DigitString,
+) -> Result<(), Error> {
+    if lang.word_splitter().is_splittable(num_func) {
+        // All or nothing
+        let mut group = b.clone();
+        let mut incomplete = false;
+        for word in lang.word_splitter().split(num_func) {
+            incomplete = match apply(lang, word, &mut group) {
+                Err(Error::Incomplete) => true,
+                Ok(()) => false,
+                Err(error) => return Err(error),
+            };
+        }
+        *b = group;
+        return if incomplete {
+            Err(Error::Incomplete)
+        } else {
+            Ok(())
+        };
+    }
+    let numeral = lang.numeral(num_func).ok_or(Error::NaN)?;
+    let flags = b.flags;
+    match numeral {
+        Numeral::Zero if b.is_empty() || flags & SEQUENCE != 0 => {
+            if b.is_null() {
+                b.put(b"0")?;
+            } else {
+                b.push(b"0")?;
+            }
+            b.flags = SEQUENCE;
+            Ok(())
+        }
+        Numeral::Zero if flags & MULTIPLIER != 0 => {
+            b.flags = ZERO;
+            Err(Error::Incomplete)
+        }
+        Numeral::Zero => Err(Error::Overlap),
+        Numeral::Digit(digit) if b.is_empty() || flags & SEQUENCE != 0 => {
+            if b.is_null() {
+                b.put(&[digit])?;
+            } else {
+                b.push(&[digit])?;
+            }
+            b.flags = SEQUENCE;
+            Ok(())
+        }
+        Numeral::Digit(digit) if flags & MULTIPLIER != 0 => {
+            let position = (flags & POSITION) as usize;
+            if D::ABBREVIATED && position >= 2 {
+                b.put_digit_at(digit, position - 1)?;
+                b.flags = PENDING | (position - 1) as u64;
+                Ok(())
+            } else {
+                b.put(&[digit])?;
+                b.flags = 0;
+                Ok(())
+            }
+        }
+        Numeral::Digit(digit) if flags & ZERO != 0 => {
+            b.put(&[digit])?;
+            b.flags = 0;
+            Ok(())
+        }
+        Numeral::Digit(_) => Err(Error::Overlap),
+        Numeral::Multiplier(position, alone) => {
+            if flags & PENDING != 0 {
+                unpend(b)?;
+            }
+            let digit = match b.peek(1).first() {
+                Some(&digit) if digit != b'0' => digit,
+                _ if alone => b'1',
+                _ => return Err(Error::NaN),
+            };
+            // Only a single digit can be multiplied, and the positions must decrease.
+            if !b.is_null() && !(1..=position).all(|pos| b.is_position_free(pos)) {
+                return Err(Error::Overlap);
+            }
+            let mut digits = vec![b'0'; position + 1];
+            digits[0] = digit;
+            b.fput(&digits)?;
+            b.flags = MULTIPLIER | position as u64;
+            Ok(())
+        }
+        Numeral::Myriad(position) => {
+            if b.is_null() {
+                return Err(Error::NaN);
+            }
+            if flags & PENDING != 0 {
+                unpend(b)?;
+            }
+            if b.is_free(position) {
+                return Err(Error::Overlap);
+            }
+            b.shift(position)?;
+            b.flags = MULTIPLIER | position as u64;
+            Ok(())
+        }
+    }
+}
+
+pub(super) fn apply_decimal<D: Dialect>(
+    lang: &D,
+    decimal_func: &str,
+    b: &mut DigitString,
+) -> Result<(), Error> {
+    if lang.word_splitter().is_splittable(decimal_func) {
+        let mut group = b.clone();
+        for word in lang.word_splitter().split(decimal_func) {
+            apply_decimal(lang, word, &mut group)?;
+        }
+        *b = group;
+        return Ok(());
+    }
+    // The decimals are read digit by digit
+    match lang.numeral(decimal_func) {
+        Some(Numeral::Zero) => b.push(b"0"),
+        Some(Numeral::Digit(digit)) => b.push(&[digit]),
+        _ => Err(Error::NaN),
+    }
+}
+
+fn is_kana(c: char) -> bool {
+    ('\u{3041}'..='\u{30ff}').contains(&c)
+}
+
+/// Split the run of kanji (or hanzi) `run` on the number words, except the multipliers that don't count anything,
+/// like "*十*" in "*十分*" (enough), and the idioms of the dialect.
+fn split_run<'a, D: Dialect>(lang: &'a D, run: &'a str, parts: &mut Vec<&'a str>) {
+    let words: Vec<&str> = lang.word_splitter().split(run).collect();
+    let numerals: Vec<Option<Numeral>> = words.iter().map(|word| lang.numeral(word)).collect();
+    let mut numeric: Vec<bool> = numerals.iter().map(Option::is_some).collect();
+    let mut starts = Vec::with_capacity(words.len());
+    let mut offset = 0;
+    for word in &words {
+        starts.push(offset);
+        offset += word.len();
+    }
+    for i in 0..words.len() {
+        let before = i > 0 && numerals[i - 1].is_some();
+        let after = numerals.get(i + 1).is_some_and(Option::is_some);
+        match numerals[i] {
+            // "十" alone, or "万" without a count
+            Some(Numeral::Multiplier(..)) if !before && !after => numeric[i] = false,
+            Some(Numeral::Myriad(_)) if !before => numeric[i] = false,
+            _ => (),
+        }
+        if before {
+            continue;
+        }
+        if let Some(idiom) = D::IDIOMS
+            .iter()
+            .find(|idiom| run[starts[i]..].starts_with(**idiom))
+        {
+            let end = starts[i] + idiom.len();
+            for j in (i..words.len()).take_while(|&j| starts[j] < end) {
+                numeric[j] = false;
+            }
+        }
+    }
+    // The other words are kept together
+    let mut start = 0;
+    for (i, word) in words.iter().enumerate() {
+        if numeric[i] {
+            if starts[i] > start {
+                parts.push(&run[start..starts[i]]);
+            }
+            parts.push(word);
+            start = starts[i] + word.len();
+        }
+    }
+    if start < run.len() {
+        parts.push(&run[start..]);
+    }
+}
+
+/// Split the `word` on the number words, except in the runs of kana that are not only made of number words:
+/// "*に*" is "two" in "*にじゅう*", not in "*にほんご*".
+pub(super) fn segment<'a, D: Dialect>(lang: &'a D, word: &'a str) -> Option<Vec<&'a str>> {
+    if !lang.word_splitter().is_splittable(word) {
+        return None;
+    }
+    let is_number = |part: &str| {
+        lang.numeral(part).is_some()
+            || lang.check_decimal_separator(part).is_some()
+            || lang.check_sign(part).is_some()
+    };
+    let mut parts = Vec::new();
+    let mut start = 0;
+    for (end, c) in word.char_indices().skip(1).chain([(word.len(), ' ')]) {
+        let run = &word[start..end];
+        if end < word.len() && is_kana(c) == run.starts_with(is_kana) {
+            continue;
+        }
+        if run.starts_with(is_kana) && !lang.word_splitter().split(run).all(is_number) {
+            parts.push(run);
+        } else if run.starts_with(is_kana) {
+            parts.extend(lang.word_splitter().split(run));
+        } else {
+            split_run(lang, run, &mut parts);
+        }
+        start = end;
+    }
+    (parts.len() > 1).then_some(parts)
+}
+
+pub(super) fn get_morph_marker() -> MorphologicalMarker {
+    MorphologicalMarker::None
+}
+
+pub(super) fn format_and_value(b: &DigitString) -> (String, f64) {
+    let repr = b.to_string();
+    let val: f64 = repr.parse().unwrap();
+    (repr, val)
+}
+
+pub(super) fn format_decimal_and_value(
+    int: &DigitString,
+    dec: &DigitString,
+    sep: char,
+) -> (String, f64) {
+    let irepr = int.to_string();
+    let drepr = dec.to_string();
+    let frepr = format!("{irepr}{sep}{drepr}");
+    let val = format!("{irepr}.{drepr}").parse().unwrap();
+    (frepr, val)
+}
@@ -0,0 +1,308 @@
+//! Czech number interpreter
+//!
+//! The units usually follow the tens, "dvacet jedna", but the inverted form "jednadvacet" is also understood.
+//! Ordinals are written with a final dot: "*pátý*" becomes "*5.*".
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+use crate::tokenizer::WordSplitter;
+
+mod vocabulary;
+
+use super::czechoslovak::{self, Dialect, Numeral};
+use super::{LangInterpreter, MorphologicalMarker};
+use vocabulary::INSIGNIFICANT;
+
+#[derive(Default)]
+pub struct Czech {}
+
+impl Czech {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl Dialect for Czech {
+    const ORDINAL_ENDINGS: &'static [&'static str] = &["ého", "ího", "ý", "á", "é", "í"];
+    const INVERTED_UNITS: &'static [(&'static str, u8)] = &[
+        ("jedna", b'1'),
+        ("dvaa", b'2'),
+        ("třia", b'3'),
+        ("čtyřia", b'4'),
+        ("pěta", b'5'),
+        ("šesta", b'6'),
+        ("sedma", b'7'),
+        ("osma", b'8'),
+        ("devěta", b'9'),
+    ];
+
+    fn word_splitter(&self) -> Option<&WordSplitter> {
+        None
+    }
+
+    fn cardinal(&self, word: &str) -> Option<Numeral> {
+        let numeral = match word {
+            "nula" => Numeral::Zero,
+            "jeden" | "jedna" | "jedno" | "jednoho" | "jedné" | "jednu" => Numeral::Unit(b'1'),
+            "dva" | "dvě" | "dvou" | "dvěma" => Numeral::Unit(b'2'),
+            "tři" | "tří" | "třem" | "třemi" => Numeral::Unit(b'3'),
+            "čtyři" | "čtyř" | "čtyřem" | "čtyřmi" => Numeral::Unit(b'4'),
+            "pět" | "pěti" => Numeral::Unit(b'5'),
+            "šest" | "šesti" => Numeral::Unit(b'6'),
+            "sedm" | "sedmi" => Numeral::Unit(b'7'),
+            "osm" | "osmi" => Numeral::Unit(b'8'),
+            "devět" | "devíti" => Numeral::Unit(b'9'),
+            "deset" | "deseti" => Numeral::Digits(b"10"),
+            "jedenáct" => Numeral::Digits(b"11"),
+            "dvanáct" => Numeral::Digits(b"12"),
+            "třináct" => Numeral::Digits(b"13"),
+            "čtrnáct" => Numeral::Digits(b"14"),
+            "patnáct" => Numeral::Digits(b"15"),
+            "šestnáct" => Numeral::Digits(b"16"),
+            "sedmnáct" => Numeral::Digits(b"17"),
+            "osmnáct" => Numeral::Digits(b"18"),
+            "devatenáct" => Numeral::Digits(b"19"),
+            "dvacet" | "dvaceti" => Numeral::Tens(b'2'),
+            "třicet" | "třiceti" => Numeral::Tens(b'3'),
+            "čtyřicet" | "čtyřiceti" => Numeral::Tens(b'4'),
+            "padesát" | "padesáti" => Numeral::Tens(b'5'),
+            "šedesát" | "šedesáti" => Numeral::Tens(b'6'),
+            "sedmdesát" | "sedmdesáti" => Numeral::Tens(b'7'),
+            "osmdesát" | "osmdesáti" => Numeral::Tens(b'8'),
+            "devadesát" | "devadesáti" => Numeral::Tens(b'9'),
+            "sto" => Numeral::Hundred,
+            "stě" | "sta" | "set" => Numeral::Hundreds,
+            "tisíc" | "tisíce" | "tisíci" => Numeral::Thousand,
+            "milion" | "miliony" | "milionů" | "milionu" => Numeral::Million,
+            "miliarda" | "miliardy" | "miliard" => Numeral::Milliard,
+            _ => return None,
+        };
+        Some(numeral)
+    }
+
+    fn ordinal(&self, stem: &str) -> Option<Numeral> {
+        let numeral = match stem {
+            "prvn" => Numeral::Unit(b'1'),
+            "druh" => Numeral::Unit(b'2'),
+            "třet" => Numeral::Unit(b'3'),
+            "čtvrt" => Numeral::Unit(b'4'),
+            "pát" => Numeral::Unit(b'5'),
+            "šest" => Numeral::Unit(b'6'),
+            "sedm" => Numeral::Unit(b'7'),
+            "osm" => Numeral::Unit(b'8'),
+            "devát" => Numeral::Unit(b'9'),
+            "desát" => Numeral::Digits(b"10"),
+            "jedenáct" => Numeral::Digits(b"11"),
+            "dvanáct" => Numeral::Digits(b"12"),
+            "třináct" => Numeral::Digits(b"13"),
+            "čtrnáct" => Numeral::Digits(b"14"),
+            "patnáct" => Numeral::Digits(b"15"),
+            "šestnáct" => Numeral::Digits(b"16"),
+            "sedmnáct" => Numeral::Digits(b"17"),
+            "osmnáct" => Numeral::Digits(b"18"),
+            "devatenáct" => Numeral::Digits(b"19"),
+            "dvacát" => Numeral::Tens(b'2'),
+            "třicát" => Numeral::Tens(b'3'),
+            "čtyřicát" => Numeral::Tens(b'4'),
+            "padesát" => Numeral::Tens(b'5'),
+            "šedesát" => Numeral::Tens(b'6'),
+            "sedmdesát" => Numeral::Tens(b'7'),
+            "osmdesát" => Numeral::Tens(b'8'),
+            "devadesát" => Numeral::Tens(b'9'),
+            "st" => Numeral::Hundred,
+            "dvoust" => Numeral::Digits(b"200"),
+            "třist" => Numeral::Digits(b"300"),
+            "čtyřst" => Numeral::Digits(b"400"),
+            "pětist" => Numeral::Digits(b"500"),
+            "šestist" => Numeral::Digits(b"600"),
+            "sedmist" => Numeral::Digits(b"700"),
+            "osmist" => Numeral::Digits(b"800"),
+            "devítist" => Numeral::Digits(b"900"),
+            "tisíc" => Numeral::Thousand,
+            "miliont" => Numeral::Million,
+            _ => return None,
+        };
+        Some(numeral)
+    }
+}
+
+impl LangInterpreter for Czech {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        czechoslovak::apply(self, num_func, b)
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        self.apply(decimal_func, b)
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        czechoslovak::check_decimal_separator(word)
+    }
+
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "minus" => Some('-'),
+            "plus" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn format_and_value(&self, b: &DigitString) -> (String, f64) {
+        czechoslovak::format_and_value(b)
+    }
+
+    fn format_decimal_and_value(
+        &self,
+        int: &DigitString,
+        dec: &DigitString,
+        sep: char,
+    ) -> (String, f64) {
+        czechoslovak::format_decimal_and_value(int, dec, sep)
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        czechoslovak::get_morph_marker(self, word)
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Czech;
+    use crate::ConversionOptions;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Czech::new();
+            let res = text2digits($text, &f);
+            dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Czech::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Czech::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Czech::new();
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("nula", "0");
+        assert_text2digits!("dvanáct", "12");
+        assert_text2digits!("dvacet jedna", "21");
+        assert_text2digits!("devadesát devět", "99");
+        assert_text2digits!("sto", "100");
+        assert_text2digits!("sto patnáct", "115");
+        assert_text2digits!("dvě stě třicet", "230");
+        assert_text2digits!("tři sta", "300");
+        assert_text2digits!("pět set", "500");
+        assert_text2digits!("tisíc devět set osmdesát čtyři", "1984");
+        assert_text2digits!("dva tisíce tři sta", "2300");
+        assert_text2digits!("pět tisíc", "5000");
+        assert_text2digits!("sto tisíc", "100000");
+        assert_text2digits!(
+            "padesát tři miliardy dvě stě čtyřicet tři tisíc sedm set dvacet čtyři",
+            "53000243724"
+        );
+    }
+
+    #[test]
+    fn test_plurals() {
+        assert_text2digits!("jeden tisíc", "1000");
+        assert_text2digits!("tři tisíce", "3000");
+        assert_text2digits!("šest tisíc", "6000");
+        assert_text2digits!("milion", "1000000");
+        assert_text2digits!("dva miliony", "2000000");
+        assert_text2digits!("pět milionů", "5000000");
+        assert_text2digits!("miliarda", "1000000000");
+    }
+
+    #[test]
+    fn test_inverted() {
+        assert_text2digits!("jednadvacet", "21");
+        assert_text2digits!("dvaadvacet", "22");
+        assert_text2digits!("pětadevadesát", "95");
+        assert_text2digits!("sto čtyřiadvacet", "124");
+        assert_text2digits!("pětadvacátý", "25.");
+        assert_invalid!("dvacet jednadvacet");
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_text2digits!("první", "1.");
+        assert_text2digits!("druhá", "2.");
+        assert_text2digits!("třetí", "3.");
+        assert_text2digits!("devatenáctý", "19.");
+        assert_text2digits!("dvacátý první", "21.");
+        assert_text2digits!("stý", "100.");
+        assert_text2digits!("sto dvacátý pátý", "125.");
+        assert_text2digits!("dvoustý", "200.");
+        assert_text2digits!("tisící", "1000.");
+        assert_replace_numbers!("dvacátého osmého října", "28. října");
+    }
+
+    #[test]
+    fn test_decimals() {
+        assert_replace_numbers!("tři celé čtrnáct", "3,14");
+        assert_replace_numbers!("dvanáct celých pět", "12,5");
+        assert_replace_numbers!("nula celá nula pět", "0,05");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("tisíc tisíc dvě stě");
+        assert_invalid!("šedesát patnáct");
+        assert_invalid!("deset pět");
+        assert_invalid!("pět dvacet");
+        assert_invalid!("dvacátý pět");
+        assert_invalid!("set");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!(
+            "dvacet pět krav, dvanáct slepic a sto dvacet pět kilo brambor.",
+            "25 krav, 12 slepic a 125 kilo brambor."
+        );
+        assert_replace_numbers!("jedna dva tři čtyři dvacet patnáct", "1 2 3 4 20 15");
+        assert_replace_all_numbers!("jedna kočka", "1 kočka");
+    }
+
+    #[test]
+    fn test_fractions_unsupported() {
+        // Fraction words are not known yet, so they stay as words even when fractions are converted.
+        let f = Czech::new();
+        let options = ConversionOptions::from(0.0).convert_fractions(true);
+        assert_eq!(
+            replace_numbers_in_text("tři čtvrtiny", &f, options),
+            "3 čtvrtiny"
+        );
+        assert_eq!(replace_numbers_in_text("dvě a půl", &f, options), "2 a půl");
+    }
+}
@@ -0,0 +1,5 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "a", "no", "tak", "ehm", "hm", "jo", "ano", "minus", "plus", "je", "pak", "teda"
+};
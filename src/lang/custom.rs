@@ -0,0 +1,266 @@
+//! Domain specific vocabulary on top of any interpreter.
+
+use std::collections::HashSet;
+
+use crate::decimal::Decimal;
+use crate::digit_string::DigitString;
+use crate::error::Error;
+
+use super::{BasicAnnotate, Gender, LangInterpreter, MorphologicalMarker, Verbalize};
+
+/// An interpreter with extra linking words and extra forced-separator words.
+///
+/// The builtin interpreters know the linking words of everyday speech, like "*uh*" in English (see
+/// [`LangInterpreter::is_linking`]). A domain may have its own fillers that should not isolate the numbers around
+/// them, like "*like*" in English or "*genre*" and "*quoi*" in French, or on the contrary words that must always end a
+/// number, even if they are conjunctions or fillers for the language.
+///
+/// The words are single tokens, matched case insensitively: to ignore "*you know*", add both "*you*" and "*know*".
+/// They don't apply inside compound words like "*vingt-et-un*".
+///
+/// ```
+/// use text2num::lang::{Customized, French};
+/// use text2num::replace_numbers_in_text;
+///
+/// let fr = Customized::new(French::default()).linking_words(["genre", "quoi"]);
+/// assert_eq!(
+///     replace_numbers_in_text("un genre deux genre trois quoi", &fr, 10.0),
+///     "1 genre 2 genre 3 quoi"
+/// );
+///
+/// let fr = Customized::new(French::default()).separator_words(["et"]);
+/// assert_eq!(
+///     replace_numbers_in_text("cent et un", &fr, 0.0),
+///     "100 et 1"
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Customized<L> {
+    lang: L,
+    linking: HashSet<String>,
+    separators: HashSet<String>,
+}
+
+impl<L: LangInterpreter> Customized<L> {
+    /// Wrap `lang`, without any extra word yet.
+    pub fn new(lang: L) -> Self {
+        Self {
+            lang,
+            linking: HashSet::new(),
+            separators: HashSet::new(),
+        }
+    }
+
+    /// Add `words` to the linking words of the language.
+    pub fn linking_words<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.linking
+            .extend(words.into_iter().map(|word| word.as_ref().to_lowercase()));
+        self
+    }
+
+    /// Add `words` to the words that always end a number and isolate it.
+    pub fn separator_words<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.separators
+            .extend(words.into_iter().map(|word| word.as_ref().to_lowercase()));
+        self
+    }
+
+    /// The wrapped interpreter.
+    pub fn inner(&self) -> &L {
+        &self.lang
+    }
+
+    fn separates(&self, word: &str) -> bool {
+        !self.separators.is_empty() && self.separators.contains(&word.trim().to_lowercase())
+    }
+}
+
+impl<L: LangInterpreter> LangInterpreter for Customized<L> {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        if self.separates(num_func) {
+            return Err(Error::NaN);
+        }
+        self.lang.apply(num_func, b)
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        if self.separates(decimal_func) {
+            return Err(Error::NaN);
+        }
+        self.lang.apply_decimal(decimal_func, b)
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        self.lang.get_morph_marker(word)
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        if self.separates(word) {
+            return None;
+        }
+        self.lang.check_decimal_separator(word)
+    }
+
+    fn check_sign(&self, word: &str) -> Option<char> {
+        if self.separates(word) {
+            return None;
+        }
+        self.lang.check_sign(word)
+    }
+
+    fn decimal_separator(&self) -> char {
+        self.lang.decimal_separator()
+    }
+
+    fn apply_denominator(
+        &self,
+        frac_func: &str,
+        numerator: &DigitString,
+        b: &mut DigitString,
+    ) -> Result<(), Error> {
+        if self.separates(frac_func) {
+            return Err(Error::NaN);
+        }
+        self.lang.apply_denominator(frac_func, numerator, b)
+    }
+
+    fn is_fraction_link(&self, word: &str) -> bool {
+        !self.separates(word) && self.lang.is_fraction_link(word)
+    }
+
+    fn format_and_value(&self, b: &DigitString) -> (String, f64) {
+        self.lang.format_and_value(b)
+    }
+
+    fn format_decimal_and_value(
+        &self,
+        int: &DigitString,
+        dec: &DigitString,
+        sep: char,
+    ) -> (String, f64) {
+        self.lang.format_decimal_and_value(int, dec, sep)
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        if self.separates(word) {
+            return false;
+        }
+        self.lang.is_linking(word)
+            || !self.linking.is_empty() && self.linking.contains(&word.trim().to_lowercase())
+    }
+
+    fn is_separator(&self, word: &str) -> bool {
+        self.separates(word) || self.lang.is_separator(word)
+    }
+
+    fn segment<'a>(&'a self, word: &'a str) -> Option<Vec<&'a str>> {
+        self.lang.segment(word)
+    }
+
+    fn basic_annotate<T: BasicAnnotate>(&self, tokens: &mut Vec<T>) {
+        self.lang.basic_annotate(tokens)
+    }
+}
+
+impl<L: Verbalize> Verbalize for Customized<L> {
+    fn verbalize(&self, n: u64) -> Option<String> {
+        self.lang.verbalize(n)
+    }
+
+    fn verbalize_ordinal(&self, n: u64, gender: Gender, plural: bool) -> Option<String> {
+        self.lang.verbalize_ordinal(n, gender, plural)
+    }
+
+    fn verbalize_decimal(&self, value: &Decimal) -> Option<String> {
+        self.lang.verbalize_decimal(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Customized;
+    use crate::lang::{English, French, Language, Verbalize};
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    #[test]
+    fn test_linking_words() {
+        let en = English::new();
+        let custom = Customized::new(English::new()).linking_words(["like", "You", "know"]);
+        let text = "one like two you know three";
+        assert_eq!(replace_numbers_in_text(text, &en, 10.0), text);
+        assert_eq!(
+            replace_numbers_in_text(text, &custom, 10.0),
+            "1 like 2 you know 3"
+        );
+        assert_eq!(
+            replace_numbers_in_text("One Like two", &custom, 10.0),
+            "1 Like 2"
+        );
+        // The builtin linking words still link
+        assert_eq!(
+            replace_numbers_in_text("one uh two like three", &custom, 10.0),
+            "1 uh 2 like 3"
+        );
+    }
+
+    #[test]
+    fn test_separator_words() {
+        let custom = Customized::new(English::new()).separator_words(["and", "uh", "|"]);
+        assert_eq!(
+            replace_numbers_in_text("one hundred and five", &English::new(), 0.0),
+            "105"
+        );
+        assert_eq!(
+            replace_numbers_in_text("one hundred and five", &custom, 0.0),
+            "100 and 5"
+        );
+        assert!(text2digits("one hundred and five", &custom).is_err());
+        // No longer linking
+        assert_eq!(
+            replace_numbers_in_text("one uh two uh three", &custom, 10.0),
+            "one uh two uh three"
+        );
+        // Punctuation usually doesn't isolate numbers
+        assert_eq!(
+            replace_numbers_in_text("one / two / three", &custom, 10.0),
+            "1 / 2 / 3"
+        );
+        assert_eq!(
+            replace_numbers_in_text("one | two | three", &custom, 10.0),
+            "one | two | three"
+        );
+    }
+
+    #[test]
+    fn test_separator_over_linking() {
+        let custom = Customized::new(French::default())
+            .linking_words(["genre"])
+            .separator_words(["genre", "virgule"]);
+        assert_eq!(
+            replace_numbers_in_text("un genre deux", &custom, 10.0),
+            "un genre deux"
+        );
+        assert_eq!(
+            replace_numbers_in_text("douze virgule cinq", &custom, 0.0),
+            "12 virgule 5"
+        );
+    }
+
+    #[test]
+    fn test_language() {
+        let custom = Customized::new(Language::spanish()).linking_words(["o", "sea"]);
+        assert_eq!(
+            replace_numbers_in_text("uno o sea dos o sea tres", &custom, 10.0),
+            "1 o sea 2 o sea 3"
+        );
+        assert_eq!(custom.verbalize(85).unwrap(), "ochenta y cinco");
+    }
+}
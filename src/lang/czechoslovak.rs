@@ -0,0 +1,178 @@
+//! Shared core of the Czech and Slovak interpreters
+//!
+//! Both languages build their numbers the same way: the units follow the tens, "*dvacet jedna*", the hundreds and the
+//! thousands are multiplied by the unit before them, "*tři sta*", "*dva tisíce*", and the ordinals decline like
+//! adjectives. They differ in their words, in the inverted Czech form "*jednadvacet*", and in the solid Slovak
+//! compounds like "*dvestodvadsaťpäť*".
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+use crate::tokenizer::WordSplitter;
+
+use super::{LangInterpreter, MorphologicalMarker};
+
+/// The meaning of a simple number word.
+#[derive(Debug, Clone, Copy)]
+pub(super) enum Numeral {
+    Zero,
+    /// A digit from 1 to 9
+    Unit(u8),
+    /// A number that is put as is, like the teens
+    Digits(&'static [u8]),
+    /// The tens digit, from 2 to 9
+    Tens(u8),
+    /// A unit before the tens, like "*jednadvacet*"
+    Inverted(u8, u8),
+    /// "*sto*", either alone or multiplied by the unit before it
+    Hundred,
+    /// "*stě*", "*sta*" or "*set*", always multiplied by the unit before it
+    Hundreds,
+    Thousand,
+    Million,
+    Milliard,
+}
+
+/// What Czech or Slovak provides to the shared core.
+pub(super) trait Dialect: LangInterpreter {
+    /// The adjective endings of the ordinals, longest first
+    const ORDINAL_ENDINGS: &'static [&'static str];
+    /// The units that can come before the tens in a single word, with their linking "*a*"
+    const INVERTED_UNITS: &'static [(&'static str, u8)];
+
+    /// The splitter of the solid compounds, if the language writes them.
+    fn word_splitter(&self) -> Option<&WordSplitter>;
+
+    /// The meaning of a cardinal word.
+    fn cardinal(&self, word: &str) -> Option<Numeral>;
+
+    /// The meaning of an ordinal stem, the ordinal without its adjective ending.
+    fn ordinal(&self, stem: &str) -> Option<Numeral>;
+}
+
+/// The meaning of a number word, and whether it is an ordinal.
+fn numeral<D: Dialect>(lang: &D, word: &str) -> Option<(Numeral, bool)> {
+    if let Some(numeral) = lang.cardinal(word) {
+        return Some((numeral, false));
+    }
+    let ordinal = D::ORDINAL_ENDINGS.iter().find_map(|ending| {
+        word.strip_suffix(ending)
+            .and_then(|stem| lang.ordinal(stem))
+            .map(|numeral| (numeral, true))
+    });
+    if ordinal.is_some() {
+        return ordinal;
+    }
+    // "jednadvacet", "pětadvacátý"
+    D::INVERTED_UNITS.iter().find_map(|(prefix, unit)| {
+        match numeral(lang, word.strip_prefix(prefix)?)? {
+            (Numeral::Tens(tens), ordinal) => Some((Numeral::Inverted(*unit, tens), ordinal)),
+            _ => None,
+        }
+    })
+}
+
+/// Is the number a single unit from 2 on, possibly after some thousands?
+fn ends_with_lone_unit(b: &DigitString) -> bool {
+    matches!(b.peek(3), [unit] | [b'0', unit] | [b'0', b'0', unit] if *unit >= b'2')
+}
+
+pub(super) fn apply<D: Dialect>(
+    lang: &D,
+    num_func: &str,
+    b: &mut DigitString,
+) -> Result<(), Error> {
+    if let Some(splitter) = lang.word_splitter()
+        && splitter.is_splittable(num_func)
+    {
+        return match lang.exec_group(splitter.split(num_func)) {
+            Ok(ds) => {
+                if ds.len() > 3 && ds.len() <= 6 && !b.is_range_free(3, 5) {
+                    return Err(Error::Overlap);
+                }
+                b.put(&ds)?;
+                if ds.marker.is_ordinal() {
+                    b.marker = ds.marker;
+                    b.freeze()
+                }
+                Ok(())
+            }
+            Err(err) => Err(err),
+        };
+    }
+    let (numeral, ordinal) = numeral(lang, num_func).ok_or(Error::NaN)?;
+    // Every part of a compound ordinal after the hundreds is ordinal: "sto dvacátý pátý"
+    if !ordinal && b.marker.is_ordinal() {
+        return Err(Error::Overlap);
+    }
+    match numeral {
+        Numeral::Zero => b.put(b"0"),
+        Numeral::Unit(digit) => {
+            let after_tens = b.len() >= 2 && b.peek(1) == b"0" && b.peek(2)[0] >= b'2';
+            if b.is_free(2) || after_tens {
+                b.put(&[digit])
+            } else {
+                Err(Error::Overlap)
+            }
+        }
+        Numeral::Digits(digits) => b.put(digits),
+        Numeral::Tens(digit) => b.put(&[digit, b'0']),
+        Numeral::Inverted(unit, tens) => b.put(&[tens, unit]),
+        Numeral::Hundred if ends_with_lone_unit(b) => b.shift(2),
+        Numeral::Hundred => b.put(b"100"),
+        Numeral::Hundreds if ends_with_lone_unit(b) => b.shift(2),
+        Numeral::Thousand if b.is_range_free(3, 5) => b.shift(3),
+        Numeral::Million if b.is_range_free(6, 8) => b.shift(6),
+        Numeral::Milliard if b.is_range_free(9, 11) => b.shift(9),
+        _ => Err(Error::NaN),
+    }?;
+    if ordinal {
+        b.marker = MorphologicalMarker::Ordinal(".");
+        // The tens and the multipliers may be followed by more ordinals: "dvacátý první", "stý první"
+        if b.peek(1) != b"0" {
+            b.freeze();
+        }
+    }
+    Ok(())
+}
+
+pub(super) fn check_decimal_separator(word: &str) -> Option<char> {
+    match word {
+        // "tři celé čtrnáct"
+        "celá" | "celé" | "celých" | "čárka" | "čiarka" => Some(','),
+        _ => None,
+    }
+}
+
+pub(super) fn get_morph_marker<D: Dialect>(lang: &D, word: &str) -> MorphologicalMarker {
+    // Only the last part of a compound is ordinal
+    let last = lang
+        .word_splitter()
+        .and_then(|splitter| splitter.split(word).last())
+        .unwrap_or(word);
+    match numeral(lang, last) {
+        Some((_, true)) => MorphologicalMarker::Ordinal("."),
+        _ => MorphologicalMarker::None,
+    }
+}
+
+pub(super) fn format_and_value(b: &DigitString) -> (String, f64) {
+    let repr = b.to_string();
+    let val: f64 = repr.parse().unwrap();
+    if let MorphologicalMarker::Ordinal(marker) = b.marker {
+        (format!("{repr}{marker}"), val)
+    } else {
+        (repr, val)
+    }
+}
+
+pub(super) fn format_decimal_and_value(
+    int: &DigitString,
+    dec: &DigitString,
+    sep: char,
+) -> (String, f64) {
+    let irepr = int.to_string();
+    let drepr = dec.to_string();
+    let frepr = format!("{irepr}{sep}{drepr}");
+    let val = format!("{irepr}.{drepr}").parse().unwrap();
+    (frepr, val)
+}
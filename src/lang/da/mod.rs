@@ -0,0 +1,343 @@
+//! Danish number interpreter
+//!
+//! Danish numbers put the units before the tens, "femogtyve", and count the tens from fifty by scores
+//! ("halvtreds", "tres", "halvfjerds", "firs", "halvfems"). The interpreter accepts compounds split in several words,
+//! as the main application, Speech-to-text recognition, may introduce spurious spaces.
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+use crate::tokenizer::WordSplitter;
+
+mod vocabulary;
+
+use super::scandinavian::{self, Dialect, Numeral};
+use super::{LangInterpreter, MorphologicalMarker};
+use vocabulary::INSIGNIFICANT;
+
+pub struct Danish {
+    word_splitter: WordSplitter,
+}
+
+impl Default for Danish {
+    fn default() -> Self {
+        Self {
+            word_splitter: WordSplitter::new([
+                "tyve",
+                "tyvende",
+                "tredive",
+                "tredivte",
+                "fyrre",
+                "fyrretyve",
+                "fyrretyvende",
+                "halvtreds",
+                "halvtredsindstyve",
+                "halvtredsindstyvende",
+                "tres",
+                "tresindstyve",
+                "tresindstyvende",
+                "halvfjerds",
+                "halvfjerdsindstyve",
+                "halvfjerdsindstyvende",
+                "firs",
+                "firsindstyve",
+                "firsindstyvende",
+                "halvfems",
+                "halvfemsindstyve",
+                "halvfemsindstyvende",
+                "hundred",
+                "hundrede",
+                "tusind",
+                "tusinde",
+                "million",
+                "millioner",
+                "millionte",
+                "milliard",
+                "milliarder",
+                "milliardte",
+                // connector
+                "og",
+            ])
+            .unwrap(),
+        }
+    }
+}
+
+impl Danish {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl Dialect for Danish {
+    const UNITS_FIRST: bool = true;
+    const TENS_FIRST: bool = false;
+    const HALVES: &'static [&'static str] = &["halv", "halvt", "halve", "halvdel", "halvdele"];
+    const PARTS: &'static [&'static str] = &["del", "dele"];
+
+    fn word_splitter(&self) -> &WordSplitter {
+        &self.word_splitter
+    }
+
+    fn numeral(&self, word: &str) -> Option<(Numeral, Option<&'static str>)> {
+        let numeral = match word {
+            "nul" => (Numeral::Zero, None),
+            "en" | "et" => (Numeral::Unit(b'1'), None),
+            "første" => (Numeral::Unit(b'1'), Some(".")),
+            "to" => (Numeral::Unit(b'2'), None),
+            "anden" | "andet" => (Numeral::Unit(b'2'), Some(".")),
+            "tre" => (Numeral::Unit(b'3'), None),
+            "tredje" => (Numeral::Unit(b'3'), Some(".")),
+            "fire" => (Numeral::Unit(b'4'), None),
+            "fjerde" => (Numeral::Unit(b'4'), Some(".")),
+            "fem" => (Numeral::Unit(b'5'), None),
+            "femte" => (Numeral::Unit(b'5'), Some(".")),
+            "seks" => (Numeral::Unit(b'6'), None),
+            "sjette" => (Numeral::Unit(b'6'), Some(".")),
+            "syv" => (Numeral::Unit(b'7'), None),
+            "syvende" => (Numeral::Unit(b'7'), Some(".")),
+            "otte" => (Numeral::Unit(b'8'), None),
+            "ottende" => (Numeral::Unit(b'8'), Some(".")),
+            "ni" => (Numeral::Unit(b'9'), None),
+            "niende" => (Numeral::Unit(b'9'), Some(".")),
+            "ti" => (Numeral::Teen(b"10"), None),
+            "tiende" => (Numeral::Teen(b"10"), Some(".")),
+            "elleve" => (Numeral::Teen(b"11"), None),
+            "ellevte" => (Numeral::Teen(b"11"), Some(".")),
+            "tolv" => (Numeral::Teen(b"12"), None),
+            "tolvte" => (Numeral::Teen(b"12"), Some(".")),
+            "tretten" => (Numeral::Teen(b"13"), None),
+            "trettende" => (Numeral::Teen(b"13"), Some(".")),
+            "fjorten" => (Numeral::Teen(b"14"), None),
+            "fjortende" => (Numeral::Teen(b"14"), Some(".")),
+            "femten" => (Numeral::Teen(b"15"), None),
+            "femtende" => (Numeral::Teen(b"15"), Some(".")),
+            "seksten" => (Numeral::Teen(b"16"), None),
+            "sekstende" => (Numeral::Teen(b"16"), Some(".")),
+            "sytten" => (Numeral::Teen(b"17"), None),
+            "syttende" => (Numeral::Teen(b"17"), Some(".")),
+            "atten" => (Numeral::Teen(b"18"), None),
+            "attende" => (Numeral::Teen(b"18"), Some(".")),
+            "nitten" => (Numeral::Teen(b"19"), None),
+            "nittende" => (Numeral::Teen(b"19"), Some(".")),
+            "tyve" => (Numeral::Tens(b'2'), None),
+            "tyvende" => (Numeral::Tens(b'2'), Some(".")),
+            "tredive" => (Numeral::Tens(b'3'), None),
+            "tredivte" => (Numeral::Tens(b'3'), Some(".")),
+            "fyrre" | "fyrretyve" => (Numeral::Tens(b'4'), None),
+            "fyrretyvende" => (Numeral::Tens(b'4'), Some(".")),
+            "halvtreds" | "halvtredsindstyve" => (Numeral::Tens(b'5'), None),
+            "halvtredsindstyvende" => (Numeral::Tens(b'5'), Some(".")),
+            "tres" | "tresindstyve" => (Numeral::Tens(b'6'), None),
+            "tresindstyvende" => (Numeral::Tens(b'6'), Some(".")),
+            "halvfjerds" | "halvfjerdsindstyve" => (Numeral::Tens(b'7'), None),
+            "halvfjerdsindstyvende" => (Numeral::Tens(b'7'), Some(".")),
+            "firs" | "firsindstyve" => (Numeral::Tens(b'8'), None),
+            "firsindstyvende" => (Numeral::Tens(b'8'), Some(".")),
+            "halvfems" | "halvfemsindstyve" => (Numeral::Tens(b'9'), None),
+            "halvfemsindstyvende" => (Numeral::Tens(b'9'), Some(".")),
+            "hundred" | "hundrede" => (Numeral::Hundred, None),
+            "tusind" | "tusinde" => (Numeral::Thousand, None),
+            "million" | "millioner" => (Numeral::Million, None),
+            "millionte" => (Numeral::Million, Some(".")),
+            "milliard" | "milliarder" => (Numeral::Milliard, None),
+            "milliardte" => (Numeral::Milliard, Some(".")),
+            "og" => (Numeral::And, None),
+            _ => return None,
+        };
+        Some(numeral)
+    }
+}
+
+impl LangInterpreter for Danish {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        scandinavian::apply(self, num_func, b)
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        self.apply(decimal_func, b)
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        if word == "komma" { Some(',') } else { None }
+    }
+
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "minus" => Some('-'),
+            "plus" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn apply_denominator(
+        &self,
+        frac_func: &str,
+        numerator: &DigitString,
+        b: &mut DigitString,
+    ) -> Result<(), Error> {
+        scandinavian::apply_denominator(self, frac_func, numerator, b)
+    }
+
+    fn is_fraction_link(&self, word: &str) -> bool {
+        matches!(word, "og" | "en" | "et")
+    }
+
+    fn format_and_value(&self, b: &DigitString) -> (String, f64) {
+        scandinavian::format_and_value(b)
+    }
+
+    fn format_decimal_and_value(
+        &self,
+        int: &DigitString,
+        dec: &DigitString,
+        sep: char,
+    ) -> (String, f64) {
+        scandinavian::format_decimal_and_value(int, dec, sep)
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        scandinavian::get_morph_marker(self, word)
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Danish;
+    use crate::ConversionOptions;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Danish::new();
+            let res = text2digits($text, &f);
+            dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Danish::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_fractions {
+        ($text:expr, $res:expr) => {
+            assert_replace_fractions!($text, $res, 10.0)
+        };
+        ($text:expr, $res:expr, $threshold:expr) => {
+            let f = Danish::new();
+            let options = ConversionOptions::from($threshold).convert_fractions(true);
+            assert_eq!(replace_numbers_in_text($text, &f, options), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Danish::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Danish::new();
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("nul", "0");
+        assert_text2digits!("et", "1");
+        assert_text2digits!("tolv", "12");
+        assert_text2digits!("tyve", "20");
+        assert_text2digits!("femogtyve", "25");
+        assert_text2digits!("fem og tyve", "25");
+        assert_text2digits!("enogtredive", "31");
+        assert_text2digits!("halvtreds", "50");
+        assert_text2digits!("toogtres", "62");
+        assert_text2digits!("halvfjerds", "70");
+        assert_text2digits!("syvogfirs", "87");
+        assert_text2digits!("nioghalvfems", "99");
+        assert_text2digits!("hundrede", "100");
+        assert_text2digits!("et hundrede og femten", "115");
+        assert_text2digits!("to hundrede og fireogtyve", "224");
+        assert_text2digits!("nitten hundrede og fireogfirs", "1984");
+        assert_text2digits!("tre tusind", "3000");
+        assert_text2digits!(
+            "tre og halvtreds milliarder to hundrede og treogfyrre tusind syv hundrede og fireogtyve",
+            "53000243724"
+        );
+        assert_text2digits!("en million", "1000000");
+    }
+
+    #[test]
+    fn test_vigesimal() {
+        assert_text2digits!("halvtredsindstyve", "50");
+        assert_text2digits!("femogtresindstyve", "65");
+        assert_text2digits!("fyrretyve", "40");
+        assert_text2digits!("firsindstyve", "80");
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_text2digits!("første", "1.");
+        assert_text2digits!("anden", "2.");
+        assert_text2digits!("tredje", "3.");
+        assert_text2digits!("enogtyvende", "21.");
+        assert_text2digits!("tresindstyvende", "60.");
+        assert_text2digits!("hundrede og femte", "105.");
+        assert_replace_numbers!("den femogtyvende december", "den 25. december");
+    }
+
+    #[test]
+    fn test_fractions() {
+        assert_replace_fractions!("tre fjerdedele", "3/4");
+        assert_replace_fractions!("to og en halv", "2 1/2");
+        assert_replace_fractions!("en tredjedel", "1/3");
+    }
+
+    #[test]
+    fn test_decimals() {
+        assert_replace_numbers!("tolv komma fem", "12,5");
+        assert_replace_numbers!("nul komma nul fem", "0,05");
+    }
+
+    #[test]
+    fn test_zeroes() {
+        assert_text2digits!("nul otte", "08");
+        assert_invalid!("fem nul");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("tusind tusind to hundrede");
+        assert_invalid!("tres femten");
+        assert_invalid!("ti fem");
+        assert_invalid!("femtyve");
+        assert_invalid!("tyve fem");
+        assert_invalid!("femogtyvende tre");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!(
+            "femogtyve køer, tolv høns og et hundrede og femogtyve kg kartofler.",
+            "25 køer, 12 høns og 125 kg kartofler."
+        );
+        assert_replace_numbers!("en to tre fire tyve femten", "1 2 3 4 20 15");
+        assert_replace_all_numbers!("en kat", "1 kat");
+    }
+}
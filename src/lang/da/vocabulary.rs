@@ -0,0 +1,5 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "og", "eller", "så", "altså", "ligesom", "ja", "nej", "okay", "øh", "æh", "hm", "plus", "minus", "med"
+};
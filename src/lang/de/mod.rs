@@ -9,9 +9,10 @@ use crate::digit_string::DigitString;
 use crate::error::Error;
 use crate::tokenizer::WordSplitter;
 
+mod verbalize;
 mod vocabulary;
 
-use super::{LangInterpreter, MorphologicalMarker};
+use super::{BasicAnnotate, LangInterpreter, MorphologicalMarker, ordinal_denominator};
 use vocabulary::INSIGNIFICANT;
 
 fn lemmatize(word: &str) -> &str {
@@ -32,6 +33,8 @@ bitflags! {
     ///(logical, numerical feature inconsistencies are already taken care of by DigitString)
     struct Excludable: u64 {
         const TENS = 1;
+        /// "*eine*" only counts before "*Million*" or "*Milliarde*"
+        const EINE = 2;
     }
 }
 
@@ -79,6 +82,9 @@ impl LangInterpreter for German {
                         return Err(Error::Overlap);
                     }
                     b.put(&ds)?;
+                    if Excludable::from_bits_truncate(ds.flags).contains(Excludable::EINE) {
+                        b.flags = Excludable::EINE.bits();
+                    }
                     if ds.marker.is_ordinal() {
                         b.marker = ds.marker;
                         b.freeze()
@@ -91,12 +97,22 @@ impl LangInterpreter for German {
         let blocked = Excludable::from_bits_truncate(b.flags);
         let mut to_block = Excludable::empty();
 
+        if blocked.contains(Excludable::EINE)
+            && !matches!(lemma, "million" | "millionen" | "milliarde" | "milliarden")
+        {
+            b.flags = 0;
+            return Err(Error::NaN);
+        }
         let status = match lemma {
             "null" => b.put(b"0"),
             "ein" | "eins" | "erste" if b.is_free(2) => {
                 to_block = Excludable::TENS;
                 b.put(b"1")
             }
+            "eine" if b.is_free(2) => {
+                to_block = Excludable::TENS | Excludable::EINE;
+                b.put(b"1")
+            }
             "zwei" | "zwo" | "zweite" if b.is_free(2) => {
                 to_block = Excludable::TENS;
                 b.put(b"2")
@@ -167,7 +183,7 @@ impl LangInterpreter for German {
             }
             "hundert" | "hundertste" => {
                 let peek = b.peek(2);
-                if peek.len() == 1 || peek < b"20" {
+                if peek.len() == 1 || peek < b"20".as_slice() {
                     b.shift(2)
                 } else {
                     Err(Error::Overlap)
@@ -220,11 +236,51 @@ impl LangInterpreter for German {
         }
     }
 
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "minus" => Some('-'),
+            "plus" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn apply_denominator(
+        &self,
+        frac_func: &str,
+        numerator: &DigitString,
+        b: &mut DigitString,
+    ) -> Result<(), Error> {
+        match frac_func {
+            "halb" | "halbe" | "halbes" | "halben" | "hälfte" => b.put(b"2"),
+            // Only in a mixed number, like "zweieinhalb"
+            "einhalb" if numerator.len() == 1 && numerator.peek(1) == b"1" => b.put(b"2"),
+            // "Drittel", "Viertel", "Zwanzigstel"… are the ordinals with an "l"
+            _ => match frac_func.strip_suffix("tel") {
+                Some(stem) => ordinal_denominator(self, &format!("{stem}te"), numerator, false, b),
+                None => Err(Error::NaN),
+            },
+        }
+    }
+
+    fn is_fraction_link(&self, word: &str) -> bool {
+        matches!(word, "und")
+    }
+
+    fn split_mixed<'a>(&self, word: &'a str) -> Option<(&'a str, &'a str)> {
+        // "zweieinhalb", "dreieinhalb"…
+        let int = word.strip_suffix("einhalb")?;
+        (!int.is_empty()).then_some((int, "einhalb"))
+    }
+
     fn format_and_value(&self, b: &DigitString) -> (String, f64) {
         let repr = b.to_string();
         let val: f64 = repr.parse().unwrap();
         if let MorphologicalMarker::Ordinal(marker) = b.marker {
-            (format!("{}{}", b.to_string(), marker), val)
+            (format!("{repr}{marker}"), val)
         } else {
             (repr, val)
         }
@@ -254,11 +310,39 @@ impl LangInterpreter for German {
     fn is_linking(&self, word: &str) -> bool {
         INSIGNIFICANT.contains(word)
     }
+
+    fn basic_annotate<T: BasicAnnotate>(&self, tokens: &mut Vec<T>) {
+        // "eine" is an article, unless it counts millions or billions
+        let significant_tokens_indices: Vec<usize> = tokens
+            .iter()
+            .enumerate()
+            .filter_map(|(i, t)| {
+                if !t.text_lowercase().chars().all(|c| c.is_ascii_whitespace()) {
+                    Some(i)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        for (j, &i) in significant_tokens_indices.iter().enumerate() {
+            if tokens[i].text_lowercase() == "eine"
+                && !significant_tokens_indices.get(j + 1).is_some_and(|&next| {
+                    matches!(
+                        tokens[next].text_lowercase(),
+                        "million" | "millionen" | "milliarde" | "milliarden"
+                    )
+                })
+            {
+                tokens[i].set_nan(true);
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::German;
+    use crate::ConversionOptions;
     use crate::word_to_digit::{replace_numbers_in_text, text2digits};
 
     macro_rules! assert_text2digits {
@@ -278,6 +362,17 @@ mod tests {
         };
     }
 
+    macro_rules! assert_replace_fractions {
+        ($text:expr, $res:expr) => {
+            assert_replace_fractions!($text, $res, 10.0)
+        };
+        ($text:expr, $res:expr, $threshold:expr) => {
+            let f = German::new();
+            let options = ConversionOptions::from($threshold).convert_fractions(true);
+            assert_eq!(replace_numbers_in_text($text, &f, options), $res)
+        };
+    }
+
     macro_rules! assert_replace_all_numbers {
         ($text:expr, $res:expr) => {
             let f = German::new();
@@ -316,6 +411,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_eine_million() {
+        assert_text2digits!("eine Million", "1000000");
+        assert_text2digits!("eine Milliarde zweihunderteine Millionen", "1201000000");
+        assert_invalid!("eine zwei");
+        assert_invalid!("eine tausend");
+        assert_replace_numbers!("Es kostet eine Million Euro", "Es kostet 1000000 Euro");
+        assert_replace_all_numbers!("Eine Million und eine Frau", "1000000 und eine Frau");
+    }
+
     #[test]
     fn test_ordinals() {
         assert_text2digits!("einundzwanzigster", "21.");
@@ -478,6 +583,18 @@ mod tests {
         assert_replace_all_numbers!("eins komma erste", "1 komma 1.");
     }
 
+    #[test]
+    fn test_fractions() {
+        assert_replace_fractions!("zwei Drittel und drei Viertel", "2/3 und 3/4");
+        assert_replace_fractions!("ein halb", "1/2");
+        assert_replace_fractions!("fünf Hundertstel", "5/100");
+        assert_replace_fractions!("zwei und ein halb Stunden", "2 1/2 Stunden");
+        assert_replace_fractions!("zwei und drei Viertel", "2 3/4");
+        assert_replace_fractions!("zweieinhalb Stunden", "2 1/2 Stunden");
+        assert_replace_fractions!("hundertdreieinhalb", "103 1/2");
+        assert_replace_fractions!("zwei einhalb", "2 einhalb", 0.0);
+    }
+
     #[test]
     fn test_replace_signed() {
         assert_replace_numbers!(
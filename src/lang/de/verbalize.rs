@@ -0,0 +1,243 @@
+//! Spelling out numbers in German.
+
+use super::German;
+use crate::decimal::Decimal;
+use crate::lang::{Gender, Verbalize, decimal_parts};
+
+const UNITS: [&str; 20] = [
+    "null",
+    "eins",
+    "zwei",
+    "drei",
+    "vier",
+    "fünf",
+    "sechs",
+    "sieben",
+    "acht",
+    "neun",
+    "zehn",
+    "elf",
+    "zwölf",
+    "dreizehn",
+    "vierzehn",
+    "fünfzehn",
+    "sechzehn",
+    "siebzehn",
+    "achtzehn",
+    "neunzehn",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig", "neunzig",
+];
+
+/// The unit as the first part of a compound: "*ein*" instead of "*eins*".
+fn prefix(n: usize) -> &'static str {
+    if n == 1 { "ein" } else { UNITS[n] }
+}
+
+fn below_hundred(n: usize) -> String {
+    match (n / 10, n % 10) {
+        _ if n < 20 => UNITS[n].to_owned(),
+        (tens, 0) => TENS[tens].to_owned(),
+        (tens, units) => format!("{}und{}", prefix(units), TENS[tens]),
+    }
+}
+
+/// A number below one million, as a single word.
+fn below_million(n: usize) -> String {
+    let mut word = String::new();
+    let (thousands, hundreds, rest) = (n / 1000, n / 100 % 10, n % 100);
+    if thousands > 0 {
+        word.push_str(&below_thousand_prefix(thousands));
+        word.push_str("tausend");
+    }
+    if hundreds > 0 {
+        word.push_str(prefix(hundreds));
+        word.push_str("hundert");
+    }
+    if rest > 0 {
+        word.push_str(&below_hundred(rest));
+    }
+    word
+}
+
+/// A number below one thousand, used as the first part of a compound.
+fn below_thousand_prefix(n: usize) -> String {
+    match (n / 100, n % 100) {
+        (0, 1) => "ein".to_owned(),
+        (0, rest) => below_hundred(rest),
+        (hundreds, 0) => format!("{}hundert", prefix(hundreds)),
+        (hundreds, 1) => format!("{}hundertein", prefix(hundreds)),
+        (hundreds, rest) => format!("{}hundert{}", prefix(hundreds), below_hundred(rest)),
+    }
+}
+
+fn cardinal(n: u64) -> Option<String> {
+    if n >= 1_000_000_000_000 {
+        return None;
+    }
+    if n == 0 {
+        return Some(UNITS[0].to_owned());
+    }
+    let mut words: Vec<String> = Vec::with_capacity(5);
+    for (scale, singular, plural) in [
+        (1_000_000_000, "Milliarde", "Milliarden"),
+        (1_000_000, "Million", "Millionen"),
+    ] {
+        match (n / scale % 1000) as usize {
+            0 => (),
+            1 => words.extend(["eine".to_owned(), singular.to_owned()]),
+            count if count % 100 == 1 => words.extend([
+                format!("{}e", below_thousand_prefix(count)),
+                plural.to_owned(),
+            ]),
+            count => words.extend([below_million(count), plural.to_owned()]),
+        }
+    }
+    let rest = (n % 1_000_000) as usize;
+    if rest > 0 {
+        words.push(below_million(rest));
+    }
+    Some(words.join(" "))
+}
+
+fn ordinal_word(word: &str) -> String {
+    for (cardinal, ordinal) in [
+        ("eins", "erste"),
+        ("drei", "dritte"),
+        ("sieben", "siebte"),
+        ("acht", "achte"),
+    ] {
+        if let Some(stem) = word.strip_suffix(cardinal) {
+            return format!("{stem}{ordinal}");
+        }
+    }
+    if word.ends_with("zig")
+        || word.ends_with("ßig")
+        || word.ends_with("hundert")
+        || word.ends_with("tausend")
+    {
+        format!("{word}ste")
+    } else {
+        format!("{word}te")
+    }
+}
+
+impl Verbalize for German {
+    fn verbalize(&self, n: u64) -> Option<String> {
+        cardinal(n)
+    }
+
+    fn verbalize_ordinal(&self, n: u64, _gender: Gender, plural: bool) -> Option<String> {
+        if n == 0 {
+            return None;
+        }
+        let words = cardinal(n)?;
+        let mut words: Vec<&str> = words.split(' ').collect();
+        let last = words.pop()?;
+        let mut ordinal = match last {
+            "Million" | "Millionen" | "Milliarde" | "Milliarden" => {
+                // Ordinals are adjectives, written in a single word with their multiplier.
+                let stem = if last.starts_with("Million") {
+                    "millionste"
+                } else {
+                    "milliardste"
+                };
+                match words.pop() {
+                    Some("eine") | None => stem.to_owned(),
+                    Some(count) => format!("{}{stem}", count.trim_end_matches('e')),
+                }
+            }
+            _ => ordinal_word(last),
+        };
+        if plural {
+            ordinal.push('n');
+        }
+        words.push(&ordinal);
+        Some(words.join(" "))
+    }
+
+    fn verbalize_decimal(&self, value: &Decimal) -> Option<String> {
+        let (int, dec) = decimal_parts(value)?;
+        let mut words = cardinal(int)?;
+        if !dec.is_empty() {
+            words.push_str(" Komma");
+            for digit in dec.bytes() {
+                words.push(' ');
+                words.push_str(UNITS[(digit - b'0') as usize]);
+            }
+        }
+        Some(words)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    #[test]
+    fn test_cardinals() {
+        let de = German::new();
+        assert_eq!(de.verbalize(1).unwrap(), "eins");
+        assert_eq!(de.verbalize(21).unwrap(), "einundzwanzig");
+        assert_eq!(de.verbalize(101).unwrap(), "einhunderteins");
+        assert_eq!(
+            de.verbalize(1973).unwrap(),
+            "eintausendneunhundertdreiundsiebzig"
+        );
+        assert_eq!(de.verbalize(1_000_000).unwrap(), "eine Million");
+        assert_eq!(
+            de.verbalize(53_000_243_724).unwrap(),
+            "dreiundfünfzig Milliarden zweihundertdreiundvierzigtausendsiebenhundertvierundzwanzig"
+        );
+        assert_eq!(
+            de.verbalize(201_000_000).unwrap(),
+            "zweihunderteine Millionen"
+        );
+    }
+
+    #[test]
+    fn test_ordinals() {
+        let de = German::new();
+        let ordinal = |n| de.verbalize_ordinal(n, Gender::Feminine, false).unwrap();
+        assert_eq!(ordinal(1), "erste");
+        assert_eq!(ordinal(3), "dritte");
+        assert_eq!(ordinal(19), "neunzehnte");
+        assert_eq!(ordinal(20), "zwanzigste");
+        assert_eq!(ordinal(101), "einhunderterste");
+        assert_eq!(ordinal(1_000_000), "millionste");
+        assert_eq!(ordinal(2_000_000), "zweimillionste");
+        assert_eq!(
+            de.verbalize_ordinal(7, Gender::Masculine, true).unwrap(),
+            "siebten"
+        );
+    }
+
+    #[test]
+    fn test_decimals() {
+        let de = German::new();
+        let value: Decimal = "3.14".parse().unwrap();
+        let words = de.verbalize_decimal(&value).unwrap();
+        assert_eq!(words, "drei Komma eins vier");
+        assert_eq!(replace_numbers_in_text(&words, &de, 0.0), "3,14");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let de = German::new();
+        for n in (0..2000).chain((1..10_000_000_000).step_by(7_919_111)) {
+            let words = de.verbalize(n).unwrap();
+            assert_eq!(text2digits(&words, &de).unwrap(), n.to_string(), "{words}");
+            if n > 0 {
+                let words = de.verbalize_ordinal(n, Gender::Masculine, false).unwrap();
+                assert_eq!(
+                    text2digits(&words, &de).unwrap(),
+                    format!("{n}."),
+                    "{words}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,381 @@
+//! Greek number interpreter
+//!
+//! Greek numerals agree in gender and case with the noun they count ("ένας", "μία", "ένα"; "διακόσιοι", "διακόσιες",
+//! "διακόσια"). Ordinals are written with their ending: "*πέμπτος*" becomes "*5ος*", "*πέμπτη*" becomes "*5η*".
+//!
+//! Words are matched without their accents, as Speech-to-text output often drops the tonos.
+
+use std::borrow::Cow;
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+
+mod vocabulary;
+
+use super::{LangInterpreter, MorphologicalMarker};
+use vocabulary::INSIGNIFICANT;
+
+/// The ordinal endings, longest first.
+const ORDINAL_ENDINGS: [&str; 5] = ["ος", "ου", "ης", "ο", "η"];
+
+/// Remove the tonos and the dialytika.
+fn strip_accents(word: &str) -> Cow<'_, str> {
+    if word.contains(['ά', 'έ', 'ή', 'ί', 'ό', 'ύ', 'ώ', 'ϊ', 'ϋ', 'ΐ', 'ΰ']) {
+        Cow::Owned(
+            word.chars()
+                .map(|c| match c {
+                    'ά' => 'α',
+                    'έ' => 'ε',
+                    'ή' => 'η',
+                    'ί' | 'ϊ' | 'ΐ' => 'ι',
+                    'ό' => 'ο',
+                    'ύ' | 'ϋ' | 'ΰ' => 'υ',
+                    'ώ' => 'ω',
+                    c => c,
+                })
+                .collect(),
+        )
+    } else {
+        Cow::Borrowed(word)
+    }
+}
+
+/// Split an ordinal into the cardinal it stands for, its ending, and whether it must end the number.
+fn split_ordinal(word: &str) -> Option<(&'static str, &'static str, bool)> {
+    ORDINAL_ENDINGS.iter().find_map(|&ending| {
+        let stem = word.strip_suffix(ending)?;
+        let (cardinal, last) = match stem {
+            "πρωτ" => ("ενα", true),
+            "δευτερ" => ("δυο", true),
+            "τριτ" => ("τρια", true),
+            "τεταρτ" => ("τεσσερα", true),
+            "πεμπτ" => ("πεντε", true),
+            "εκτ" => ("εξι", true),
+            "εβδομ" => ("επτα", true),
+            "ογδο" => ("οκτω", true),
+            "ενατ" => ("εννεα", true),
+            "δεκατ" => ("δεκα", false),
+            "ενδεκατ" => ("εντεκα", true),
+            "δωδεκατ" => ("δωδεκα", true),
+            "εικοστ" => ("εικοσι", false),
+            "τριακοστ" => ("τριαντα", false),
+            "τεσσαρακοστ" => ("σαραντα", false),
+            "πεντηκοστ" => ("πενηντα", false),
+            "εξηκοστ" => ("εξηντα", false),
+            "εβδομηκοστ" => ("εβδομηντα", false),
+            "ογδοηκοστ" => ("ογδοντα", false),
+            "ενενηκοστ" => ("ενενηντα", false),
+            "εκατοστ" => ("εκατο", false),
+            "διακοσιοστ" => ("διακοσια", false),
+            "τριακοσιοστ" => ("τριακοσια", false),
+            "τετρακοσιοστ" => ("τετρακοσια", false),
+            "πεντακοσιοστ" => ("πεντακοσια", false),
+            "εξακοσιοστ" => ("εξακοσια", false),
+            "επτακοσιοστ" => ("επτακοσια", false),
+            "οκτακοσιοστ" => ("οκτακοσια", false),
+            "εννεακοσιοστ" | "εννιακοσιοστ" => ("εννιακοσια", false),
+            "χιλιοστ" => ("χιλια", false),
+            "εκατομμυριοστ" => ("εκατομμυριο", false),
+            "δισεκατομμυριοστ" => ("δισεκατομμυριο", false),
+            _ => return None,
+        };
+        Some((cardinal, ending, last))
+    })
+}
+
+/// The hundreds digit of an inflected hundred, like "*διακόσιοι*", "*διακόσιες*", "*διακόσια*" or "*διακοσίων*".
+fn hundreds(word: &str) -> Option<u8> {
+    let stem = ["οι", "ες", "α", "ων"]
+        .iter()
+        .find_map(|ending| word.strip_suffix(ending))?
+        .strip_suffix("κοσι")?;
+    match stem {
+        "δια" => Some(b'2'),
+        "τρια" => Some(b'3'),
+        "τετρα" => Some(b'4'),
+        "πεντα" => Some(b'5'),
+        "εξα" => Some(b'6'),
+        "επτα" | "εφτα" => Some(b'7'),
+        "οκτα" | "οχτα" => Some(b'8'),
+        "εννια" | "εννεα" => Some(b'9'),
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+pub struct Greek {}
+
+impl Greek {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    fn apply_cardinal(&self, word: &str, b: &mut DigitString) -> Result<(), Error> {
+        let unit = match word {
+            "ενας" | "μια" | "ενα" | "εναν" | "ενος" | "μιας" => Some(b'1'),
+            "δυο" => Some(b'2'),
+            "τρεις" | "τρια" | "τριων" => Some(b'3'),
+            "τεσσερις" | "τεσσερα" | "τεσσαρων" => Some(b'4'),
+            "πεντε" => Some(b'5'),
+            "εξι" => Some(b'6'),
+            "επτα" | "εφτα" => Some(b'7'),
+            "οκτω" | "οχτω" => Some(b'8'),
+            "εννεα" | "εννια" => Some(b'9'),
+            _ => None,
+        };
+        if let Some(digit) = unit {
+            // "δέκατος τρίτος" is the only way to say "13th"
+            let after_tens = b.len() >= 2
+                && b.peek(1) == b"0"
+                && (b.peek(2)[0] >= b'2' || b.marker.is_ordinal());
+            return if b.is_free(2) || after_tens {
+                b.put(&[digit])
+            } else {
+                Err(Error::Overlap)
+            };
+        }
+        if let Some(digit) = hundreds(word) {
+            return b.put(&[digit, b'0', b'0']);
+        }
+        match word {
+            "μηδεν" => b.put(b"0"),
+            "δεκα" => b.put(b"10"),
+            "εντεκα" => b.put(b"11"),
+            "δωδεκα" => b.put(b"12"),
+            "δεκατρια" | "δεκατρεις" => b.put(b"13"),
+            "δεκατεσσερα" | "δεκατεσσερις" => b.put(b"14"),
+            "δεκαπεντε" => b.put(b"15"),
+            "δεκαεξι" | "δεκαξι" => b.put(b"16"),
+            "δεκαεπτα" | "δεκαεφτα" => b.put(b"17"),
+            "δεκαοκτω" | "δεκαοχτω" => b.put(b"18"),
+            "δεκαεννεα" | "δεκαεννια" => b.put(b"19"),
+            "εικοσι" => b.put(b"20"),
+            "τριαντα" => b.put(b"30"),
+            "σαραντα" => b.put(b"40"),
+            "πενηντα" => b.put(b"50"),
+            "εξηντα" => b.put(b"60"),
+            "εβδομηντα" => b.put(b"70"),
+            "ογδοντα" => b.put(b"80"),
+            "ενενηντα" => b.put(b"90"),
+            "εκατο" | "εκατον" => b.put(b"100"),
+            "χιλια" | "χιλιοι" | "χιλιες" | "χιλιων" => b.put(b"1000"),
+            // "δύο χιλιάδες"
+            "χιλιαδες" | "χιλιαδων" if !b.is_empty() && b.is_range_free(3, 5) => {
+                b.shift(3)
+            }
+            "εκατομμυριο" | "εκατομμυρια" | "εκατομμυριων" if b.is_range_free(6, 8) => {
+                b.shift(6)
+            }
+            "δισεκατομμυριο" | "δισεκατομμυρια" | "δισεκατομμυριων" if b.is_range_free(9, 11) => {
+                b.shift(9)
+            }
+            _ => Err(Error::NaN),
+        }
+    }
+}
+
+impl LangInterpreter for Greek {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        let word = strip_accents(num_func);
+        if let Some((cardinal, ending, last)) = split_ordinal(&word) {
+            // Every part of a compound ordinal is ordinal, "εικοστός πέμπτος", but the leading hundreds and
+            // thousands may stay cardinal, "εκατόν εικοστός πέμπτος".
+            self.apply_cardinal(cardinal, b)?;
+            b.marker = MorphologicalMarker::Ordinal(ending);
+            if last {
+                b.freeze();
+            }
+            Ok(())
+        } else if b.marker.is_ordinal() {
+            Err(Error::Overlap)
+        } else {
+            self.apply_cardinal(&word, b)
+        }
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        self.apply(decimal_func, b)
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        if strip_accents(word) == "κομμα" {
+            Some(',')
+        } else {
+            None
+        }
+    }
+
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match strip_accents(word).as_ref() {
+            "μειον" | "πλην" => Some('-'),
+            "συν" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn format_and_value(&self, b: &DigitString) -> (String, f64) {
+        let repr = b.to_string();
+        let val: f64 = repr.parse().unwrap();
+        if let MorphologicalMarker::Ordinal(marker) = b.marker {
+            (format!("{repr}{marker}"), val)
+        } else {
+            (repr, val)
+        }
+    }
+
+    fn format_decimal_and_value(
+        &self,
+        int: &DigitString,
+        dec: &DigitString,
+        sep: char,
+    ) -> (String, f64) {
+        let irepr = int.to_string();
+        let drepr = dec.to_string();
+        let frepr = format!("{irepr}{sep}{drepr}");
+        let val = format!("{irepr}.{drepr}").parse().unwrap();
+        (frepr, val)
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        match split_ordinal(&strip_accents(word)) {
+            Some((_, ending, _)) => MorphologicalMarker::Ordinal(ending),
+            None => MorphologicalMarker::None,
+        }
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(strip_accents(word).as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Greek;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Greek::new();
+            let res = text2digits($text, &f);
+            dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Greek::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Greek::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Greek::new();
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("μηδέν", "0");
+        assert_text2digits!("έντεκα", "11");
+        assert_text2digits!("δεκαεπτά", "17");
+        assert_text2digits!("είκοσι πέντε", "25");
+        assert_text2digits!("ενενήντα εννέα", "99");
+        assert_text2digits!("εκατό", "100");
+        assert_text2digits!("εκατόν δεκαπέντε", "115");
+        assert_text2digits!("διακόσια τριάντα", "230");
+        assert_text2digits!("χίλια", "1000");
+        assert_text2digits!("χίλια εννιακόσια ογδόντα τέσσερα", "1984");
+        assert_text2digits!("δύο χιλιάδες είκοσι τέσσερα", "2024");
+        assert_text2digits!("εκατό χιλιάδες", "100000");
+        assert_text2digits!(
+            "πενήντα τρία δισεκατομμύρια διακόσιες σαράντα τρεις χιλιάδες επτακόσια είκοσι τέσσερα",
+            "53000243724"
+        );
+        assert_text2digits!("ένα εκατομμύριο", "1000000");
+    }
+
+    #[test]
+    fn test_agreement() {
+        assert_text2digits!("ένας", "1");
+        assert_text2digits!("μία", "1");
+        assert_text2digits!("ένα", "1");
+        assert_text2digits!("τρεις", "3");
+        assert_text2digits!("τρία", "3");
+        assert_text2digits!("τέσσερις", "4");
+        assert_text2digits!("τέσσερα", "4");
+        assert_text2digits!("διακόσιοι", "200");
+        assert_text2digits!("διακόσιες", "200");
+        assert_text2digits!("διακοσίων", "200");
+        assert_text2digits!("τριακόσιες χιλιάδες", "300000");
+    }
+
+    #[test]
+    fn test_accents() {
+        assert_text2digits!("εικοσι πεντε", "25");
+        assert_text2digits!("χιλια εννιακοσια ογδοντα τεσσερα", "1984");
+        assert_text2digits!("δυο χιλιαδες", "2000");
+        assert_text2digits!("πεμπτος", "5ος");
+        assert_replace_numbers!("ΔΙΑΚΌΣΙΑ ΕΥΡΏ", "200 ΕΥΡΏ");
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_text2digits!("πρώτος", "1ος");
+        assert_text2digits!("δεύτερη", "2η");
+        assert_text2digits!("τρίτο", "3ο");
+        assert_text2digits!("όγδοος", "8ος");
+        assert_text2digits!("δέκατος τρίτος", "13ος");
+        assert_text2digits!("εικοστή πέμπτη", "25η");
+        assert_text2digits!("εκατοστός", "100ος");
+        assert_text2digits!("εκατόν εικοστός πρώτος", "121ος");
+        assert_text2digits!("χιλιοστή", "1000η");
+        assert_replace_numbers!(
+            "Η εικοστή πέμπτη Μαρτίου και ο εικοστός πρώτος αιώνας",
+            "Η 25η Μαρτίου και ο 21ος αιώνας"
+        );
+        assert_replace_all_numbers!("του πρώτου ορόφου", "του 1ου ορόφου");
+    }
+
+    #[test]
+    fn test_decimals() {
+        assert_replace_numbers!("δώδεκα κόμμα πέντε", "12,5");
+        assert_replace_numbers!("μηδέν κόμμα μηδέν πέντε", "0,05");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("χίλια χίλια");
+        assert_invalid!("εξήντα δεκαπέντε");
+        assert_invalid!("δέκα πέντε");
+        assert_invalid!("πέντε είκοσι");
+        assert_invalid!("εικοστός πέντε");
+        assert_invalid!("πρώτος δύο");
+        assert_invalid!("διακόσια εκατό");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!(
+            "είκοσι πέντε αγελάδες, δώδεκα κότες και εκατόν είκοσι πέντε κιλά πατάτες.",
+            "25 αγελάδες, 12 κότες και 125 κιλά πατάτες."
+        );
+        assert_replace_numbers!("ένα δύο τρία τέσσερα είκοσι δεκαπέντε", "1 2 3 4 20 15");
+        assert_replace_all_numbers!("μία γάτα", "1 γάτα");
+    }
+}
@@ -0,0 +1,5 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "και", "εε", "εμ", "λοιπον", "οκ", "ναι", "οχι", "μειον", "συν", "δηλαδη", "μετα", "ειναι"
+};
@@ -1,11 +1,16 @@
 //! English number interpreter
+//!
+//! It supports regional variants.
 
 use crate::digit_string::DigitString;
 use crate::error::Error;
 
+mod verbalize;
 mod vocabulary;
 
-use super::{BasicAnnotate, LangInterpreter, MorphologicalMarker};
+use super::{
+    BasicAnnotate, LangInterpreter, MorphologicalMarker, indian_grouping, ordinal_denominator,
+};
 use vocabulary::INSIGNIFICANT;
 
 fn lemmatize(word: &str) -> &str {
@@ -17,13 +22,39 @@ fn lemmatize(word: &str) -> &str {
     }
 }
 
+/// Regional varieties of English.
+///
+/// In India, the numbers are also counted in lakhs (10⁵) and crores (10⁷): "*five lakh twenty thousand*" is 520000.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EnglishRegion {
+    #[default]
+    International,
+    India,
+}
+
 #[derive(Default)]
-pub struct English {}
+pub struct English {
+    region: EnglishRegion,
+    indian_grouping: bool,
+}
 
 impl English {
     pub fn new() -> Self {
         Default::default()
     }
+
+    pub fn with_region(region: EnglishRegion) -> Self {
+        Self {
+            region,
+            ..Default::default()
+        }
+    }
+
+    /// Whether the digits are grouped in the Indian style, like "5,20,000" (default `false`).
+    pub fn indian_grouping(mut self, yes: bool) -> Self {
+        self.indian_grouping = yes;
+        self
+    }
 }
 
 impl LangInterpreter for English {
@@ -48,6 +79,9 @@ impl LangInterpreter for English {
             };
         }
         let lemma = lemmatize(num_func);
+        // In India, the lakhs come right above the thousands
+        let india = self.region == EnglishRegion::India;
+        let thousands_end = if india { 4 } else { 5 };
         let status = match lemma {
             "zero" | "o" | "nought" => b.put(b"0"),
             "one" | "first" | "oneth" if b.peek(2) != b"10" => b.put(b"1"),
@@ -73,7 +107,7 @@ impl LangInterpreter for English {
             "thirty" | "thirtieth" => b.put(b"30"),
             "fourty" | "forty" | "fortieth" | "fourtieth" => b.put(b"40"),
             "fifty" | "fiftieth" => b.put(b"50"),
-            "sixty" | "sixteeth" => b.put(b"60"),
+            "sixty" | "sixtieth" | "sixteeth" => b.put(b"60"),
             "seventy" | "seventieth" => b.put(b"70"),
             "eighty" | "eightieth" => b.put(b"80"),
             "ninety" | "ninetieth" => b.put(b"90"),
@@ -85,7 +119,9 @@ impl LangInterpreter for English {
                     Err(Error::Overlap)
                 }
             }
-            "thousand" | "thousandth" if b.is_range_free(3, 5) => b.shift(3),
+            "thousand" | "thousandth" if b.is_range_free(3, thousands_end) => b.shift(3),
+            "lakh" | "lac" if india && b.is_range_free(5, 6) => b.shift(5),
+            "crore" if india => b.shift(7),
             "million" | "millionth" if b.is_range_free(6, 8) => b.shift(6),
             "billion" | "billionth" => b.shift(9),
             "and" if b.len() >= 2 => Err(Error::Incomplete),
@@ -124,11 +160,48 @@ impl LangInterpreter for English {
         if word == "point" { Some('.') } else { None }
     }
 
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "minus" => Some('-'),
+            "plus" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn apply_denominator(
+        &self,
+        frac_func: &str,
+        numerator: &DigitString,
+        b: &mut DigitString,
+    ) -> Result<(), Error> {
+        match frac_func {
+            "half" | "halves" => b.put(b"2"),
+            "quarter" | "quarters" => b.put(b"4"),
+            _ => ordinal_denominator(self, frac_func, numerator, false, b),
+        }
+    }
+
+    fn is_fraction_link(&self, word: &str) -> bool {
+        matches!(word, "and" | "a" | "an")
+    }
+
+    fn is_multiplier_denominator(&self, word: &str) -> bool {
+        matches!(
+            word,
+            "hundredths" | "thousandths" | "millionths" | "billionths"
+        )
+    }
+
     fn format_and_value(&self, b: &DigitString) -> (String, f64) {
         let repr = b.to_string();
         let val: f64 = repr.parse().unwrap();
+        let repr = if self.indian_grouping {
+            indian_grouping(&repr)
+        } else {
+            repr
+        };
         if let MorphologicalMarker::Ordinal(marker) = b.marker {
-            (format!("{}{}", b.to_string(), marker), val)
+            (format!("{repr}{marker}"), val)
         } else {
             (repr, val)
         }
@@ -142,9 +215,13 @@ impl LangInterpreter for English {
     ) -> (String, f64) {
         let irepr = int.to_string();
         let drepr = dec.to_string();
-        let frepr = format!("{irepr}.{drepr}");
-        let val = frepr.parse().unwrap();
-        (frepr, val)
+        let val = format!("{irepr}.{drepr}").parse().unwrap();
+        let irepr = if self.indian_grouping {
+            indian_grouping(&irepr)
+        } else {
+            irepr
+        };
+        (format!("{irepr}.{drepr}"), val)
     }
 
     fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
@@ -208,12 +285,13 @@ impl LangInterpreter for English {
 
 #[cfg(test)]
 mod tests {
-    use super::English;
+    use super::{English, EnglishRegion};
+    use crate::ConversionOptions;
     use crate::word_to_digit::{replace_numbers_in_text, text2digits};
 
     macro_rules! assert_text2digits {
         ($text:expr, $res:expr) => {
-            let f = English {};
+            let f = English::new();
             let res = text2digits($text, &f);
             dbg!(&res);
             assert!(res.is_ok());
@@ -223,21 +301,32 @@ mod tests {
 
     macro_rules! assert_replace_numbers {
         ($text:expr, $res:expr) => {
-            let f = English {};
+            let f = English::new();
             assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
         };
     }
 
+    macro_rules! assert_replace_fractions {
+        ($text:expr, $res:expr) => {
+            assert_replace_fractions!($text, $res, 10.0)
+        };
+        ($text:expr, $res:expr, $threshold:expr) => {
+            let f = English::new();
+            let options = ConversionOptions::from($threshold).convert_fractions(true);
+            assert_eq!(replace_numbers_in_text($text, &f, options), $res)
+        };
+    }
+
     macro_rules! assert_replace_all_numbers {
         ($text:expr, $res:expr) => {
-            let f = English {};
+            let f = English::new();
             assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
         };
     }
 
     macro_rules! assert_invalid {
         ($text:expr) => {
-            let f = English {};
+            let f = English::new();
             let res = text2digits($text, &f);
             assert!(res.is_err());
         };
@@ -283,6 +372,7 @@ mod tests {
         assert_text2digits!("twenty-first", "21st");
         assert_text2digits!("thirty-second", "32nd");
         assert_text2digits!("fiftieth", "50th");
+        assert_text2digits!("sixtieth", "60th");
         assert_text2digits!("seventy fourth", "74th");
         assert_text2digits!("twenty-eighth", "28th");
     }
@@ -291,6 +381,21 @@ mod tests {
     fn test_fractions() {
         assert_text2digits!("twenty-fifths", "25ths");
         assert_text2digits!("fourty-oneths", "41ths");
+        assert_replace_fractions!(
+            "three quarters of the cake and two and a half apples",
+            "3/4 of the cake and 2 1/2 apples"
+        );
+        assert_replace_fractions!("one fifth and two thirds", "1/5 and 2/3");
+        assert_replace_fractions!("wait one second", "wait one second");
+        assert_replace_fractions!("two and a dog", "two and a dog");
+        assert_replace_fractions!("two and a dog", "2 and a dog", 0.0);
+        assert_replace_fractions!("one and three quarters", "1 3/4");
+        assert_replace_fractions!("two and three dogs", "2 and 3 dogs", 0.0);
+        assert_replace_fractions!("two and three", "2 and 3", 0.0);
+        assert_replace_fractions!("we have five hundredths left", "we have 5/100 left");
+        assert_replace_fractions!("three thousandths of a second", "3/1000 of a second");
+        assert_replace_fractions!("two and five hundredths", "2 5/100");
+        assert_replace_fractions!("the five hundredth time", "the 500th time");
     }
 
     #[test]
@@ -415,6 +520,46 @@ mod tests {
         assert_replace_numbers!("One may count: one two three", "One may count: 1 2 3");
     }
 
+    #[test]
+    fn test_india() {
+        let f = English::with_region(EnglishRegion::India);
+        assert_eq!(
+            text2digits("five lakh twenty thousand", &f).unwrap(),
+            "520000"
+        );
+        assert_eq!(
+            text2digits("one lakh twenty-five thousand", &f).unwrap(),
+            "125000"
+        );
+        assert_eq!(text2digits("ten lakhs", &f).unwrap(), "1000000");
+        assert_eq!(text2digits("two crore", &f).unwrap(), "20000000");
+        assert_eq!(
+            text2digits("three crore fifty lakh", &f).unwrap(),
+            "35000000"
+        );
+        assert_eq!(
+            text2digits("fifteen hundred crores", &f).unwrap(),
+            "15000000000"
+        );
+        assert!(text2digits("two hundred thousand", &f).is_ok());
+        assert!(text2digits("five lakh two lakh", &f).is_err());
+        assert_replace_all_numbers!("five lakh rupees", "5 lakh rupees");
+    }
+
+    #[test]
+    fn test_indian_grouping() {
+        let f = English::with_region(EnglishRegion::India).indian_grouping(true);
+        assert_eq!(
+            replace_numbers_in_text("he paid five lakh twenty thousand rupees", &f, 10.0),
+            "he paid 5,20,000 rupees"
+        );
+        assert_eq!(
+            replace_numbers_in_text("one crore twenty-three lakh point five", &f, 10.0),
+            "1,23,00,000.5"
+        );
+        assert_eq!(replace_numbers_in_text("nine hundred", &f, 10.0), "900");
+    }
+
     #[test]
     fn test_isolates_with_noise() {
         assert_replace_numbers!(
@@ -0,0 +1,166 @@
+//! Spelling out numbers in English.
+
+use super::English;
+use crate::decimal::Decimal;
+use crate::lang::{Gender, Verbalize, decimal_parts, map_last_word};
+
+const UNITS: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const SCALES: [&str; 4] = ["", " thousand", " million", " billion"];
+
+fn below_hundred(n: usize) -> String {
+    match (n / 10, n % 10) {
+        _ if n < 20 => UNITS[n].to_owned(),
+        (tens, 0) => TENS[tens].to_owned(),
+        (tens, units) => format!("{}-{}", TENS[tens], UNITS[units]),
+    }
+}
+
+fn below_thousand(n: usize) -> String {
+    match (n / 100, n % 100) {
+        (0, rest) => below_hundred(rest),
+        (hundreds, 0) => format!("{} hundred", UNITS[hundreds]),
+        (hundreds, rest) => format!("{} hundred {}", UNITS[hundreds], below_hundred(rest)),
+    }
+}
+
+fn cardinal(n: u64) -> Option<String> {
+    if n >= 1_000_000_000_000 {
+        return None;
+    }
+    if n == 0 {
+        return Some(UNITS[0].to_owned());
+    }
+    let mut words: Vec<String> = Vec::with_capacity(4);
+    for (scale, name) in SCALES.iter().enumerate().rev() {
+        let group = (n / 1000u64.pow(scale as u32) % 1000) as usize;
+        if group > 0 {
+            words.push(format!("{}{name}", below_thousand(group)));
+        }
+    }
+    Some(words.join(" "))
+}
+
+fn ordinal_word(word: &str) -> String {
+    match word {
+        "one" => "first".to_owned(),
+        "two" => "second".to_owned(),
+        "three" => "third".to_owned(),
+        "five" => "fifth".to_owned(),
+        "eight" => "eighth".to_owned(),
+        "nine" => "ninth".to_owned(),
+        "twelve" => "twelfth".to_owned(),
+        tens if tens.ends_with('y') => format!("{}ieth", &tens[..tens.len() - 1]),
+        other => format!("{other}th"),
+    }
+}
+
+impl Verbalize for English {
+    fn verbalize(&self, n: u64) -> Option<String> {
+        cardinal(n)
+    }
+
+    fn verbalize_ordinal(&self, n: u64, _gender: Gender, _plural: bool) -> Option<String> {
+        if n == 0 {
+            return None;
+        }
+        cardinal(n).map(|words| map_last_word(&words, &[' ', '-'], ordinal_word))
+    }
+
+    fn verbalize_decimal(&self, value: &Decimal) -> Option<String> {
+        let (int, dec) = decimal_parts(value)?;
+        let mut words = cardinal(int)?;
+        if !dec.is_empty() {
+            words.push_str(" point");
+            for digit in dec.bytes() {
+                words.push(' ');
+                words.push_str(UNITS[(digit - b'0') as usize]);
+            }
+        }
+        Some(words)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    #[test]
+    fn test_cardinals() {
+        let en = English::new();
+        assert_eq!(en.verbalize(0).unwrap(), "zero");
+        assert_eq!(en.verbalize(42).unwrap(), "forty-two");
+        assert_eq!(en.verbalize(115).unwrap(), "one hundred fifteen");
+        assert_eq!(
+            en.verbalize(53_000_243_724).unwrap(),
+            "fifty-three billion two hundred forty-three thousand seven hundred twenty-four"
+        );
+        assert!(en.verbalize(1_000_000_000_000).is_none());
+    }
+
+    #[test]
+    fn test_ordinals() {
+        let en = English::new();
+        let ordinal = |n| en.verbalize_ordinal(n, Gender::Masculine, false).unwrap();
+        assert_eq!(ordinal(1), "first");
+        assert_eq!(ordinal(12), "twelfth");
+        assert_eq!(ordinal(21), "twenty-first");
+        assert_eq!(ordinal(60), "sixtieth");
+        assert_eq!(ordinal(1230), "one thousand two hundred thirtieth");
+        assert!(en.verbalize_ordinal(0, Gender::Masculine, false).is_none());
+    }
+
+    #[test]
+    fn test_decimals() {
+        let en = English::new();
+        let value: Decimal = "120.05".parse().unwrap();
+        assert_eq!(
+            en.verbalize_decimal(&value).unwrap(),
+            "one hundred twenty point zero five"
+        );
+        assert_eq!(
+            replace_numbers_in_text(&en.verbalize_decimal(&value).unwrap(), &en, 0.0),
+            "120.05"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let en = English::new();
+        for n in (0..2000).chain((1..10_000_000_000).step_by(7_919_111)) {
+            let words = en.verbalize(n).unwrap();
+            assert_eq!(text2digits(&words, &en).unwrap(), n.to_string(), "{words}");
+            if n > 0 {
+                let words = en.verbalize_ordinal(n, Gender::Masculine, false).unwrap();
+                let digits = text2digits(&words, &en).unwrap();
+                assert!(digits.starts_with(&n.to_string()), "{words} -> {digits}");
+            }
+        }
+    }
+}
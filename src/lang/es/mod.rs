@@ -2,9 +2,10 @@
 use crate::digit_string::DigitString;
 use crate::error::Error;
 
+mod verbalize;
 mod vocabulary;
 
-use super::{LangInterpreter, MorphologicalMarker};
+use super::{LangInterpreter, MorphologicalMarker, ordinal_denominator};
 use vocabulary::INSIGNIFICANT;
 
 fn lemmatize(word: &str) -> &str {
@@ -71,7 +72,7 @@ impl LangInterpreter for Spanish {
             "dieciocho" | "decimoctavo" | "decimoctava" | "dieciochoavo" => b.put(b"18"),
             "diecinueve" | "decimonoveno" | "decimonovena" | "decinueveavo" => b.put(b"19"),
             "veinte" | "vigésimo" | "vigésima" | "veintavo" | "veinteavo" => b.put(b"20"),
-            "veintiuno" | "veintiuna" | "veintiunoavo" => b.put(b"21"),
+            "veintiuno" | "veintiuna" | "veintiún" | "veintiunoavo" => b.put(b"21"),
             "veintidós" | "veintidos" | "veintidosavo" => b.put(b"22"),
             "veintitrés" | "veintitres" | "veintitresavo" => b.put(b"23"),
             "veinticuatro" | "veinticuatroavo" => b.put(b"24"),
@@ -90,9 +91,8 @@ impl LangInterpreter for Spanish {
             "cien" | "ciento" | "centésimo" | "centésima" | "centavo" => b.put(b"100"),
             "dosciento" | "doscienta" | "ducentésimo" | "ducentésima" => b.put(b"200"),
             "tresciento" | "trescienta" | "tricentésimo" | "tricentésima" => b.put(b"300"),
-            "cuatrociento" | "cuatrocienta" | "quadringentésimo" | "quadringentésima" => {
-                b.put(b"400")
-            }
+            "cuatrociento" | "cuatrocienta" | "cuadringentésimo" | "cuadringentésima"
+            | "quadringentésimo" | "quadringentésima" => b.put(b"400"),
             "quiniento" | "quinienta" | "quingentésimo" | "quingentésima" => b.put(b"500"),
             "seisciento" | "seiscienta" | "sexcentésimo" | "sexcentésima" => b.put(b"600"),
             "seteciento" | "setecienta" | "septingentésimo" | "septingentésima" => b.put(b"700"),
@@ -134,6 +134,35 @@ impl LangInterpreter for Spanish {
         }
     }
 
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "menos" => Some('-'),
+            "más" | "mas" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn apply_denominator(
+        &self,
+        frac_func: &str,
+        numerator: &DigitString,
+        b: &mut DigitString,
+    ) -> Result<(), Error> {
+        match frac_func {
+            "medio" | "media" | "medios" | "medias" => b.put(b"2"),
+            "tercio" | "tercios" => b.put(b"3"),
+            _ => ordinal_denominator(self, frac_func, numerator, true, b),
+        }
+    }
+
+    fn is_fraction_link(&self, word: &str) -> bool {
+        matches!(word, "y")
+    }
+
     fn format_and_value(&self, b: &DigitString) -> (String, f64) {
         let repr = b.to_string();
         let val: f64 = repr.parse().unwrap();
@@ -188,6 +217,7 @@ impl LangInterpreter for Spanish {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ConversionOptions;
     use crate::word_to_digit::{replace_numbers_in_text, text2digits};
 
     macro_rules! assert_text2digits {
@@ -207,6 +237,17 @@ mod tests {
         };
     }
 
+    macro_rules! assert_replace_fractions {
+        ($text:expr, $res:expr) => {
+            assert_replace_fractions!($text, $res, 10.0)
+        };
+        ($text:expr, $res:expr, $threshold:expr) => {
+            let f = Spanish {};
+            let options = ConversionOptions::from($threshold).convert_fractions(true);
+            assert_eq!(replace_numbers_in_text($text, &f, options), $res)
+        };
+    }
+
     macro_rules! assert_replace_all_numbers {
         ($text:expr, $res:expr) => {
             let f = Spanish {};
@@ -242,6 +283,7 @@ mod tests {
         assert_text2digits!("diecinueve", "19");
         assert_text2digits!("veinte", "20");
         assert_text2digits!("veintiuno", "21");
+        assert_text2digits!("veintiún mil", "21000");
         assert_text2digits!("treinta", "30");
         assert_text2digits!("treinta y uno", "31");
         assert_text2digits!("treinta y dos", "32");
@@ -297,6 +339,8 @@ mod tests {
         assert_text2digits!("vigésimo cuarto", "24º");
         assert_text2digits!("vigésimo primero", "21º");
         assert_text2digits!("centésimo primero", "101º");
+        assert_text2digits!("cuadringentésimo", "400º");
+        assert_text2digits!("quadringentésimo", "400º");
         assert_text2digits!("decimosexta", "16ª");
         assert_text2digits!("decimosextas", "16ᵃˢ");
         assert_text2digits!("decimosextos", "16ᵒˢ");
@@ -307,6 +351,9 @@ mod tests {
         assert_text2digits!("doceavo", "1/12");
         assert_text2digits!("centavo", "1/100");
         assert_text2digits!("ciento veintiochoavos", "1/128");
+        assert_replace_fractions!("tres cuartos y un tercio", "3/4 y 1/3");
+        assert_replace_fractions!("dos y medio", "2 1/2");
+        assert_replace_fractions!("tres onceavos", "3/11");
     }
 
     #[test]
@@ -0,0 +1,312 @@
+//! Spelling out numbers in Spanish.
+
+use super::Spanish;
+use crate::decimal::Decimal;
+use crate::lang::{Gender, Verbalize, decimal_parts};
+
+const UNITS: [&str; 30] = [
+    "cero",
+    "uno",
+    "dos",
+    "tres",
+    "cuatro",
+    "cinco",
+    "seis",
+    "siete",
+    "ocho",
+    "nueve",
+    "diez",
+    "once",
+    "doce",
+    "trece",
+    "catorce",
+    "quince",
+    "dieciséis",
+    "diecisiete",
+    "dieciocho",
+    "diecinueve",
+    "veinte",
+    "veintiuno",
+    "veintidós",
+    "veintitrés",
+    "veinticuatro",
+    "veinticinco",
+    "veintiséis",
+    "veintisiete",
+    "veintiocho",
+    "veintinueve",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "",
+    "treinta",
+    "cuarenta",
+    "cincuenta",
+    "sesenta",
+    "setenta",
+    "ochenta",
+    "noventa",
+];
+
+const HUNDREDS: [&str; 10] = [
+    "",
+    "ciento",
+    "doscientos",
+    "trescientos",
+    "cuatrocientos",
+    "quinientos",
+    "seiscientos",
+    "setecientos",
+    "ochocientos",
+    "novecientos",
+];
+
+/// Ordinal stems, to be completed with the gender and number ending.
+const ORDINAL_UNITS: [&str; 13] = [
+    "",
+    "primer",
+    "segund",
+    "tercer",
+    "cuart",
+    "quint",
+    "sext",
+    "séptim",
+    "octav",
+    "noven",
+    "décim",
+    "undécim",
+    "duodécim",
+];
+
+const ORDINAL_TENS: [&str; 10] = [
+    "",
+    "décim",
+    "vigésim",
+    "trigésim",
+    "cuadragésim",
+    "quincuagésim",
+    "sexagésim",
+    "septuagésim",
+    "octogésim",
+    "nonagésim",
+];
+
+const ORDINAL_HUNDREDS: [&str; 10] = [
+    "",
+    "centésim",
+    "ducentésim",
+    "tricentésim",
+    "cuadringentésim",
+    "quingentésim",
+    "sexcentésim",
+    "septingentésim",
+    "octingentésim",
+    "noningentésim",
+];
+
+fn below_hundred(n: usize) -> String {
+    match (n / 10, n % 10) {
+        _ if n < 30 => UNITS[n].to_owned(),
+        (tens, 0) => TENS[tens].to_owned(),
+        (tens, units) => format!("{} y {}", TENS[tens], UNITS[units]),
+    }
+}
+
+fn below_thousand(n: usize) -> String {
+    match (n / 100, n % 100) {
+        (0, rest) => below_hundred(rest),
+        (1, 0) => "cien".to_owned(),
+        (hundreds, 0) => HUNDREDS[hundreds].to_owned(),
+        (hundreds, rest) => format!("{} {}", HUNDREDS[hundreds], below_hundred(rest)),
+    }
+}
+
+/// `n` < 1 000 000, with the apocope of "*uno*" if `multiplier` follows.
+fn below_million(n: usize, multiplier: bool) -> String {
+    let mut words = match (n / 1000, n % 1000) {
+        (0, rest) => below_thousand(rest),
+        (1, 0) => "mil".to_owned(),
+        (1, rest) => format!("mil {}", below_thousand(rest)),
+        (thousands, 0) => format!("{} mil", apocope(below_thousand(thousands))),
+        (thousands, rest) => format!(
+            "{} mil {}",
+            apocope(below_thousand(thousands)),
+            below_thousand(rest)
+        ),
+    };
+    if multiplier {
+        words = apocope(words);
+    }
+    words
+}
+
+fn apocope(words: String) -> String {
+    if let Some(stem) = words.strip_suffix("veintiuno") {
+        format!("{stem}veintiún")
+    } else if let Some(stem) = words.strip_suffix("uno") {
+        format!("{stem}un")
+    } else {
+        words
+    }
+}
+
+fn cardinal(n: u64) -> Option<String> {
+    if n >= 1_000_000_000_000 {
+        return None;
+    }
+    let (millions, rest) = ((n / 1_000_000) as usize, (n % 1_000_000) as usize);
+    Some(match (millions, rest) {
+        (0, rest) => below_million(rest, false),
+        (1, 0) => "un millón".to_owned(),
+        (1, rest) => format!("un millón {}", below_million(rest, false)),
+        (millions, 0) => format!("{} millones", below_million(millions, true)),
+        (millions, rest) => format!(
+            "{} millones {}",
+            below_million(millions, true),
+            below_million(rest, false)
+        ),
+    })
+}
+
+/// Ordinal stems of `n` < 1000.
+fn ordinal_below_thousand(n: usize, stems: &mut Vec<String>) {
+    let (hundreds, rest) = (n / 100, n % 100);
+    if hundreds > 0 {
+        stems.push(ORDINAL_HUNDREDS[hundreds].to_owned());
+    }
+    match rest {
+        0 => (),
+        1..=12 => stems.push(ORDINAL_UNITS[rest].to_owned()),
+        13..=19 => stems.push(format!(
+            "decimo{}",
+            ORDINAL_UNITS[rest - 10].trim_start_matches('o')
+        )),
+        _ => {
+            stems.push(ORDINAL_TENS[rest / 10].to_owned());
+            if rest % 10 > 0 {
+                stems.push(ORDINAL_UNITS[rest % 10].to_owned());
+            }
+        }
+    }
+}
+
+impl Verbalize for Spanish {
+    fn verbalize(&self, n: u64) -> Option<String> {
+        cardinal(n)
+    }
+
+    fn verbalize_ordinal(&self, n: u64, gender: Gender, plural: bool) -> Option<String> {
+        if n == 0 || n >= 1_000_000_000_000 {
+            return None;
+        }
+        let mut stems: Vec<String> = Vec::with_capacity(4);
+        for (scale, stem) in [(1_000_000, "millonésim"), (1000, "milésim")] {
+            match (n / scale % 1000) as usize {
+                0 => (),
+                1 => stems.push(stem.to_owned()),
+                // "dosmilésimo"
+                count => stems.push(format!("{}{stem}", apocope(below_thousand(count)))),
+            }
+        }
+        ordinal_below_thousand((n % 1000) as usize, &mut stems);
+        let ending = match (gender, plural) {
+            (Gender::Feminine, false) => "a",
+            (Gender::Feminine, true) => "as",
+            (_, false) => "o",
+            (_, true) => "os",
+        };
+        Some(
+            stems
+                .into_iter()
+                .map(|stem| format!("{stem}{ending}"))
+                .collect::<Vec<_>>()
+                .join(" "),
+        )
+    }
+
+    fn verbalize_decimal(&self, value: &Decimal) -> Option<String> {
+        let (int, dec) = decimal_parts(value)?;
+        let mut words = cardinal(int)?;
+        if !dec.is_empty() {
+            words.push_str(" coma");
+            let significant = dec.trim_start_matches('0');
+            for _ in 0..(dec.len() - significant.len()) {
+                words.push_str(" cero");
+            }
+            if !significant.is_empty() {
+                words.push(' ');
+                words.push_str(&cardinal(significant.parse().ok()?)?);
+            }
+        }
+        Some(words)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    #[test]
+    fn test_cardinals() {
+        let es = Spanish::new();
+        assert_eq!(es.verbalize(100).unwrap(), "cien");
+        assert_eq!(es.verbalize(101).unwrap(), "ciento uno");
+        assert_eq!(es.verbalize(21_000).unwrap(), "veintiún mil");
+        assert_eq!(es.verbalize(31_000_000).unwrap(), "treinta y un millones");
+        assert_eq!(
+            es.verbalize(53_020_243_724).unwrap(),
+            "cincuenta y tres mil veinte millones doscientos cuarenta y tres mil setecientos veinticuatro"
+        );
+    }
+
+    #[test]
+    fn test_ordinals() {
+        let es = Spanish::new();
+        assert_eq!(
+            es.verbalize_ordinal(132, Gender::Masculine, false).unwrap(),
+            "centésimo trigésimo segundo"
+        );
+        assert_eq!(
+            es.verbalize_ordinal(22, Gender::Masculine, true).unwrap(),
+            "vigésimos segundos"
+        );
+        assert_eq!(
+            es.verbalize_ordinal(18, Gender::Feminine, false).unwrap(),
+            "decimoctava"
+        );
+        assert_eq!(
+            es.verbalize_ordinal(2000, Gender::Masculine, false)
+                .unwrap(),
+            "dosmilésimo"
+        );
+    }
+
+    #[test]
+    fn test_decimals() {
+        let es = Spanish::new();
+        let value: Decimal = "1.401".parse().unwrap();
+        let words = es.verbalize_decimal(&value).unwrap();
+        assert_eq!(words, "uno coma cuatrocientos uno");
+        assert_eq!(replace_numbers_in_text(&words, &es, 0.0), "1,401");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let es = Spanish::new();
+        for n in (0..2000).chain((1..10_000_000_000).step_by(7_919_111)) {
+            let words = es.verbalize(n).unwrap();
+            assert_eq!(text2digits(&words, &es).unwrap(), n.to_string(), "{words}");
+        }
+        for n in 1..2000 {
+            let words = es.verbalize_ordinal(n, Gender::Feminine, false).unwrap();
+            assert_eq!(
+                text2digits(&words, &es).unwrap(),
+                format!("{n}ª"),
+                "{words}"
+            );
+        }
+    }
+}
@@ -6,9 +6,10 @@ use bitflags::bitflags;
 use crate::digit_string::DigitString;
 use crate::error::Error;
 
+mod verbalize;
 mod vocabulary;
 
-use super::{BasicAnnotate, LangInterpreter, MorphologicalMarker};
+use super::{BasicAnnotate, LangInterpreter, MorphologicalMarker, ordinal_denominator};
 use vocabulary::INSIGNIFICANT;
 
 fn lemmatize(word: &str) -> &str {
@@ -20,13 +21,31 @@ fn lemmatize(word: &str) -> &str {
     }
 }
 
+/// Regional varieties of French.
+///
+/// All the varieties are understood when parsing; the region only selects the words used to spell out numbers
+/// (e.g. "*septante*" and "*nonante*" in Belgium).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FrenchRegion {
+    #[default]
+    France,
+    Belgium,
+    Switzerland,
+}
+
 #[derive(Default)]
-pub struct French {}
+pub struct French {
+    region: FrenchRegion,
+}
 
 impl French {
     pub fn new() -> Self {
         Default::default()
     }
+
+    pub fn with_region(region: FrenchRegion) -> Self {
+        Self { region }
+    }
 }
 
 bitflags! {
@@ -158,7 +177,7 @@ impl LangInterpreter for French {
                 to_block = Excludable::UN;
                 b.put(b"70")
             }
-            "huitante" | "huitantiène" => {
+            "huitante" | "huitantième" | "huitantiène" => {
                 to_block = Excludable::UN;
                 b.put(b"80")
             }
@@ -172,7 +191,7 @@ impl LangInterpreter for French {
             }
             "cent" | "centième" => {
                 let peek = b.peek(2);
-                if (peek.len() == 1 || peek < b"20") && peek != b"1" && peek != b"01" {
+                if (peek.len() == 1 || peek < b"20".as_slice()) && peek != b"1" && peek != b"01" {
                     b.shift(2)
                 } else {
                     Err(Error::Overlap)
@@ -229,11 +248,48 @@ impl LangInterpreter for French {
         if word == "virgule" { Some(',') } else { None }
     }
 
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "moins" => Some('-'),
+            "plus" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn apply_denominator(
+        &self,
+        frac_func: &str,
+        numerator: &DigitString,
+        b: &mut DigitString,
+    ) -> Result<(), Error> {
+        match frac_func {
+            "demi" | "demie" | "demis" | "demies" => b.put(b"2"),
+            "tiers" => b.put(b"3"),
+            "quart" | "quarts" => b.put(b"4"),
+            _ => ordinal_denominator(self, frac_func, numerator, true, b),
+        }
+    }
+
+    fn is_fraction_link(&self, word: &str) -> bool {
+        matches!(word, "et")
+    }
+
+    fn is_multiplier_denominator(&self, word: &str) -> bool {
+        matches!(
+            word,
+            "centièmes" | "millièmes" | "millionièmes" | "milliardièmes"
+        )
+    }
+
     fn format_and_value(&self, b: &DigitString) -> (String, f64) {
         let repr = b.to_string();
         let val = repr.parse().unwrap();
         if let MorphologicalMarker::Ordinal(marker) = b.marker {
-            (format!("{}{}", b.to_string(), marker), val)
+            (format!("{repr}{marker}"), val)
         } else {
             (repr, val)
         }
@@ -320,11 +376,12 @@ impl LangInterpreter for French {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ConversionOptions;
     use crate::word_to_digit::{replace_numbers_in_text, text2digits};
 
     macro_rules! assert_text2digits {
         ($text:expr, $res:expr) => {
-            let f = French {};
+            let f = French::new();
             let res = text2digits($text, &f);
             dbg!(&res);
             assert!(res.is_ok());
@@ -334,21 +391,32 @@ mod tests {
 
     macro_rules! assert_replace_numbers {
         ($text:expr, $res:expr) => {
-            let f = French {};
+            let f = French::new();
             assert_eq!(replace_numbers_in_text($text, &f, 7.0), $res)
         };
     }
 
+    macro_rules! assert_replace_fractions {
+        ($text:expr, $res:expr) => {
+            assert_replace_fractions!($text, $res, 7.0)
+        };
+        ($text:expr, $res:expr, $threshold:expr) => {
+            let f = French::new();
+            let options = ConversionOptions::from($threshold).convert_fractions(true);
+            assert_eq!(replace_numbers_in_text($text, &f, options), $res)
+        };
+    }
+
     macro_rules! assert_replace_all_numbers {
         ($text:expr, $res:expr) => {
-            let f = French {};
+            let f = French::new();
             assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
         };
     }
 
     macro_rules! assert_invalid {
         ($text:expr) => {
-            let f = French {};
+            let f = French::new();
             let res = text2digits($text, &f);
             assert!(res.is_err());
         };
@@ -356,7 +424,7 @@ mod tests {
 
     #[test]
     fn test_apply_steps() {
-        let f = French {};
+        let f = French::new();
         let mut b = DigitString::new();
         assert!(f.apply("trente", &mut b).is_ok());
         assert!(f.apply("quatre", &mut b).is_ok());
@@ -430,6 +498,7 @@ mod tests {
         assert_text2digits!("vingt-cinquième", "25ème");
         assert_text2digits!("vingt cinquième", "25ème");
         assert_text2digits!("vingt et unième", "21ème");
+        assert_text2digits!("huitantième", "80ème");
     }
 
     #[test]
@@ -437,6 +506,13 @@ mod tests {
         assert_text2digits!("vingt-cinquièmes", "25èmes");
         assert_text2digits!("vingt cinquièmes", "25èmes");
         assert_text2digits!("vingt et unièmes", "21èmes");
+        assert_replace_fractions!("trois quarts d'heure", "3/4 d'heure");
+        assert_replace_fractions!("deux et demi", "2 1/2");
+        assert_replace_fractions!("deux cinquièmes et un tiers", "2/5 et 1/3");
+        assert_replace_fractions!("un dixième essai", "un 10ème essai");
+        assert_replace_fractions!("cinq centièmes de seconde", "5/100 de seconde");
+        assert_replace_fractions!("trois millièmes", "3/1000");
+        assert_replace_fractions!("le cinq centième jour", "le 500ème jour");
     }
 
     #[test]
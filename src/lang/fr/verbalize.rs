@@ -0,0 +1,253 @@
+//! Spelling out numbers in French.
+
+use super::{French, FrenchRegion};
+use crate::decimal::Decimal;
+use crate::lang::{Gender, Verbalize, decimal_parts, map_last_word};
+
+const UNITS: [&str; 17] = [
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze",
+    "douze", "treize", "quatorze", "quinze", "seize",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "dix",
+    "vingt",
+    "trente",
+    "quarante",
+    "cinquante",
+    "soixante",
+    "septante",
+    "huitante",
+    "nonante",
+];
+
+impl French {
+    fn below_hundred(&self, n: usize) -> String {
+        let (tens, units) = (n / 10, n % 10);
+        match (tens, units) {
+            _ if n < 17 => UNITS[n].to_owned(),
+            (1, _) => format!("dix-{}", UNITS[units]),
+            (7, _) if self.region == FrenchRegion::France => match units {
+                1 => "soixante et onze".to_owned(),
+                _ => format!("soixante-{}", self.below_hundred(10 + units)),
+            },
+            (8, 0) if self.region != FrenchRegion::Switzerland => "quatre-vingts".to_owned(),
+            (8, _) if self.region != FrenchRegion::Switzerland => {
+                format!("quatre-vingt-{}", UNITS[units])
+            }
+            (9, _) if self.region == FrenchRegion::France => {
+                format!("quatre-vingt-{}", self.below_hundred(10 + units))
+            }
+            (_, 0) => TENS[tens].to_owned(),
+            (_, 1) => format!("{} et un", TENS[tens]),
+            _ => format!("{}-{}", TENS[tens], UNITS[units]),
+        }
+    }
+
+    fn below_thousand(&self, n: usize) -> String {
+        match (n / 100, n % 100) {
+            (0, rest) => self.below_hundred(rest),
+            (1, 0) => "cent".to_owned(),
+            (1, rest) => format!("cent {}", self.below_hundred(rest)),
+            (hundreds, 0) => format!("{} cents", UNITS[hundreds]),
+            (hundreds, rest) => format!("{} cent {}", UNITS[hundreds], self.below_hundred(rest)),
+        }
+    }
+
+    fn cardinal(&self, n: u64) -> Option<String> {
+        if n >= 1_000_000_000_000 {
+            return None;
+        }
+        if n == 0 {
+            return Some(UNITS[0].to_owned());
+        }
+        let group = |scale: u32| (n / 1000u64.pow(scale) % 1000) as usize;
+        let mut words: Vec<String> = Vec::with_capacity(4);
+        for (scale, noun) in [(3, "milliard"), (2, "million")] {
+            match group(scale) {
+                0 => (),
+                1 => words.push(format!("un {noun}")),
+                count => words.push(format!("{} {noun}s", self.below_thousand(count))),
+            }
+        }
+        match group(1) {
+            0 => (),
+            1 => words.push("mille".to_owned()),
+            // "vingt" and "cent" are invariable before "mille"
+            count => {
+                let mut multiplier = self.below_thousand(count);
+                if multiplier.ends_with("cents") || multiplier.ends_with("vingts") {
+                    multiplier.pop();
+                }
+                words.push(format!("{multiplier} mille"))
+            }
+        }
+        if group(0) > 0 {
+            words.push(self.below_thousand(group(0)));
+        }
+        Some(words.join(" "))
+    }
+}
+
+fn ordinal_word(word: &str) -> String {
+    match word {
+        "cinq" => "cinquième".to_owned(),
+        "neuf" => "neuvième".to_owned(),
+        "cents" | "vingts" | "millions" | "milliards" => format!("{}ième", &word[..word.len() - 1]),
+        _ if word.ends_with('e') => format!("{}ième", &word[..word.len() - 1]),
+        _ => format!("{word}ième"),
+    }
+}
+
+impl Verbalize for French {
+    fn verbalize(&self, n: u64) -> Option<String> {
+        self.cardinal(n)
+    }
+
+    fn verbalize_ordinal(&self, n: u64, gender: Gender, plural: bool) -> Option<String> {
+        let mut words = match n {
+            0 => return None,
+            1 if gender == Gender::Feminine => "première".to_owned(),
+            1 => "premier".to_owned(),
+            _ => {
+                let cardinal = self.cardinal(n)?;
+                // "un millionième" is a fraction, the rank is "millionième"
+                let rank = if n == 1_000_000 || n == 1_000_000_000 {
+                    &cardinal[3..]
+                } else {
+                    &cardinal
+                };
+                map_last_word(rank, &[' ', '-'], ordinal_word)
+            }
+        };
+        if plural {
+            words.push('s');
+        }
+        Some(words)
+    }
+
+    fn verbalize_decimal(&self, value: &Decimal) -> Option<String> {
+        let (int, dec) = decimal_parts(value)?;
+        let mut words = self.cardinal(int)?;
+        if !dec.is_empty() {
+            words.push_str(" virgule");
+            let significant = dec.trim_start_matches('0');
+            for _ in 0..(dec.len() - significant.len()) {
+                words.push_str(" zéro");
+            }
+            if !significant.is_empty() {
+                words.push(' ');
+                words.push_str(&self.cardinal(significant.parse().ok()?)?);
+            }
+        }
+        Some(words)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    #[test]
+    fn test_cardinals() {
+        let fr = French::new();
+        assert_eq!(fr.verbalize(21).unwrap(), "vingt et un");
+        assert_eq!(fr.verbalize(71).unwrap(), "soixante et onze");
+        assert_eq!(fr.verbalize(80).unwrap(), "quatre-vingts");
+        assert_eq!(fr.verbalize(81).unwrap(), "quatre-vingt-un");
+        assert_eq!(fr.verbalize(97).unwrap(), "quatre-vingt-dix-sept");
+        assert_eq!(fr.verbalize(200).unwrap(), "deux cents");
+        assert_eq!(
+            fr.verbalize(80_200).unwrap(),
+            "quatre-vingt mille deux cents"
+        );
+        assert_eq!(fr.verbalize(200_000_000).unwrap(), "deux cents millions");
+        assert_eq!(
+            fr.verbalize(51_578_302).unwrap(),
+            "cinquante et un millions cinq cent soixante-dix-huit mille trois cent deux"
+        );
+    }
+
+    #[test]
+    fn test_regions() {
+        let be = French::with_region(FrenchRegion::Belgium);
+        let ch = French::with_region(FrenchRegion::Switzerland);
+        assert_eq!(be.verbalize(71).unwrap(), "septante et un");
+        assert_eq!(be.verbalize(80).unwrap(), "quatre-vingts");
+        assert_eq!(be.verbalize(95).unwrap(), "nonante-cinq");
+        assert_eq!(ch.verbalize(88).unwrap(), "huitante-huit");
+        assert_eq!(
+            ch.verbalize_ordinal(80, Gender::Masculine, false).unwrap(),
+            "huitantième"
+        );
+    }
+
+    #[test]
+    fn test_ordinals() {
+        let fr = French::new();
+        assert_eq!(
+            fr.verbalize_ordinal(1, Gender::Feminine, true).unwrap(),
+            "premières"
+        );
+        assert_eq!(
+            fr.verbalize_ordinal(21, Gender::Feminine, false).unwrap(),
+            "vingt et unième"
+        );
+        assert_eq!(
+            fr.verbalize_ordinal(80, Gender::Masculine, false).unwrap(),
+            "quatre-vingtième"
+        );
+        assert_eq!(
+            fr.verbalize_ordinal(1_000_000, Gender::Masculine, false)
+                .unwrap(),
+            "millionième"
+        );
+        assert_eq!(
+            fr.verbalize_ordinal(25, Gender::Masculine, true).unwrap(),
+            "vingt-cinquièmes"
+        );
+    }
+
+    #[test]
+    fn test_decimals() {
+        let fr = French::new();
+        let value: Decimal = "0.05".parse().unwrap();
+        assert_eq!(
+            fr.verbalize_decimal(&value).unwrap(),
+            "zéro virgule zéro cinq"
+        );
+        let value: Decimal = "12.99".parse().unwrap();
+        let words = fr.verbalize_decimal(&value).unwrap();
+        assert_eq!(words, "douze virgule quatre-vingt-dix-neuf");
+        assert_eq!(replace_numbers_in_text(&words, &fr, 0.0), "12,99");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for region in [
+            FrenchRegion::France,
+            FrenchRegion::Belgium,
+            FrenchRegion::Switzerland,
+        ] {
+            let fr = French::with_region(region);
+            for n in (0..2000).chain((1..10_000_000_000).step_by(7_919_111)) {
+                let words = fr.verbalize(n).unwrap();
+                assert_eq!(
+                    text2digits(&words, &fr).ok(),
+                    Some(n.to_string()),
+                    "{words}"
+                );
+                if n > 1 {
+                    let words = fr.verbalize_ordinal(n, Gender::Masculine, false).unwrap();
+                    assert_eq!(
+                        text2digits(&words, &fr).unwrap(),
+                        format!("{n}ème"),
+                        "{words}"
+                    );
+                }
+            }
+        }
+    }
+}
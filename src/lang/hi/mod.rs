@@ -0,0 +1,370 @@
+//! Hindi number interpreter
+//!
+//! Numbers are understood both in Devanagari and romanized, as the speech-to-text engines output either.
+//! Every number under a hundred is a word of its own, "पच्चीस" is 25, and the big numbers are counted in
+//! thousands, lakhs (10⁵), crores (10⁷), arabs (10⁹) and kharabs (10¹¹): "पांच लाख बीस हज़ार" is 520000.
+
+use std::borrow::Cow;
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+
+mod vocabulary;
+
+use super::{LangInterpreter, MorphologicalMarker, indian_grouping};
+use vocabulary::INSIGNIFICANT;
+
+#[derive(Default)]
+pub struct Hindi {
+    indian_grouping: bool,
+}
+
+impl Hindi {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Whether the digits are grouped in the Indian style, like "5,20,000" (default `false`).
+    pub fn indian_grouping(mut self, yes: bool) -> Self {
+        self.indian_grouping = yes;
+        self
+    }
+
+    fn group(&self, repr: String) -> String {
+        if self.indian_grouping {
+            indian_grouping(&repr)
+        } else {
+            repr
+        }
+    }
+}
+
+/// Drop the nuktas and the chandrabindus, as their use varies: "हज़ार" or "हजार", "पाँच" or "पांच".
+fn normalize(word: &str) -> Cow<'_, str> {
+    if !word
+        .chars()
+        .any(|c| matches!(c, '\u{901}' | '\u{93c}' | '\u{958}'..='\u{95f}'))
+    {
+        return Cow::Borrowed(word);
+    }
+    let normalized = word
+        .chars()
+        .filter_map(|c| match c {
+            '\u{93c}' => None,
+            '\u{901}' => Some('\u{902}'),
+            '\u{958}' => Some('\u{915}'),
+            '\u{959}' => Some('\u{916}'),
+            '\u{95a}' => Some('\u{917}'),
+            '\u{95b}' => Some('\u{91c}'),
+            '\u{95c}' => Some('\u{921}'),
+            '\u{95d}' => Some('\u{922}'),
+            '\u{95e}' => Some('\u{92b}'),
+            '\u{95f}' => Some('\u{92f}'),
+            c => Some(c),
+        })
+        .collect();
+    Cow::Owned(normalized)
+}
+
+/// The numbers from 0 to 99, in their normalized spelling.
+fn below_hundred(word: &str) -> Option<&'static [u8]> {
+    let digits: &[u8] = match word {
+        "शून्य" | "shunya" | "shoonya" => b"0",
+        "एक" | "ek" => b"1",
+        "दो" | "do" => b"2",
+        "तीन" | "teen" | "tin" => b"3",
+        "चार" | "char" | "chaar" => b"4",
+        "पांच" | "panch" | "paanch" => b"5",
+        "छह" | "छः" | "छै" | "छे" | "chhah" | "chhe" | "chah" => b"6",
+        "सात" | "saat" | "sat" => b"7",
+        "आठ" | "aath" | "ath" => b"8",
+        "नौ" | "nau" => b"9",
+        "दस" | "das" => b"10",
+        "ग्यारह" | "gyarah" | "gyaarah" => b"11",
+        "बारह" | "barah" | "baarah" => b"12",
+        "तेरह" | "terah" => b"13",
+        "चौदह" | "chaudah" => b"14",
+        "पंद्रह" | "पन्द्रह" | "pandrah" => b"15",
+        "सोलह" | "solah" => b"16",
+        "सत्रह" | "satrah" => b"17",
+        "अठारह" | "atharah" | "athaarah" => b"18",
+        "उन्नीस" | "unnis" | "unnees" => b"19",
+        "बीस" | "bees" | "bis" => b"20",
+        "इक्कीस" | "ikkis" | "ikkees" => b"21",
+        "बाईस" | "bais" | "baees" | "baais" => b"22",
+        "तेईस" | "teis" | "teees" => b"23",
+        "चौबीस" | "chaubis" | "chaubees" => b"24",
+        "पच्चीस" | "pachchis" | "pachchees" | "pachis" | "pachees" => b"25",
+        "छब्बीस" | "chhabbis" | "chhabbees" => b"26",
+        "सत्ताईस" | "sattais" | "sattaees" => b"27",
+        "अट्ठाईस" | "अठाईस" | "atthais" | "atthaees" | "athais" => b"28",
+        "उनतीस" | "untis" | "untees" => b"29",
+        "तीस" | "tis" | "tees" => b"30",
+        "इकतीस" | "iktis" | "iktees" | "ikattis" => b"31",
+        "बत्तीस" | "battis" | "battees" => b"32",
+        "तैंतीस" | "taintis" | "taintees" => b"33",
+        "चौंतीस" | "chauntis" | "chauntees" => b"34",
+        "पैंतीस" | "paintis" | "paintees" => b"35",
+        "छत्तीस" | "chhattis" | "chhattees" => b"36",
+        "सैंतीस" | "saintis" | "saintees" => b"37",
+        "अडतीस" | "adtis" | "adtees" | "artis" => b"38",
+        "उनतालीस" | "उन्तालीस" | "untalis" | "untaalees" => b"39",
+        "चालीस" | "chalis" | "chaalees" => b"40",
+        "इकतालीस" | "iktalis" | "iktaalees" => b"41",
+        "बयालीस" | "bayalis" | "bayaalees" => b"42",
+        "तैंतालीस" | "taintalis" | "taintaalees" => b"43",
+        "चवालीस" | "चौवालीस" | "chavalis" | "chavaalees" => b"44",
+        "पैंतालीस" | "paintalis" | "paintaalees" => b"45",
+        "छियालीस" | "chhiyalis" | "chhiyaalees" => b"46",
+        "सैंतालीस" | "saintalis" | "saintaalees" => b"47",
+        "अडतालीस" | "adtalis" | "adtaalees" => b"48",
+        "उनचास" | "unchas" | "unchaas" => b"49",
+        "पचास" | "pachas" | "pachaas" => b"50",
+        "इक्यावन" | "ikyavan" | "ikyaavan" => b"51",
+        "बावन" | "bavan" | "baavan" => b"52",
+        "तिरपन" | "तिरेपन" | "tirpan" | "tirepan" => b"53",
+        "चौवन" | "chauvan" => b"54",
+        "पचपन" | "pachpan" => b"55",
+        "छप्पन" | "chhappan" => b"56",
+        "सत्तावन" | "sattavan" | "sattaavan" => b"57",
+        "अट्ठावन" | "अठावन" | "atthavan" | "atthaavan" => b"58",
+        "उनसठ" | "unsath" => b"59",
+        // not "saath", that is also "with"
+        "साठ" | "sath" => b"60",
+        "इकसठ" | "iksath" => b"61",
+        "बासठ" | "basath" | "baasath" => b"62",
+        "तिरसठ" | "tirsath" => b"63",
+        "चौंसठ" | "chaunsath" => b"64",
+        "पैंसठ" | "painsath" => b"65",
+        "छियासठ" | "chhiyasath" | "chhiyaasath" => b"66",
+        "सडसठ" | "सरसठ" | "sadsath" | "sarsath" => b"67",
+        "अडसठ" | "adsath" | "arsath" => b"68",
+        "उनहत्तर" | "unhattar" => b"69",
+        "सत्तर" | "sattar" => b"70",
+        "इकहत्तर" | "ikhattar" | "ikahattar" => b"71",
+        "बहत्तर" | "bahattar" => b"72",
+        "तिहत्तर" | "tihattar" => b"73",
+        "चौहत्तर" | "chauhattar" => b"74",
+        "पचहत्तर" | "pachhattar" | "pachahattar" => b"75",
+        "छिहत्तर" | "chhihattar" => b"76",
+        "सतहत्तर" | "सतत्तर" | "sathattar" | "satattar" => b"77",
+        "अठहत्तर" | "अठत्तर" | "athhattar" | "athattar" => b"78",
+        "उनासी" | "उन्यासी" | "unasi" | "unaasi" => b"79",
+        "अस्सी" | "assi" => b"80",
+        "इक्यासी" | "ikyasi" | "ikyaasi" => b"81",
+        "बयासी" | "bayasi" | "bayaasi" => b"82",
+        "तिरासी" | "tirasi" | "tiraasi" => b"83",
+        "चौरासी" | "chaurasi" | "chauraasi" => b"84",
+        "पचासी" | "pachasi" | "pachaasi" => b"85",
+        "छियासी" | "chhiyasi" | "chhiyaasi" => b"86",
+        "सत्तासी" | "sattasi" | "sattaasi" => b"87",
+        "अट्ठासी" | "अठासी" | "atthasi" | "atthaasi" => b"88",
+        "नवासी" | "navasi" | "navaasi" => b"89",
+        "नब्बे" | "nabbe" => b"90",
+        "इक्यानवे" | "इक्यानबे" | "ikyanve" | "ikyaanve" => b"91",
+        "बानवे" | "बानबे" | "banve" | "baanve" => b"92",
+        "तिरानवे" | "तिरानबे" | "tiranve" | "tiraanve" => b"93",
+        "चौरानवे" | "चौरानबे" | "chauranve" | "chauraanve" => b"94",
+        "पचानवे" | "पंचानबे" | "pachanve" | "pachaanve" => b"95",
+        "छियानवे" | "छियानबे" | "chhiyanve" | "chhiyaanve" => b"96",
+        "सत्तानवे" | "सत्तानबे" | "sattanve" | "sattaanve" => b"97",
+        "अट्ठानवे" | "अट्ठानबे" | "atthanve" | "atthaanve" => b"98",
+        "निन्यानवे" | "निन्यानबे" | "ninyanve" | "ninyaanve" => {
+            b"99"
+        }
+        _ => return None,
+    };
+    Some(digits)
+}
+
+impl LangInterpreter for Hindi {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        let word = normalize(num_func);
+        match word.as_ref() {
+            "सौ" | "sau" => {
+                let peek = b.peek(2);
+                if peek.len() == 1 || peek != b"00" {
+                    b.shift(2)
+                } else {
+                    Err(Error::Overlap)
+                }
+            }
+            "हजार" | "hazar" | "hazaar" | "hajar" if b.is_range_free(3, 4) => b.shift(3),
+            "लाख" | "lakh" | "lac" if b.is_range_free(5, 6) => b.shift(5),
+            "करोड" | "crore" | "karod" | "karor" if b.is_range_free(7, 8) => b.shift(7),
+            "अरब" | "arab" if b.is_range_free(9, 10) => b.shift(9),
+            "खरब" | "kharab" => b.shift(11),
+            word => match below_hundred(word) {
+                // Each number under a hundred is a single word, so they never combine
+                Some(digits) if b.is_free(2) => b.put(digits),
+                Some(_) => Err(Error::Overlap),
+                None => Err(Error::NaN),
+            },
+        }
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        // The decimals are read digit by digit
+        match below_hundred(&normalize(decimal_func)) {
+            Some(digit) if digit.len() == 1 => b.push(digit),
+            _ => Err(Error::NaN),
+        }
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        match word {
+            "दशमलव" | "dashamlav" | "point" => Some('.'),
+            _ => None,
+        }
+    }
+
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "ऋण" | "माइनस" | "minus" => Some('-'),
+            "प्लस" | "plus" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn format_and_value(&self, b: &DigitString) -> (String, f64) {
+        let repr = b.to_string();
+        let val: f64 = repr.parse().unwrap();
+        (self.group(repr), val)
+    }
+
+    fn format_decimal_and_value(
+        &self,
+        int: &DigitString,
+        dec: &DigitString,
+        sep: char,
+    ) -> (String, f64) {
+        let irepr = int.to_string();
+        let drepr = dec.to_string();
+        let val = format!("{irepr}.{drepr}").parse().unwrap();
+        (format!("{}{sep}{drepr}", self.group(irepr)), val)
+    }
+
+    fn get_morph_marker(&self, _word: &str) -> MorphologicalMarker {
+        MorphologicalMarker::None
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Hindi;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Hindi::new();
+            let res = text2digits($text, &f);
+            dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Hindi::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Hindi::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Hindi::new();
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("शून्य", "0");
+        assert_text2digits!("ग्यारह", "11");
+        assert_text2digits!("इक्कीस", "21");
+        assert_text2digits!("पचपन", "55");
+        assert_text2digits!("निन्यानवे", "99");
+        assert_text2digits!("सौ", "100");
+        assert_text2digits!("एक सौ पांच", "105");
+        assert_text2digits!("तीन सौ बयालीस", "342");
+        assert_text2digits!("दो हज़ार तीन सौ", "2300");
+        assert_text2digits!("उन्नीस सौ चौरासी", "1984");
+        assert_text2digits!("पचास हज़ार", "50000");
+    }
+
+    #[test]
+    fn test_lakh_crore() {
+        assert_text2digits!("एक लाख", "100000");
+        assert_text2digits!("पाँच लाख बीस हज़ार", "520000");
+        assert_text2digits!("दो करोड़", "20000000");
+        assert_text2digits!("तीन करोड़ पचास लाख", "35000000");
+        assert_text2digits!("सौ करोड़", "1000000000");
+        assert_text2digits!("दो अरब पांच करोड़", "2050000000");
+    }
+
+    #[test]
+    fn test_spelling_variants() {
+        assert_text2digits!("पांच हजार", "5000");
+        assert_text2digits!("पाँच हज़ार", "5000");
+        // With a precomposed or a combining nukta
+        assert_text2digits!("\u{905}\u{95c}\u{924}\u{940}\u{938}", "38");
+        assert_text2digits!("\u{905}\u{921}\u{93c}\u{924}\u{940}\u{938}", "38");
+    }
+
+    #[test]
+    fn test_romanized() {
+        assert_text2digits!("paanch lakh bees hazaar", "520000");
+        assert_text2digits!("do crore", "20000000");
+        assert_text2digits!("ek sau pachchees", "125");
+    }
+
+    #[test]
+    fn test_decimals() {
+        assert_replace_numbers!("तीन दशमलव एक चार", "3.14");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("बीस पांच");
+        assert_invalid!("हज़ार हज़ार");
+        assert_invalid!("लाख सौ");
+        assert_invalid!("पांच लाख दो लाख");
+    }
+
+    #[test]
+    fn test_grouping() {
+        let f = Hindi::new().indian_grouping(true);
+        assert_eq!(
+            replace_numbers_in_text("पाँच लाख बीस हज़ार रुपये", &f, 10.0),
+            "5,20,000 रुपये"
+        );
+        assert_eq!(
+            replace_numbers_in_text("एक करोड़ तेईस लाख पैंतालीस हज़ार छह सौ अठहत्तर", &f, 10.0),
+            "1,23,45,678"
+        );
+        assert_eq!(replace_numbers_in_text("नौ सौ", &f, 10.0), "900");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!(
+            "मेरे पास पच्चीस गायें और एक सौ बीस मुर्गियां हैं",
+            "मेरे पास 25 गायें और 120 मुर्गियां हैं"
+        );
+        assert_replace_numbers!("एक दो तीन चार बीस पंद्रह", "1 2 3 4 20 15");
+        assert_replace_all_numbers!("एक किताब", "1 किताब");
+    }
+}
@@ -0,0 +1,6 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "और", "aur", "तो", "to", "हाँ", "हां", "haan", "जी", "ji", "यानी", "yaani", "अच्छा", "accha", "माइनस", "minus",
+    "प्लस", "plus"
+};
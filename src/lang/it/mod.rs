@@ -4,9 +4,10 @@ use crate::digit_string::DigitString;
 use crate::error::Error;
 use crate::tokenizer::WordSplitter;
 
+mod verbalize;
 mod vocabulary;
 
-use super::{LangInterpreter, MorphologicalMarker};
+use super::{LangInterpreter, MorphologicalMarker, ordinal_denominator};
 use vocabulary::INSIGNIFICANT;
 
 pub struct Italian {
@@ -122,7 +123,7 @@ impl LangInterpreter for Italian {
             "tredici" | "tredicesim" => b.put(b"13"),
             "quattordici" | "quattordicesim" => b.put(b"14"),
             "quindici" | "quindicesim" => b.put(b"15"),
-            "sedici" | "dedicesim" => b.put(b"16"),
+            "sedici" | "sedicesim" | "dedicesim" => b.put(b"16"),
             "diciassette" | "diciassettesim" => b.put(b"17"),
             "diciotto" | "diciottesim" => b.put(b"18"),
             "diciannove" | "diciannovesim" => b.put(b"19"),
@@ -142,7 +143,7 @@ impl LangInterpreter for Italian {
             "sessantuno" | "sessantun" | "sessantunesim" => b.put(b"61"),
             "sessantotto" | "sessantottesim" => b.put(b"68"),
             "settanta" | "settantesim" => b.put(b"70"),
-            "settantuno" | "settantun" | "settanunesim" => b.put(b"71"),
+            "settantuno" | "settantun" | "settantunesim" | "settanunesim" => b.put(b"71"),
             "settantotto" | "settantottesim" => b.put(b"78"),
             "ottanta" | "ottantesim" | "ttanta" | "ttantesim" => b.put(b"80"),
             "ottantuno" | "ottantun" | "ottantunesim" => b.put(b"81"),
@@ -152,7 +153,7 @@ impl LangInterpreter for Italian {
             "novantotto" | "novantottesim" => b.put(b"98"),
             "cento" | "centesim" => {
                 let peek = b.peek(2);
-                if (peek.len() == 1 || peek < b"10") && peek != b"1" && peek != b"01" {
+                if (peek.len() == 1 || peek < b"10".as_slice()) && peek != b"1" && peek != b"01" {
                     b.shift(2)
                 } else {
                     Err(Error::Overlap)
@@ -177,7 +178,7 @@ impl LangInterpreter for Italian {
                 }
             }
             "milione" if b.is_range_free(6, 8) => {
-                if b.len() != 1 || b.peek(1) != b"1" {
+                if b.peek(1) != b"1" || !b.is_range_free(1, 2) {
                     Err(Error::NaN)
                 } else {
                     b.shift(6)
@@ -269,11 +270,54 @@ impl LangInterpreter for Italian {
     fn check_decimal_separator(&self, word: &str) -> Option<char> {
         if word == "virgola" { Some(',') } else { None }
     }
+
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "meno" => Some('-'),
+            "più" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn apply_denominator(
+        &self,
+        frac_func: &str,
+        numerator: &DigitString,
+        b: &mut DigitString,
+    ) -> Result<(), Error> {
+        match frac_func {
+            "mezzo" | "mezza" | "mezzi" | "mezze" => b.put(b"2"),
+            _ => ordinal_denominator(self, frac_func, numerator, true, b),
+        }
+    }
+
+    fn is_fraction_link(&self, word: &str) -> bool {
+        matches!(word, "e")
+    }
+
+    fn is_multiplier_denominator(&self, word: &str) -> bool {
+        matches!(
+            word,
+            "centesimi"
+                | "centesime"
+                | "millesimi"
+                | "millesime"
+                | "milionesimi"
+                | "milionesime"
+                | "miliardesimi"
+                | "miliardesime"
+        )
+    }
+
     fn format_and_value(&self, b: &DigitString) -> (String, f64) {
         let repr = b.to_string();
         let val = repr.parse().unwrap();
         if let MorphologicalMarker::Ordinal(marker) = b.marker {
-            (format!("{}{}", b.to_string(), marker), val)
+            (format!("{repr}{marker}"), val)
         } else {
             (repr, val)
         }
@@ -298,6 +342,7 @@ impl LangInterpreter for Italian {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ConversionOptions;
     use crate::word_to_digit::{replace_numbers_in_text, text2digits};
 
     macro_rules! assert_text2digits {
@@ -317,6 +362,17 @@ mod tests {
         };
     }
 
+    macro_rules! assert_replace_fractions {
+        ($text:expr, $res:expr) => {
+            assert_replace_fractions!($text, $res, 10.0)
+        };
+        ($text:expr, $res:expr, $threshold:expr) => {
+            let f = Italian::default();
+            let options = ConversionOptions::from($threshold).convert_fractions(true);
+            assert_eq!(replace_numbers_in_text($text, &f, options), $res)
+        };
+    }
+
     macro_rules! assert_replace_all_numbers {
         ($text:expr, $res:expr) => {
             let f = Italian::default();
@@ -390,6 +446,7 @@ mod tests {
 
         assert_text2digits!("settantacinquemila", "75000");
         assert_text2digits!("un miliardo venticinque milioni", "1025000000");
+        assert_text2digits!("tre miliardi un milione", "3001000000");
     }
 
     #[test]
@@ -424,6 +481,18 @@ mod tests {
         assert_text2digits!("ventunesimo", "21º");
         assert_text2digits!("venticinquesimi", "25º");
         assert_text2digits!("ventunesimi", "21º");
+        assert_text2digits!("sedicesimo", "16º");
+        assert_text2digits!("settantunesimo", "71º");
+    }
+
+    #[test]
+    fn test_fractions() {
+        assert_replace_fractions!("tre quarti e un mezzo", "3/4 e 1/2");
+        assert_replace_fractions!("due e mezzo", "2 1/2");
+        assert_replace_fractions!("due terzi", "2/3");
+        assert_replace_fractions!("un ventesimo tentativo", "un 20º tentativo");
+        assert_replace_fractions!("cinque centesimi", "5/100");
+        assert_replace_fractions!("tre millesimi di secondo", "3/1000 di secondo");
     }
 
     #[test]
@@ -0,0 +1,254 @@
+//! Spelling out numbers in Italian.
+
+use super::Italian;
+use crate::decimal::Decimal;
+use crate::lang::{Gender, Verbalize, decimal_parts, map_last_word};
+
+const UNITS: [&str; 20] = [
+    "zero",
+    "uno",
+    "due",
+    "tre",
+    "quattro",
+    "cinque",
+    "sei",
+    "sette",
+    "otto",
+    "nove",
+    "dieci",
+    "undici",
+    "dodici",
+    "tredici",
+    "quattordici",
+    "quindici",
+    "sedici",
+    "diciassette",
+    "diciotto",
+    "diciannove",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "venti",
+    "trenta",
+    "quaranta",
+    "cinquanta",
+    "sessanta",
+    "settanta",
+    "ottanta",
+    "novanta",
+];
+
+const ORDINALS: [&str; 11] = [
+    "", "prim", "second", "terz", "quart", "quint", "sest", "settim", "ottav", "non", "decim",
+];
+
+fn below_hundred(n: usize) -> String {
+    match (n / 10, n % 10) {
+        _ if n < 20 => UNITS[n].to_owned(),
+        (tens, 0) => TENS[tens].to_owned(),
+        // elision of the final vowel of the tens
+        (tens, units @ (1 | 8)) => {
+            let tens = TENS[tens];
+            format!("{}{}", &tens[..tens.len() - 1], UNITS[units])
+        }
+        (tens, 3) => format!("{}tré", TENS[tens]),
+        (tens, units) => format!("{}{}", TENS[tens], UNITS[units]),
+    }
+}
+
+fn below_thousand(n: usize) -> String {
+    let hundreds = match n / 100 {
+        0 => "",
+        1 => "cento",
+        h => &format!("{}cento", UNITS[h]),
+    };
+    match n % 100 {
+        0 => hundreds.to_owned(),
+        rest => format!("{hundreds}{}", below_hundred(rest)),
+    }
+}
+
+/// Form used before a multiplier: "*ventun*", "*ventitre*".
+fn multiplied(n: usize) -> String {
+    let words = below_thousand(n);
+    if let Some(stem) = words.strip_suffix("uno") {
+        format!("{stem}un")
+    } else if let Some(stem) = words.strip_suffix("tré") {
+        format!("{stem}tre")
+    } else {
+        words
+    }
+}
+
+fn cardinal(n: u64) -> Option<String> {
+    if n >= 1_000_000_000_000 {
+        return None;
+    }
+    if n == 0 {
+        return Some(UNITS[0].to_owned());
+    }
+    let mut words: Vec<String> = Vec::with_capacity(3);
+    for (scale, singular, plural) in [
+        (1_000_000_000, "un miliardo", "miliardi"),
+        (1_000_000, "un milione", "milioni"),
+    ] {
+        match (n / scale % 1000) as usize {
+            0 => (),
+            1 => words.push(singular.to_owned()),
+            count => words.push(format!("{} {plural}", multiplied(count))),
+        }
+    }
+    let thousands = match (n / 1000 % 1000) as usize {
+        0 => String::new(),
+        1 => "mille".to_owned(),
+        count => format!("{}mila", multiplied(count)),
+    };
+    let rest = (n % 1000) as usize;
+    if !thousands.is_empty() || rest > 0 {
+        words.push(if rest > 0 {
+            format!("{thousands}{}", below_thousand(rest))
+        } else {
+            thousands
+        });
+    }
+    Some(words.join(" "))
+}
+
+fn ordinal_stem(word: &str) -> String {
+    if let Some(stem) = word
+        .strip_suffix("tré")
+        .or_else(|| word.strip_suffix("tre"))
+    {
+        format!("{stem}treesim")
+    } else if word.ends_with("sei") {
+        format!("{word}esim")
+    } else if let Some(stem) = word.strip_suffix("dieci") {
+        format!("{stem}decim")
+    } else if let Some(stem) = word.strip_suffix("mila") {
+        format!("{stem}millesim")
+    } else {
+        format!("{}esim", &word[..word.len() - 1])
+    }
+}
+
+impl Verbalize for Italian {
+    fn verbalize(&self, n: u64) -> Option<String> {
+        cardinal(n)
+    }
+
+    fn verbalize_ordinal(&self, n: u64, gender: Gender, plural: bool) -> Option<String> {
+        let stem = match n {
+            0 => return None,
+            1..=10 => ORDINALS[n as usize].to_owned(),
+            _ => {
+                let cardinal = cardinal(n)?;
+                // "un milionesimo" is a fraction, the rank is "milionesimo"
+                let rank = if n == 1_000_000 || n == 1_000_000_000 {
+                    &cardinal[3..]
+                } else {
+                    &cardinal
+                };
+                map_last_word(rank, &[' '], ordinal_stem)
+            }
+        };
+        let ending = match (gender, plural) {
+            (Gender::Feminine, false) => 'a',
+            (Gender::Feminine, true) => 'e',
+            (_, false) => 'o',
+            (_, true) => 'i',
+        };
+        Some(format!("{stem}{ending}"))
+    }
+
+    fn verbalize_decimal(&self, value: &Decimal) -> Option<String> {
+        let (int, dec) = decimal_parts(value)?;
+        let mut words = cardinal(int)?;
+        if !dec.is_empty() {
+            words.push_str(" virgola");
+            let significant = dec.trim_start_matches('0');
+            for _ in 0..(dec.len() - significant.len()) {
+                words.push_str(" zero");
+            }
+            if !significant.is_empty() {
+                words.push(' ');
+                words.push_str(&cardinal(significant.parse().ok()?)?);
+            }
+        }
+        Some(words)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    #[test]
+    fn test_cardinals() {
+        let it = Italian::new();
+        assert_eq!(it.verbalize(21).unwrap(), "ventuno");
+        assert_eq!(it.verbalize(33).unwrap(), "trentatré");
+        assert_eq!(it.verbalize(1984).unwrap(), "millenovecentoottantaquattro");
+        assert_eq!(it.verbalize(21_000).unwrap(), "ventunmila");
+        assert_eq!(
+            it.verbalize(1_025_000_000).unwrap(),
+            "un miliardo venticinque milioni"
+        );
+    }
+
+    #[test]
+    fn test_ordinals() {
+        let it = Italian::new();
+        assert_eq!(
+            it.verbalize_ordinal(1, Gender::Feminine, false).unwrap(),
+            "prima"
+        );
+        assert_eq!(
+            it.verbalize_ordinal(23, Gender::Masculine, false).unwrap(),
+            "ventitreesimo"
+        );
+        assert_eq!(
+            it.verbalize_ordinal(21, Gender::Feminine, true).unwrap(),
+            "ventunesime"
+        );
+        assert_eq!(
+            it.verbalize_ordinal(2000, Gender::Masculine, false)
+                .unwrap(),
+            "duemillesimo"
+        );
+        assert_eq!(
+            it.verbalize_ordinal(1_000_000, Gender::Masculine, false)
+                .unwrap(),
+            "milionesimo"
+        );
+    }
+
+    #[test]
+    fn test_decimals() {
+        let it = Italian::new();
+        let value: Decimal = "3.14".parse().unwrap();
+        let words = it.verbalize_decimal(&value).unwrap();
+        assert_eq!(words, "tre virgola quattordici");
+        assert_eq!(replace_numbers_in_text(&words, &it, 0.0), "3,14");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let it = Italian::new();
+        for n in (0..2000).chain((1..10_000_000_000).step_by(7_919_111)) {
+            let words = it.verbalize(n).unwrap();
+            assert_eq!(
+                text2digits(&words, &it).ok(),
+                Some(n.to_string()),
+                "{words}"
+            );
+            if n > 0 {
+                let words = it.verbalize_ordinal(n, Gender::Masculine, false).unwrap();
+                let digits = text2digits(&words, &it).unwrap_or_else(|_| panic!("{words}"));
+                assert!(digits.starts_with(&n.to_string()), "{words} -> {digits}");
+            }
+        }
+    }
+}
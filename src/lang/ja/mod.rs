@@ -0,0 +1,281 @@
+//! Japanese number interpreter
+//!
+//! The numbers are grouped by myriads, "二十三万四千" is 234000, and are understood both in kanji and in their kana
+//! readings, as a speech recognizer may output: "さんぜんごひゃく" is 3500.
+//! Unlike in Chinese, the digits after a multiplier are always units: "三千五" is 3005.
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+use crate::tokenizer::WordSplitter;
+
+mod vocabulary;
+
+use super::cjk::{self, Dialect, Numeral};
+use super::{LangInterpreter, MorphologicalMarker};
+use vocabulary::INSIGNIFICANT;
+
+pub struct Japanese {
+    word_splitter: WordSplitter,
+}
+
+impl Default for Japanese {
+    fn default() -> Self {
+        Self {
+            word_splitter: WordSplitter::new([
+                "〇",
+                "零",
+                "一",
+                "壱",
+                "二",
+                "弐",
+                "三",
+                "参",
+                "四",
+                "五",
+                "六",
+                "七",
+                "八",
+                "九",
+                "十",
+                "拾",
+                "百",
+                "千",
+                "万",
+                "萬",
+                "億",
+                "兆",
+                "点",
+                "ぜろ",
+                "ゼロ",
+                "れい",
+                "いち",
+                "いっ",
+                "に",
+                "さん",
+                "よん",
+                "ご",
+                "ろく",
+                "ろっ",
+                "なな",
+                "しち",
+                "はち",
+                "はっ",
+                "きゅう",
+                "じゅう",
+                "ひゃく",
+                "びゃく",
+                "ぴゃく",
+                "せん",
+                "ぜん",
+                "まん",
+                "おく",
+                "ちょう",
+                "てん",
+                "マイナス",
+                "プラス",
+            ])
+            .unwrap(),
+        }
+    }
+}
+
+impl Japanese {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl Dialect for Japanese {
+    const ABBREVIATED: bool = false;
+    const IDIOMS: &'static [&'static str] = &["万一", "千万"];
+
+    fn word_splitter(&self) -> &WordSplitter {
+        &self.word_splitter
+    }
+
+    fn numeral(&self, word: &str) -> Option<Numeral> {
+        let numeral = match word {
+            "〇" | "零" | "ぜろ" | "ゼロ" | "れい" => Numeral::Zero,
+            "一" | "壱" | "いち" | "いっ" => Numeral::Digit(b'1'),
+            "二" | "弐" | "に" => Numeral::Digit(b'2'),
+            "三" | "参" | "さん" => Numeral::Digit(b'3'),
+            "四" | "よん" => Numeral::Digit(b'4'),
+            "五" | "ご" => Numeral::Digit(b'5'),
+            "六" | "ろく" | "ろっ" => Numeral::Digit(b'6'),
+            "七" | "なな" | "しち" => Numeral::Digit(b'7'),
+            "八" | "はち" | "はっ" => Numeral::Digit(b'8'),
+            "九" | "きゅう" => Numeral::Digit(b'9'),
+            "十" | "拾" | "じゅう" => Numeral::Multiplier(1, true),
+            "百" | "ひゃく" | "びゃく" | "ぴゃく" => Numeral::Multiplier(2, true),
+            "千" | "せん" | "ぜん" => Numeral::Multiplier(3, true),
+            "万" | "萬" | "まん" => Numeral::Myriad(4),
+            "億" | "おく" => Numeral::Myriad(8),
+            "兆" | "ちょう" => Numeral::Myriad(12),
+            _ => return None,
+        };
+        Some(numeral)
+    }
+}
+
+impl LangInterpreter for Japanese {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        cjk::apply(self, num_func, b)
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        cjk::apply_decimal(self, decimal_func, b)
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        match word {
+            "点" | "てん" => Some('.'),
+            _ => None,
+        }
+    }
+
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "マイナス" => Some('-'),
+            "プラス" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn format_and_value(&self, b: &DigitString) -> (String, f64) {
+        cjk::format_and_value(b)
+    }
+
+    fn format_decimal_and_value(
+        &self,
+        int: &DigitString,
+        dec: &DigitString,
+        sep: char,
+    ) -> (String, f64) {
+        cjk::format_decimal_and_value(int, dec, sep)
+    }
+
+    fn get_morph_marker(&self, _word: &str) -> MorphologicalMarker {
+        cjk::get_morph_marker()
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(word)
+    }
+
+    fn segment<'a>(&'a self, word: &'a str) -> Option<Vec<&'a str>> {
+        cjk::segment(self, word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Japanese;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Japanese::new();
+            let res = text2digits($text, &f);
+            dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Japanese::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Japanese::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Japanese::new();
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("〇", "0");
+        assert_text2digits!("十", "10");
+        assert_text2digits!("十五", "15");
+        assert_text2digits!("百", "100");
+        assert_text2digits!("千", "1000");
+        assert_text2digits!("三千五百", "3500");
+        assert_text2digits!("三千五", "3005");
+        assert_text2digits!("一万", "10000");
+        assert_text2digits!("二十三万四千", "234000");
+        assert_text2digits!("一億二千万", "120000000");
+        assert_text2digits!("千九百八十四", "1984");
+    }
+
+    #[test]
+    fn test_kana() {
+        assert_text2digits!("さんぜんごひゃく", "3500");
+        assert_text2digits!("にじゅうさん", "23");
+        assert_text2digits!("ろっぴゃくはちじゅう", "680");
+        assert_text2digits!("いっせんきゅうひゃく", "1900");
+        assert_text2digits!("よんまん", "40000");
+        assert_text2digits!("さん まん", "30000");
+        assert_text2digits!("ひゃく", "100");
+        assert_text2digits!("ひゃくにじゅう", "120");
+        assert_text2digits!("せんきゅうひゃくきゅうじゅう", "1990");
+        assert_text2digits!("じゅうご", "15");
+        assert_text2digits!("いちまんせん", "11000");
+    }
+
+    #[test]
+    fn test_sequence() {
+        assert_text2digits!("二〇二四", "2024");
+        assert_replace_numbers!("二〇二四年", "2024年");
+    }
+
+    #[test]
+    fn test_decimals() {
+        assert_replace_numbers!("三点一四", "3.14");
+        assert_replace_numbers!("にじゅうてんご", "20.5");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("万");
+        assert_invalid!("十百");
+        assert_invalid!("二千三千");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!("二十三万四千円です", "234000円です");
+        assert_replace_numbers!("本を三千五百冊買った", "本を3500冊買った");
+        assert_replace_numbers!("せんせいは百二十歳", "せんせいは120歳");
+        assert_replace_numbers!("ひゃくにじゅうえんです", "ひゃくにじゅうえんです");
+    }
+
+    #[test]
+    fn test_kana_in_words() {
+        assert_replace_all_numbers!("東京にいます", "東京にいます");
+        assert_replace_all_numbers!("田中さんが来た", "田中さんが来た");
+        assert_replace_all_numbers!("にほんご", "にほんご");
+        assert_replace_all_numbers!("ありません", "ありません");
+        assert_replace_all_numbers!("にじゅうさん歳", "23歳");
+    }
+
+    #[test]
+    fn test_kanji_in_words() {
+        assert_replace_numbers!("とても十分です", "とても十分です");
+        assert_replace_all_numbers!("万一の場合", "万一の場合");
+        assert_replace_all_numbers!("千万気をつけて", "千万気をつけて");
+        assert_replace_numbers!("二十分後", "20分後");
+        assert_replace_numbers!("三千万円", "30000000円");
+    }
+}
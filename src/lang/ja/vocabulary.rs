@@ -0,0 +1,5 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "と", "から", "まで", "は", "です", "えー", "あの", "ええと"
+};
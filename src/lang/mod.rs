@@ -21,26 +21,91 @@ translate each number word into a sequence of elementary instructions on a `Digi
 A language is just an empty (stateless) type. Everything is provided by implementating the trait.
 
 Look at the source of the builtin languages as examples.
+
+To prototype a language or a dialect without writing an interpreter, the `tables` feature provides the `TableInterpreter`,
+whose vocabulary is loaded at runtime from a TOML or JSON definition.
+
+# Domain vocabulary
+
+Any interpreter can be wrapped in a [`Customized`] interpreter to add the filler words of a domain to its linking words,
+or to force some words to separate the numbers.
+
+# Spelling out numbers
+
+Some builtin languages also implement the reverse service, the [`Verbalize`] trait, that turns integers, ordinals and
+decimal numbers into words: Dutch, English, French, German, Italian, Portuguese and Spanish. Unlike the parsing, which understands all the regional varieties at once, verbalization must pick one:
+the interpreters of languages with regional varieties can be built for a given region (see [`FrenchRegion`] and [`PortugueseRegion`]).
+
+```
+use text2num::lang::{French, FrenchRegion, Gender, Verbalize};
+
+let fr = French::default();
+let be = French::with_region(FrenchRegion::Belgium);
+
+assert_eq!(fr.verbalize(97).unwrap(), "quatre-vingt-dix-sept");
+assert_eq!(be.verbalize(97).unwrap(), "nonante-sept");
+assert_eq!(fr.verbalize_ordinal(1, Gender::Feminine, false).unwrap(), "première");
+```
 */
+mod ar;
+mod ca;
+mod cjk;
+mod cs;
+mod custom;
+mod czechoslovak;
+mod da;
 mod de;
+mod el;
 mod en;
 mod es;
 mod fr;
+mod hi;
 mod it;
+mod ja;
 mod nl;
+mod no;
+mod pl;
 mod pt;
+mod ro;
+mod ru;
+mod scandinavian;
+mod sk;
+mod sv;
+#[cfg(feature = "tables")]
+mod table;
+mod tr;
+mod zh;
 
+use crate::decimal::Decimal;
 use crate::digit_string::DigitString;
 
 use crate::error::Error;
 
+pub use ar::Arabic;
+pub use ca::Catalan;
+pub use cs::Czech;
+pub use custom::Customized;
+pub use da::Danish;
 pub use de::German;
-pub use en::English;
+pub use el::Greek;
+pub use en::{English, EnglishRegion};
 pub use es::Spanish;
-pub use fr::French;
+pub use fr::{French, FrenchRegion};
+pub use hi::Hindi;
 pub use it::Italian;
+pub use ja::Japanese;
 pub use nl::Dutch;
-pub use pt::Portuguese;
+pub use no::Norwegian;
+pub use pl::Polish;
+pub use pt::{Portuguese, PortugueseRegion};
+pub use ro::Romanian;
+pub use ru::Russian;
+pub use sk::Slovak;
+pub use sv::Swedish;
+#[cfg(feature = "tables")]
+pub use table::{TableError, TableInterpreter};
+pub use tr::Turkish;
+pub use zh::Chinese;
 
 pub trait BasicAnnotate {
     fn text_lowercase(&self) -> &str;
@@ -52,13 +117,56 @@ pub trait BasicAnnotate {
 ///
 /// For examples in English, "*twentieth*" becomes "*20th*", the ordinal marker "*th*"
 /// (`MorphologicalMarker::Ordinal("th")`) is kept.
-#[derive(Debug, PartialEq)]
+///
+/// With the `serde` feature, markers are serialized like `{"Ordinal": "th"}` or `"None"`, and deserialized
+/// as an [`OwnedMarker`], since their suffixes are static.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum MorphologicalMarker {
     Ordinal(&'static str),
     Fraction(&'static str),
     None,
 }
 
+/// A [`MorphologicalMarker`] that owns its suffix, which a marker is deserialized as.
+///
+/// ```rust
+/// use text2num::lang::{MorphologicalMarker, OwnedMarker};
+///
+/// let marker: OwnedMarker = serde_json::from_str(r#"{"Ordinal": "th"}"#).unwrap();
+/// assert_eq!(marker, MorphologicalMarker::Ordinal("th"));
+/// ```
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum OwnedMarker {
+    Ordinal(String),
+    Fraction(String),
+    None,
+}
+
+#[cfg(feature = "serde")]
+impl From<MorphologicalMarker> for OwnedMarker {
+    fn from(marker: MorphologicalMarker) -> Self {
+        match marker {
+            MorphologicalMarker::Ordinal(suffix) => Self::Ordinal(suffix.to_owned()),
+            MorphologicalMarker::Fraction(suffix) => Self::Fraction(suffix.to_owned()),
+            MorphologicalMarker::None => Self::None,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl PartialEq<MorphologicalMarker> for OwnedMarker {
+    fn eq(&self, other: &MorphologicalMarker) -> bool {
+        match (self, other) {
+            (Self::Ordinal(suffix), MorphologicalMarker::Ordinal(other))
+            | (Self::Fraction(suffix), MorphologicalMarker::Fraction(other)) => suffix == other,
+            (Self::None, MorphologicalMarker::None) => true,
+            _ => false,
+        }
+    }
+}
+
 impl MorphologicalMarker {
     pub fn is_ordinal(&self) -> bool {
         matches!(self, Self::Ordinal(_))
@@ -95,6 +203,53 @@ pub trait LangInterpreter {
     ///
     /// For example "*point*" is a decimal separator in English, figured as `'.'`
     fn check_decimal_separator(&self, word: &str) -> Option<char>;
+    /// Return `Some(sign)` if the `word` is a sign that can precede a number, `sign` being `'-'` or `'+'`.
+    ///
+    /// For example "*minus*" is a negative sign in English. Signs are only interpreted on demand
+    /// (see [`ConversionOptions::convert_signs`](crate::ConversionOptions::convert_signs)).
+    fn check_sign(&self, _word: &str) -> Option<char> {
+        None
+    }
+    /// Return the symbol used as decimal separator in the digit form of decimal numbers that were not spelled with one,
+    /// like fractions converted to decimal numbers.
+    fn decimal_separator(&self) -> char {
+        '.'
+    }
+    /// Interpret the word `frac_func` as the denominator of a fraction, whose `numerator` was given by the previous words.
+    ///
+    /// `b` is a new `DigitString` for the denominator. For example, in English, "*quarters*" puts `4`, so
+    /// "*three quarters*" is understood as `3/4`.
+    /// The default implementation does not recognize any fraction.
+    fn apply_denominator(
+        &self,
+        _frac_func: &str,
+        _numerator: &DigitString,
+        _b: &mut DigitString,
+    ) -> Result<(), Error> {
+        Err(Error::NaN)
+    }
+    /// Return true if `word` may introduce the fractional part of a mixed number, like "*and*" and "*a*"
+    /// in "*two and a half*".
+    ///
+    /// The fractional part of a mixed number has an explicit numerator, like in "*two and three quarters*",
+    /// or an implicit numerator of one.
+    fn is_fraction_link(&self, _word: &str) -> bool {
+        false
+    }
+    /// Return true if `word` is the plural of an ordinal multiplier, like "*hundredths*", which is the denominator
+    /// of a fraction after a number, "*five hundredths*" being 5/100, rather than the end of an ordinal.
+    ///
+    /// The default implementation returns `false`.
+    fn is_multiplier_denominator(&self, _word: &str) -> bool {
+        false
+    }
+    /// Split a word spelling a whole mixed number, like the German "*zweieinhalb*", into the words of its integral
+    /// part and of its denominator, the numerator being one.
+    ///
+    /// The default implementation does not split anything.
+    fn split_mixed<'a>(&self, _word: &'a str) -> Option<(&'a str, &'a str)> {
+        None
+    }
     /// Format `b` as digit string and evaluate it, according to the language's rules.
     fn format_and_value(&self, b: &DigitString) -> (String, f64);
     /// Format the decimal number given as integral part `int` and decimals `dec` according the the language's rules
@@ -113,6 +268,21 @@ pub trait LangInterpreter {
     /// that separate unrelated numbers. So the method would return `false` for them.
     /// This function is used to find isolate numbers.
     fn is_linking(&self, word: &str) -> bool;
+    /// Return true if `word` always ends the current number and isolates it from the next ones, even if the word is
+    /// otherwise insignificant or part of the number vocabulary, like a conjunction.
+    ///
+    /// The default implementation returns `false`: only the language rules decide.
+    fn is_separator(&self, _word: &str) -> bool {
+        false
+    }
+    /// Split the `word` into the words it is made of, for the languages written without spaces, like Chinese.
+    ///
+    /// The text tokenizer only splits on whitespace and punctuation, so "*我有二十三万元*" is a single word that must be
+    /// split into "*我有*", "*二*", "*十*", "*三*", "*万*" and "*元*" before looking for numbers.
+    /// The default implementation returns `None` to keep the word whole.
+    fn segment<'a>(&'a self, _word: &'a str) -> Option<Vec<&'a str>> {
+        None
+    }
     /// Process the `group` as all or nothing.
     fn exec_group<'a, I: Iterator<Item = &'a str>>(&self, group: I) -> Result<DigitString, Error> {
         let mut b = DigitString::new();
@@ -134,6 +304,90 @@ pub trait LangInterpreter {
     fn basic_annotate<T: BasicAnnotate>(&self, _tokens: &mut Vec<T>) {}
 }
 
+/// Denominator for the languages whose fraction denominators are ordinals, from "*third*" on.
+///
+/// In most languages, "*second*" is the unit of time rather than the half.
+/// If the indefinite article is also the number one, like in French, a numerator of one makes an ordinal instead
+/// ("*un douzième essai*"), so `article_is_one` must be set.
+fn ordinal_denominator<L: LangInterpreter>(
+    lang: &L,
+    frac_func: &str,
+    numerator: &DigitString,
+    article_is_one: bool,
+    b: &mut DigitString,
+) -> Result<(), Error> {
+    if article_is_one && numerator.len() == 1 && numerator.peek(1) == b"1" {
+        return Err(Error::NaN);
+    }
+    lang.apply(frac_func, b)?;
+    if !b.marker.is_none() && (b.len() > 1 || b.peek(1) > b"2".as_slice()) {
+        Ok(())
+    } else {
+        Err(Error::NaN)
+    }
+}
+
+/// Group the `digits` in the Indian style: the last three, then by two, like "5,20,000".
+///
+/// The numbers with leading zeros, like phone numbers, are left as is.
+fn indian_grouping(digits: &str) -> String {
+    if digits.len() <= 3 || digits.starts_with('0') {
+        return digits.to_owned();
+    }
+    let (head, last) = digits.split_at(digits.len() - 3);
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 2);
+    for (i, digit) in head.chars().enumerate() {
+        if i > 0 && (head.len() - i) % 2 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped.push(',');
+    grouped.push_str(last);
+    grouped
+}
+
+/// Grammatical gender, for the languages whose ordinals agree in gender.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Gender {
+    #[default]
+    Masculine,
+    Feminine,
+    Neuter,
+}
+
+/// This trait describes the reverse service: spelling out numbers in words.
+///
+/// All methods return `None` if the number is beyond what the language (or its implementation) can express.
+pub trait Verbalize {
+    /// Spell out the cardinal `n`.
+    fn verbalize(&self, n: u64) -> Option<String>;
+    /// Spell out the ordinal of rank `n`, agreeing in `gender` and number (`plural`) where the language has them.
+    ///
+    /// There is no ordinal for rank 0.
+    fn verbalize_ordinal(&self, n: u64, gender: Gender, plural: bool) -> Option<String>;
+    /// Spell out the non negative decimal number `value`.
+    fn verbalize_decimal(&self, value: &Decimal) -> Option<String>;
+}
+
+/// Rewrite the last word of `text` with `f`, words being delimited by any of the `separators`.
+fn map_last_word<F: FnOnce(&str) -> String>(text: &str, separators: &[char], f: F) -> String {
+    let split = text.rfind(separators).map_or(0, |pos| pos + 1);
+    let (head, last) = text.split_at(split);
+    format!("{head}{}", f(last))
+}
+
+/// Split the non negative decimal `value` into its integral part and the digits of its decimal part.
+fn decimal_parts(value: &Decimal) -> Option<(u64, String)> {
+    if value.is_negative() {
+        return None;
+    }
+    let repr = value.to_string();
+    let (int, dec) = repr.split_once('.').unwrap_or((&repr, ""));
+    Some((int.parse().ok()?, dec.to_owned()))
+}
+
 /// A convenience enum that encapsulates the builtin languages in a single type.
 pub enum Language {
     English(English),
@@ -143,6 +397,21 @@ pub enum Language {
     Spanish(Spanish),
     Dutch(Dutch),
     Portuguese(Portuguese),
+    Russian(Russian),
+    Polish(Polish),
+    Catalan(Catalan),
+    Romanian(Romanian),
+    Swedish(Swedish),
+    Danish(Danish),
+    Norwegian(Norwegian),
+    Turkish(Turkish),
+    Greek(Greek),
+    Czech(Czech),
+    Slovak(Slovak),
+    Chinese(Chinese),
+    Japanese(Japanese),
+    Hindi(Hindi),
+    Arabic(Arabic),
 }
 
 impl Language {
@@ -173,6 +442,70 @@ impl Language {
     pub fn portuguese() -> Self {
         Language::Portuguese(Portuguese::default())
     }
+
+    pub fn russian() -> Self {
+        Language::Russian(Russian::default())
+    }
+
+    pub fn polish() -> Self {
+        Language::Polish(Polish::default())
+    }
+
+    pub fn catalan() -> Self {
+        Language::Catalan(Catalan::default())
+    }
+
+    pub fn romanian() -> Self {
+        Language::Romanian(Romanian::default())
+    }
+
+    pub fn swedish() -> Self {
+        Language::Swedish(Swedish::default())
+    }
+
+    pub fn danish() -> Self {
+        Language::Danish(Danish::default())
+    }
+
+    pub fn norwegian() -> Self {
+        Language::Norwegian(Norwegian::default())
+    }
+
+    pub fn turkish() -> Self {
+        Language::Turkish(Turkish::default())
+    }
+
+    pub fn greek() -> Self {
+        Language::Greek(Greek::default())
+    }
+
+    pub fn czech() -> Self {
+        Language::Czech(Czech::default())
+    }
+
+    pub fn slovak() -> Self {
+        Language::Slovak(Slovak::default())
+    }
+
+    pub fn chinese() -> Self {
+        Language::Chinese(Chinese::default())
+    }
+
+    pub fn japanese() -> Self {
+        Language::Japanese(Japanese::default())
+    }
+
+    pub fn indian_english() -> Self {
+        Language::English(English::with_region(EnglishRegion::India))
+    }
+
+    pub fn hindi() -> Self {
+        Language::Hindi(Hindi::default())
+    }
+
+    pub fn arabic() -> Self {
+        Language::Arabic(Arabic::default())
+    }
 }
 
 macro_rules! delegate {
@@ -207,6 +540,53 @@ macro_rules! delegate {
                 )*
             }
         }
+        fn check_sign(&self, word: &str) -> Option<char> {
+            match self {
+                $(
+                    Language::$variant(l) => l.check_sign(word),
+                )*
+            }
+        }
+        fn decimal_separator(&self) -> char {
+            match self {
+                $(
+                    Language::$variant(l) => l.decimal_separator(),
+                )*
+            }
+        }
+
+        fn apply_denominator(&self, frac_func: &str, numerator: &DigitString, b: &mut DigitString) -> Result<(), Error> {
+            match self {
+                $(
+                    Language::$variant(l) => l.apply_denominator(frac_func, numerator, b),
+                )*
+            }
+        }
+
+        fn is_fraction_link(&self, word: &str) -> bool {
+            match self {
+                $(
+                    Language::$variant(l) => l.is_fraction_link(word),
+                )*
+            }
+        }
+
+        fn is_multiplier_denominator(&self, word: &str) -> bool {
+            match self {
+                $(
+                    Language::$variant(l) => l.is_multiplier_denominator(word),
+                )*
+            }
+        }
+
+        fn split_mixed<'a>(&self, word: &'a str) -> Option<(&'a str, &'a str)> {
+            match self {
+                $(
+                    Language::$variant(l) => l.split_mixed(word),
+                )*
+            }
+        }
+
         fn format_and_value(&self, b: &DigitString) -> (String, f64){
             match self{
                 $(
@@ -230,6 +610,22 @@ macro_rules! delegate {
             }
         }
 
+        fn is_separator(&self, word: &str) -> bool {
+            match self {
+                $(
+                    Language::$variant(l) => l.is_separator(word),
+                )*
+            }
+        }
+
+        fn segment<'a>(&'a self, word: &'a str) -> Option<Vec<&'a str>> {
+            match self {
+                $(
+                    Language::$variant(l) => l.segment(word),
+                )*
+            }
+        }
+
         fn basic_annotate<T: BasicAnnotate>(&self, tokens: &mut Vec<T>) {
             match self {
                 $(
@@ -241,5 +637,45 @@ macro_rules! delegate {
 }
 
 impl LangInterpreter for Language {
-    delegate!(Dutch, French, English, German, Italian, Spanish, Portuguese);
+    delegate!(
+        Dutch, French, English, German, Italian, Spanish, Portuguese, Russian, Polish, Catalan,
+        Romanian, Swedish, Danish, Norwegian, Turkish, Greek, Czech, Slovak, Chinese, Japanese,
+        Hindi, Arabic
+    );
+}
+
+macro_rules! delegate_verbalize {
+    ($($variant:ident), +) => {
+        fn verbalize(&self, n: u64) -> Option<String> {
+            match self {
+                $(
+                    Language::$variant(l) => l.verbalize(n),
+                )*
+                _ => None,
+            }
+        }
+
+        fn verbalize_ordinal(&self, n: u64, gender: Gender, plural: bool) -> Option<String> {
+            match self {
+                $(
+                    Language::$variant(l) => l.verbalize_ordinal(n, gender, plural),
+                )*
+                _ => None,
+            }
+        }
+
+        fn verbalize_decimal(&self, value: &Decimal) -> Option<String> {
+            match self {
+                $(
+                    Language::$variant(l) => l.verbalize_decimal(value),
+                )*
+                _ => None,
+            }
+        }
+    };
+}
+
+/// The languages without verbalization support always return `None`.
+impl Verbalize for Language {
+    delegate_verbalize!(Dutch, French, English, German, Italian, Spanish, Portuguese);
 }
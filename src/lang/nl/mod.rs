@@ -9,9 +9,10 @@ use crate::digit_string::DigitString;
 use crate::error::Error;
 use crate::tokenizer::WordSplitter;
 
+mod verbalize;
 mod vocabulary;
 
-use super::{LangInterpreter, MorphologicalMarker};
+use super::{LangInterpreter, MorphologicalMarker, ordinal_denominator};
 use vocabulary::INSIGNIFICANT;
 
 bitflags! {
@@ -236,11 +237,40 @@ impl LangInterpreter for Dutch {
         if word == "komma" { Some(',') } else { None }
     }
 
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "min" => Some('-'),
+            "plus" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn apply_denominator(
+        &self,
+        frac_func: &str,
+        numerator: &DigitString,
+        b: &mut DigitString,
+    ) -> Result<(), Error> {
+        match frac_func {
+            "half" | "halve" => b.put(b"2"),
+            "kwart" | "kwarten" => b.put(b"4"),
+            _ => ordinal_denominator(self, frac_func, numerator, true, b),
+        }
+    }
+
+    fn is_fraction_link(&self, word: &str) -> bool {
+        matches!(word, "en")
+    }
+
     fn format_and_value(&self, b: &DigitString) -> (String, f64) {
         let repr = b.to_string();
         let val: f64 = repr.parse().unwrap();
         if let MorphologicalMarker::Ordinal(marker) = b.marker {
-            (format!("{}{}", b.to_string(), marker), val)
+            (format!("{repr}{marker}"), val)
         } else {
             (repr, val)
         }
@@ -275,6 +305,7 @@ impl LangInterpreter for Dutch {
 #[cfg(test)]
 mod tests {
     use super::Dutch;
+    use crate::ConversionOptions;
     use crate::word_to_digit::{replace_numbers_in_text, text2digits};
 
     macro_rules! assert_text2digits {
@@ -294,6 +325,17 @@ mod tests {
         };
     }
 
+    macro_rules! assert_replace_fractions {
+        ($text:expr, $res:expr) => {
+            assert_replace_fractions!($text, $res, 10.0)
+        };
+        ($text:expr, $res:expr, $threshold:expr) => {
+            let f = Dutch::new();
+            let options = ConversionOptions::from($threshold).convert_fractions(true);
+            assert_eq!(replace_numbers_in_text($text, &f, options), $res)
+        };
+    }
+
     macro_rules! assert_replace_all_numbers {
         ($text:expr, $res:expr) => {
             let f = Dutch::new();
@@ -336,8 +378,6 @@ mod tests {
         );
     }
 
-    ///
-
     #[test]
     fn test_apply() {
         assert_text2digits!("tweeëntwintig", "22");
@@ -386,6 +426,12 @@ mod tests {
     fn test_fractions() {
         assert_text2digits!("vijfentwintigste", "25e");
         assert_text2digits!("eenentwintigste", "21e");
+        assert_replace_fractions!("drie kwart en twee derde", "3/4 en 2/3");
+        assert_replace_fractions!("een half", "1/2");
+        assert_replace_fractions!("een twintigste poging", "een 20e poging");
+        assert_replace_fractions!("twee en een half uur", "2 1/2 uur");
+        assert_replace_fractions!("twee en drie kwart", "2 3/4");
+        assert_replace_fractions!("twee en drie katten", "2 en 3 katten", 0.0);
     }
 
     #[test]
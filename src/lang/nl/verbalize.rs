@@ -0,0 +1,194 @@
+//! Spelling out numbers in Dutch.
+
+use super::Dutch;
+use crate::decimal::Decimal;
+use crate::lang::{Gender, Verbalize, decimal_parts, map_last_word};
+
+const UNITS: [&str; 20] = [
+    "nul",
+    "een",
+    "twee",
+    "drie",
+    "vier",
+    "vijf",
+    "zes",
+    "zeven",
+    "acht",
+    "negen",
+    "tien",
+    "elf",
+    "twaalf",
+    "dertien",
+    "veertien",
+    "vijftien",
+    "zestien",
+    "zeventien",
+    "achttien",
+    "negentien",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twintig", "dertig", "veertig", "vijftig", "zestig", "zeventig", "tachtig", "negentig",
+];
+
+fn below_hundred(n: usize) -> String {
+    match (n / 10, n % 10) {
+        _ if n < 20 => UNITS[n].to_owned(),
+        (tens, 0) => TENS[tens].to_owned(),
+        // "tweeëntwintig", "drieëndertig" but "vierentwintig"
+        (tens, units) if UNITS[units].ends_with('e') => format!("{}ën{}", UNITS[units], TENS[tens]),
+        (tens, units) => format!("{}en{}", UNITS[units], TENS[tens]),
+    }
+}
+
+/// Numbers below a thousand are written as a single word.
+fn below_thousand(n: usize) -> String {
+    match (n / 100, n % 100) {
+        (0, rest) => below_hundred(rest),
+        (1, 0) => "honderd".to_owned(),
+        (1, rest) => format!("honderd{}", below_hundred(rest)),
+        (hundreds, 0) => format!("{}honderd", UNITS[hundreds]),
+        (hundreds, rest) => format!("{}honderd{}", UNITS[hundreds], below_hundred(rest)),
+    }
+}
+
+fn cardinal(n: u64) -> Option<String> {
+    if n >= 1_000_000_000_000 {
+        return None;
+    }
+    if n == 0 {
+        return Some(UNITS[0].to_owned());
+    }
+    let mut words: Vec<String> = Vec::with_capacity(4);
+    for (scale, name) in [(1_000_000_000, " miljard"), (1_000_000, " miljoen")] {
+        let group = (n / scale % 1000) as usize;
+        if group > 0 {
+            words.push(format!("{}{name}", below_thousand(group)));
+        }
+    }
+    // "duizend" is part of the compound word
+    match (n / 1000 % 1000) as usize {
+        0 => (),
+        1 => words.push("duizend".to_owned()),
+        group => words.push(format!("{}duizend", below_thousand(group))),
+    }
+    let rest = (n % 1000) as usize;
+    if rest > 0 {
+        words.push(below_thousand(rest));
+    }
+    Some(words.join(" "))
+}
+
+fn ordinal_word(word: &str) -> String {
+    if let Some(stem) = word.strip_suffix("een") {
+        format!("{stem}eerste")
+    } else if let Some(stem) = word.strip_suffix("drie") {
+        format!("{stem}derde")
+    } else if word.ends_with("tig")
+        || word.ends_with("acht")
+        || word.ends_with("honderd")
+        || word.ends_with("duizend")
+        || word.ends_with("miljoen")
+        || word.ends_with("miljard")
+    {
+        format!("{word}ste")
+    } else {
+        format!("{word}de")
+    }
+}
+
+impl Verbalize for Dutch {
+    fn verbalize(&self, n: u64) -> Option<String> {
+        cardinal(n)
+    }
+
+    fn verbalize_ordinal(&self, n: u64, _gender: Gender, _plural: bool) -> Option<String> {
+        if n == 0 {
+            return None;
+        }
+        let words = cardinal(n)?;
+        let words = if n == 1_000_000 || n == 1_000_000_000 {
+            words.trim_start_matches("een ").to_owned()
+        } else {
+            words
+        };
+        Some(map_last_word(&words, &[' '], ordinal_word))
+    }
+
+    fn verbalize_decimal(&self, value: &Decimal) -> Option<String> {
+        let (int, dec) = decimal_parts(value)?;
+        let mut words = cardinal(int)?;
+        if !dec.is_empty() {
+            words.push_str(" komma");
+            let significant = dec.trim_start_matches('0');
+            for _ in 0..(dec.len() - significant.len()) {
+                words.push_str(" nul");
+            }
+            if !significant.is_empty() {
+                words.push(' ');
+                words.push_str(&cardinal(significant.parse().ok()?)?);
+            }
+        }
+        Some(words)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    #[test]
+    fn test_cardinals() {
+        let nl = Dutch::new();
+        assert_eq!(nl.verbalize(22).unwrap(), "tweeëntwintig");
+        assert_eq!(nl.verbalize(81).unwrap(), "eenentachtig");
+        assert_eq!(nl.verbalize(132).unwrap(), "honderdtweeëndertig");
+        assert_eq!(
+            nl.verbalize(742_986).unwrap(),
+            "zevenhonderdtweeënveertigduizend negenhonderdzesentachtig"
+        );
+        assert_eq!(
+            nl.verbalize(1_025_000_000).unwrap(),
+            "een miljard vijfentwintig miljoen"
+        );
+    }
+
+    #[test]
+    fn test_ordinals() {
+        let nl = Dutch::new();
+        let ordinal = |n| nl.verbalize_ordinal(n, Gender::Neuter, false).unwrap();
+        assert_eq!(ordinal(1), "eerste");
+        assert_eq!(ordinal(3), "derde");
+        assert_eq!(ordinal(8), "achtste");
+        assert_eq!(ordinal(19), "negentiende");
+        assert_eq!(ordinal(21), "eenentwintigste");
+        assert_eq!(ordinal(1000), "duizendste");
+    }
+
+    #[test]
+    fn test_decimals() {
+        let nl = Dutch::new();
+        let value: Decimal = "3.05".parse().unwrap();
+        let words = nl.verbalize_decimal(&value).unwrap();
+        assert_eq!(words, "drie komma nul vijf");
+        assert_eq!(replace_numbers_in_text(&words, &nl, 0.0), "3,05");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let nl = Dutch::new();
+        for n in (0..2000).chain((1..1_000_000_000_000).step_by(7_919_111_111)) {
+            let words = nl.verbalize(n).unwrap();
+            assert_eq!(text2digits(&words, &nl).unwrap(), n.to_string(), "{words}");
+        }
+        for n in 1..200 {
+            let words = nl.verbalize_ordinal(n, Gender::Neuter, false).unwrap();
+            assert_eq!(
+                text2digits(&words, &nl).unwrap(),
+                format!("{n}e"),
+                "{words}"
+            );
+        }
+    }
+}
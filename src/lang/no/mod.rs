@@ -0,0 +1,318 @@
+//! Norwegian (Bokmål) number interpreter
+//!
+//! Both counting systems are understood: the official one puts the tens first, "tjuefem", and the traditional one
+//! puts the units first, "femogtjue". The interpreter accepts compounds split in several words,
+//! as the main application, Speech-to-text recognition, may introduce spurious spaces.
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+use crate::tokenizer::WordSplitter;
+
+mod vocabulary;
+
+use super::scandinavian::{self, Dialect, Numeral};
+use super::{LangInterpreter, MorphologicalMarker};
+use vocabulary::INSIGNIFICANT;
+
+pub struct Norwegian {
+    word_splitter: WordSplitter,
+}
+
+impl Default for Norwegian {
+    fn default() -> Self {
+        Self {
+            word_splitter: WordSplitter::new([
+                "tjue",
+                "tjuende",
+                "tyve",
+                "tyvende",
+                "tretti",
+                "trettiende",
+                "tredve",
+                "tredevte",
+                "førti",
+                "førtiende",
+                "femti",
+                "femtiende",
+                "seksti",
+                "sekstiende",
+                "sytti",
+                "syttiende",
+                "åtti",
+                "åttiende",
+                "nitti",
+                "nittiende",
+                "hundre",
+                "hundrede",
+                "tusen",
+                "tusende",
+                "ettusen",
+                "ettusende",
+                "million",
+                "millioner",
+                "millionte",
+                "milliard",
+                "milliarder",
+                "milliardte",
+                // connector
+                "og",
+            ])
+            .unwrap(),
+        }
+    }
+}
+
+impl Norwegian {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl Dialect for Norwegian {
+    const UNITS_FIRST: bool = true;
+    const TENS_FIRST: bool = true;
+    const HALVES: &'static [&'static str] = &["halv", "halvt", "halve", "halvdel", "halvdeler"];
+    const PARTS: &'static [&'static str] = &["del", "deler"];
+    const MERGED: &'static [(&'static str, [&'static str; 2])] = &[
+        ("ettusen", ["ett", "tusen"]),
+        ("ettusende", ["ett", "tusende"]),
+    ];
+
+    fn word_splitter(&self) -> &WordSplitter {
+        &self.word_splitter
+    }
+
+    fn numeral(&self, word: &str) -> Option<(Numeral, Option<&'static str>)> {
+        let numeral = match word {
+            "null" => (Numeral::Zero, None),
+            "en" | "ett" | "ei" => (Numeral::Unit(b'1'), None),
+            "første" => (Numeral::Unit(b'1'), Some(".")),
+            "to" => (Numeral::Unit(b'2'), None),
+            "andre" => (Numeral::Unit(b'2'), Some(".")),
+            "tre" => (Numeral::Unit(b'3'), None),
+            "tredje" => (Numeral::Unit(b'3'), Some(".")),
+            "fire" => (Numeral::Unit(b'4'), None),
+            "fjerde" => (Numeral::Unit(b'4'), Some(".")),
+            "fem" => (Numeral::Unit(b'5'), None),
+            "femte" => (Numeral::Unit(b'5'), Some(".")),
+            "seks" => (Numeral::Unit(b'6'), None),
+            "sjette" => (Numeral::Unit(b'6'), Some(".")),
+            "sju" | "syv" => (Numeral::Unit(b'7'), None),
+            "sjuende" | "syvende" => (Numeral::Unit(b'7'), Some(".")),
+            "åtte" => (Numeral::Unit(b'8'), None),
+            "åttende" => (Numeral::Unit(b'8'), Some(".")),
+            "ni" => (Numeral::Unit(b'9'), None),
+            "niende" => (Numeral::Unit(b'9'), Some(".")),
+            "ti" => (Numeral::Teen(b"10"), None),
+            "tiende" => (Numeral::Teen(b"10"), Some(".")),
+            "elleve" => (Numeral::Teen(b"11"), None),
+            "ellevte" => (Numeral::Teen(b"11"), Some(".")),
+            "tolv" => (Numeral::Teen(b"12"), None),
+            "tolvte" => (Numeral::Teen(b"12"), Some(".")),
+            "tretten" => (Numeral::Teen(b"13"), None),
+            "trettende" => (Numeral::Teen(b"13"), Some(".")),
+            "fjorten" => (Numeral::Teen(b"14"), None),
+            "fjortende" => (Numeral::Teen(b"14"), Some(".")),
+            "femten" => (Numeral::Teen(b"15"), None),
+            "femtende" => (Numeral::Teen(b"15"), Some(".")),
+            "seksten" => (Numeral::Teen(b"16"), None),
+            "sekstende" => (Numeral::Teen(b"16"), Some(".")),
+            "sytten" => (Numeral::Teen(b"17"), None),
+            "syttende" => (Numeral::Teen(b"17"), Some(".")),
+            "atten" => (Numeral::Teen(b"18"), None),
+            "attende" => (Numeral::Teen(b"18"), Some(".")),
+            "nitten" => (Numeral::Teen(b"19"), None),
+            "nittende" => (Numeral::Teen(b"19"), Some(".")),
+            "tjue" | "tyve" => (Numeral::Tens(b'2'), None),
+            "tjuende" | "tyvende" => (Numeral::Tens(b'2'), Some(".")),
+            "tretti" | "tredve" => (Numeral::Tens(b'3'), None),
+            "trettiende" | "tredevte" => (Numeral::Tens(b'3'), Some(".")),
+            "førti" => (Numeral::Tens(b'4'), None),
+            "førtiende" => (Numeral::Tens(b'4'), Some(".")),
+            "femti" => (Numeral::Tens(b'5'), None),
+            "femtiende" => (Numeral::Tens(b'5'), Some(".")),
+            "seksti" => (Numeral::Tens(b'6'), None),
+            "sekstiende" => (Numeral::Tens(b'6'), Some(".")),
+            "sytti" => (Numeral::Tens(b'7'), None),
+            "syttiende" => (Numeral::Tens(b'7'), Some(".")),
+            "åtti" => (Numeral::Tens(b'8'), None),
+            "åttiende" => (Numeral::Tens(b'8'), Some(".")),
+            "nitti" => (Numeral::Tens(b'9'), None),
+            "nittiende" => (Numeral::Tens(b'9'), Some(".")),
+            "hundre" => (Numeral::Hundred, None),
+            "hundrede" => (Numeral::Hundred, Some(".")),
+            "tusen" => (Numeral::Thousand, None),
+            "tusende" => (Numeral::Thousand, Some(".")),
+            "million" | "millioner" => (Numeral::Million, None),
+            "millionte" => (Numeral::Million, Some(".")),
+            "milliard" | "milliarder" => (Numeral::Milliard, None),
+            "milliardte" => (Numeral::Milliard, Some(".")),
+            "og" => (Numeral::And, None),
+            _ => return None,
+        };
+        Some(numeral)
+    }
+}
+
+impl LangInterpreter for Norwegian {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        scandinavian::apply(self, num_func, b)
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        self.apply(decimal_func, b)
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        if word == "komma" { Some(',') } else { None }
+    }
+
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "minus" => Some('-'),
+            "pluss" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn apply_denominator(
+        &self,
+        frac_func: &str,
+        numerator: &DigitString,
+        b: &mut DigitString,
+    ) -> Result<(), Error> {
+        scandinavian::apply_denominator(self, frac_func, numerator, b)
+    }
+
+    fn is_fraction_link(&self, word: &str) -> bool {
+        matches!(word, "og" | "en" | "ett" | "ei")
+    }
+
+    fn format_and_value(&self, b: &DigitString) -> (String, f64) {
+        scandinavian::format_and_value(b)
+    }
+
+    fn format_decimal_and_value(
+        &self,
+        int: &DigitString,
+        dec: &DigitString,
+        sep: char,
+    ) -> (String, f64) {
+        scandinavian::format_decimal_and_value(int, dec, sep)
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        scandinavian::get_morph_marker(self, word)
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Norwegian;
+    use crate::ConversionOptions;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Norwegian::new();
+            let res = text2digits($text, &f);
+            dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Norwegian::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_fractions {
+        ($text:expr, $res:expr) => {
+            assert_replace_fractions!($text, $res, 10.0)
+        };
+        ($text:expr, $res:expr, $threshold:expr) => {
+            let f = Norwegian::new();
+            let options = ConversionOptions::from($threshold).convert_fractions(true);
+            assert_eq!(replace_numbers_in_text($text, &f, options), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Norwegian::new();
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("null", "0");
+        assert_text2digits!("tolv", "12");
+        assert_text2digits!("tjuefem", "25");
+        assert_text2digits!("tjue fem", "25");
+        assert_text2digits!("femogtjue", "25");
+        assert_text2digits!("fem og tyve", "25");
+        assert_text2digits!("nittini", "99");
+        assert_text2digits!("hundre", "100");
+        assert_text2digits!("ett hundre og femten", "115");
+        assert_text2digits!("to hundre og trettifire", "234");
+        assert_text2digits!("nitten hundre og åttifire", "1984");
+        assert_text2digits!("tretusen", "3000");
+        assert_text2digits!("ettusen", "1000");
+        assert_text2digits!(
+            "femtitre milliarder to hundre og førtitre tusen sju hundre og tjuefire",
+            "53000243724"
+        );
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_text2digits!("første", "1.");
+        assert_text2digits!("tredje", "3.");
+        assert_text2digits!("tjueførste", "21.");
+        assert_text2digits!("enogtjuende", "21.");
+        assert_text2digits!("hundrede", "100.");
+        assert_replace_numbers!("den tjuefjerde mai", "den 24. mai");
+    }
+
+    #[test]
+    fn test_fractions() {
+        assert_replace_fractions!("tre fjerdedeler", "3/4");
+        assert_replace_fractions!("to og en halv", "2 1/2");
+    }
+
+    #[test]
+    fn test_decimals() {
+        assert_replace_numbers!("tolv komma fem", "12,5");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("tusen tusen to hundre");
+        assert_invalid!("seksti femten");
+        assert_invalid!("ti fem");
+        assert_invalid!("femtjue");
+        assert_invalid!("null fem null");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!(
+            "tjuefem kyr, tolv høner og hundre og tjuefem kg poteter.",
+            "25 kyr, 12 høner og 125 kg poteter."
+        );
+        assert_replace_numbers!("en to tre fire tjue femten", "1 2 3 4 20 15");
+    }
+}
@@ -0,0 +1,5 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "og", "eller", "så", "altså", "liksom", "ja", "nei", "okei", "eh", "øh", "hm", "pluss", "minus", "med"
+};
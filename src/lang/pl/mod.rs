@@ -0,0 +1,470 @@
+//! Polish number interpreter
+//!
+//! Numerals are understood in all their cases and genders. Compound ordinals are ordinal in their tens and units,
+//! like "*dwudziesty pierwszy*" (21st), and are written with a final dot: "*21.*".
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+
+mod vocabulary;
+
+use super::{LangInterpreter, MorphologicalMarker, ordinal_denominator};
+use vocabulary::INSIGNIFICANT;
+
+/// Is `word` a form of the noun `stem` + one of the `endings`?
+fn is_inflection_of(word: &str, stem: &str, endings: &[&str]) -> bool {
+    word.strip_prefix(stem)
+        .is_some_and(|ending| endings.contains(&ending))
+}
+
+const THOUSAND_ENDINGS: [&str; 9] = [
+    "ąc", "ące", "ęcy", "ąca", "ącu", "ącem", "ącom", "ącami", "ącach",
+];
+const MILLION_ENDINGS: [&str; 10] = ["", "y", "ów", "a", "owi", "em", "ie", "om", "ami", "ach"];
+
+/// Ordinal adjective endings, longest first.
+const ORDINAL_ENDINGS: [&str; 20] = [
+    "iego", "iemu", "ych", "ich", "ymi", "imi", "ego", "emu", "iej", "ia", "ie", "ią", "ym", "im",
+    "ej", "y", "i", "a", "e", "ą",
+];
+
+/// How an ordinal stem builds the number.
+enum Rank {
+    Digits(&'static [u8]),
+    /// A multiplier, like "*tysięczny*", with its optional prefix, like "*dwu*" in "*dwutysięczny*".
+    Multiplier(usize, &'static [u8]),
+}
+
+fn ordinal_rank(stem: &str) -> Option<Rank> {
+    let digits: &[u8] = match stem {
+        "pierwsz" => b"1",
+        "drug" => b"2",
+        "trzec" => b"3",
+        "czwart" => b"4",
+        "piąt" => b"5",
+        "szóst" => b"6",
+        "siódm" => b"7",
+        "ósm" => b"8",
+        "dziewiąt" => b"9",
+        "dziesiąt" => b"10",
+        "jedenast" => b"11",
+        "dwunast" => b"12",
+        "trzynast" => b"13",
+        "czternast" => b"14",
+        "piętnast" => b"15",
+        "szesnast" => b"16",
+        "siedemnast" => b"17",
+        "osiemnast" => b"18",
+        "dziewiętnast" => b"19",
+        "dwudziest" => b"20",
+        "trzydziest" => b"30",
+        "czterdziest" => b"40",
+        "pięćdziesiąt" => b"50",
+        "sześćdziesiąt" => b"60",
+        "siedemdziesiąt" => b"70",
+        "osiemdziesiąt" => b"80",
+        "dziewięćdziesiąt" => b"90",
+        "setn" => b"100",
+        "dwusetn" => b"200",
+        "trzechsetn" => b"300",
+        "czterechsetn" => b"400",
+        "pięćsetn" => b"500",
+        "sześćsetn" => b"600",
+        "siedemsetn" => b"700",
+        "osiemsetn" => b"800",
+        "dziewięćsetn" => b"900",
+        _ => {
+            let (prefix, positions) = if let Some(prefix) = stem.strip_suffix("tysięczn") {
+                (prefix, 3)
+            } else if let Some(prefix) = stem.strip_suffix("milionow") {
+                (prefix, 6)
+            } else if let Some(prefix) = stem.strip_suffix("miliardow") {
+                (prefix, 9)
+            } else {
+                return None;
+            };
+            let multiplicand: &[u8] = match prefix {
+                "" => b"",
+                "dwu" => b"2",
+                "trzy" => b"3",
+                "cztero" => b"4",
+                "pięcio" => b"5",
+                "sześcio" => b"6",
+                "siedmio" => b"7",
+                "ośmio" => b"8",
+                "dziewięcio" => b"9",
+                _ => return None,
+            };
+            return Some(Rank::Multiplier(positions, multiplicand));
+        }
+    };
+    Some(Rank::Digits(digits))
+}
+
+/// Split an ordinal adjective into the rank of its stem and its ending.
+fn split_ordinal(word: &str) -> Option<(Rank, &str)> {
+    ORDINAL_ENDINGS.iter().find_map(|&ending| {
+        let stem = word.strip_suffix(ending)?;
+        Some((ordinal_rank(stem)?, ending))
+    })
+}
+
+#[derive(Default)]
+pub struct Polish {}
+
+impl Polish {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    fn apply_ordinal(&self, word: &str, b: &mut DigitString) -> Result<(), Error> {
+        let (rank, _) = split_ordinal(word).ok_or(Error::NaN)?;
+        let status = match rank {
+            Rank::Digits(digits) if digits.len() == 1 && b.peek(2) == b"10" => Err(Error::Overlap),
+            Rank::Digits(digits) => b.put(digits),
+            Rank::Multiplier(positions, multiplicand) => {
+                if !b.is_range_free(positions, positions + 2) {
+                    Err(Error::Overlap)
+                } else if multiplicand.is_empty() {
+                    b.shift(positions)
+                } else {
+                    b.put(multiplicand).and_then(|_| b.shift(positions))
+                }
+            }
+        };
+        if status.is_ok() {
+            b.marker = MorphologicalMarker::Ordinal(".");
+            // Ordinal tens may be followed by ordinal units
+            let units_expected = b.len() >= 2 && b.peek(1) == b"0" && b.peek(2) != b"00";
+            if !units_expected {
+                b.freeze();
+            }
+        }
+        status
+    }
+}
+
+impl LangInterpreter for Polish {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        if b.is_ordinal() {
+            // Only the units of an ordinal can follow ordinal tens
+            return match split_ordinal(num_func) {
+                Some((Rank::Digits(digits), _)) if digits.len() == 1 => {
+                    b.put(digits)?;
+                    b.freeze();
+                    Ok(())
+                }
+                _ => Err(Error::Overlap),
+            };
+        }
+        match num_func {
+            "zero" | "zera" | "zeru" | "zerem" => b.put(b"0"),
+            "jeden" | "jedna" | "jedno" | "jednego" | "jednej" | "jednemu" | "jednym" | "jedną"
+                if b.peek(2) != b"10" =>
+            {
+                b.put(b"1")
+            }
+            "dwa" | "dwie" | "dwaj" | "dwóch" | "dwu" | "dwom" | "dwoma" | "dwiema"
+                if b.peek(2) != b"10" =>
+            {
+                b.put(b"2")
+            }
+            "trzy" | "trzej" | "trzech" | "trzem" | "trzema" if b.peek(2) != b"10" => b.put(b"3"),
+            "cztery" | "czterej" | "czterech" | "czterem" | "czterema" if b.peek(2) != b"10" => {
+                b.put(b"4")
+            }
+            "pięć" | "pięciu" | "pięcioma" if b.peek(2) != b"10" => b.put(b"5"),
+            "sześć" | "sześciu" | "sześcioma" if b.peek(2) != b"10" => b.put(b"6"),
+            "siedem" | "siedmiu" | "siedmioma" if b.peek(2) != b"10" => b.put(b"7"),
+            "osiem" | "ośmiu" | "ośmioma" if b.peek(2) != b"10" => b.put(b"8"),
+            "dziewięć" | "dziewięciu" | "dziewięcioma" if b.peek(2) != b"10" => b.put(b"9"),
+            "dziesięć" | "dziesięciu" | "dziesięcioma" => b.put(b"10"),
+            "jedenaście" | "jedenastu" | "jedenastoma" => b.put(b"11"),
+            "dwanaście" | "dwunastu" | "dwunastoma" => b.put(b"12"),
+            "trzynaście" | "trzynastu" | "trzynastoma" => b.put(b"13"),
+            "czternaście" | "czternastu" | "czternastoma" => b.put(b"14"),
+            "piętnaście" | "piętnastu" | "piętnastoma" => b.put(b"15"),
+            "szesnaście" | "szesnastu" | "szesnastoma" => b.put(b"16"),
+            "siedemnaście" | "siedemnastu" | "siedemnastoma" => b.put(b"17"),
+            "osiemnaście" | "osiemnastu" | "osiemnastoma" => b.put(b"18"),
+            "dziewiętnaście" | "dziewiętnastu" | "dziewiętnastoma" => b.put(b"19"),
+            "dwadzieścia" | "dwudziestu" | "dwudziestoma" => b.put(b"20"),
+            "trzydzieści" | "trzydziestu" | "trzydziestoma" => b.put(b"30"),
+            "czterdzieści" | "czterdziestu" | "czterdziestoma" => b.put(b"40"),
+            "pięćdziesiąt" | "pięćdziesięciu" | "pięćdziesięcioma" => b.put(b"50"),
+            "sześćdziesiąt" | "sześćdziesięciu" | "sześćdziesięcioma" => b.put(b"60"),
+            "siedemdziesiąt" | "siedemdziesięciu" | "siedemdziesięcioma" => b.put(b"70"),
+            "osiemdziesiąt" | "osiemdziesięciu" | "osiemdziesięcioma" => b.put(b"80"),
+            "dziewięćdziesiąt" | "dziewięćdziesięciu" | "dziewięćdziesięcioma" => {
+                b.put(b"90")
+            }
+            "sto" | "stu" | "stoma" => b.put(b"100"),
+            "dwieście" | "dwustu" | "dwustoma" => b.put(b"200"),
+            "trzysta" | "trzystu" | "trzystoma" => b.put(b"300"),
+            "czterysta" | "czterystu" | "czterystoma" => b.put(b"400"),
+            "pięćset" | "pięciuset" | "pięciomaset" => b.put(b"500"),
+            "sześćset" | "sześciuset" | "sześciomaset" => b.put(b"600"),
+            "siedemset" | "siedmiuset" | "siedmiomaset" => b.put(b"700"),
+            "osiemset" | "ośmiuset" | "ośmiomaset" => b.put(b"800"),
+            "dziewięćset" | "dziewięciuset" | "dziewięciomaset" => b.put(b"900"),
+            _ if is_inflection_of(num_func, "tysi", &THOUSAND_ENDINGS) => {
+                if b.is_range_free(3, 5) {
+                    b.shift(3)
+                } else {
+                    Err(Error::Overlap)
+                }
+            }
+            _ if is_inflection_of(num_func, "milion", &MILLION_ENDINGS) => {
+                if b.is_range_free(6, 8) {
+                    b.shift(6)
+                } else {
+                    Err(Error::Overlap)
+                }
+            }
+            _ if is_inflection_of(num_func, "miliard", &MILLION_ENDINGS) => b.shift(9),
+            _ => self.apply_ordinal(num_func, b),
+        }
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        let status = self.apply(decimal_func, b);
+        if b.is_ordinal() {
+            // Not a cardinal after all
+            b.reset();
+            Err(Error::NaN)
+        } else {
+            status
+        }
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        if word == "przecinek" { Some(',') } else { None }
+    }
+
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "minus" => Some('-'),
+            "plus" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn apply_denominator(
+        &self,
+        frac_func: &str,
+        numerator: &DigitString,
+        b: &mut DigitString,
+    ) -> Result<(), Error> {
+        match frac_func {
+            "pół" | "połowa" | "połowy" | "połowę" | "połową" => b.put(b"2"),
+            "ćwierć" => b.put(b"4"),
+            _ => {
+                ordinal_denominator(self, frac_func, numerator, false, b)?;
+                // "jedna piąta", "dwie piąte", "pięć piątych"
+                let (_, ending) = split_ordinal(frac_func).ok_or(Error::NaN)?;
+                let units = numerator.peek(1);
+                let teen = numerator.len() > 1 && numerator.peek(2)[0] == b'1';
+                let agrees = match units {
+                    b"1" if numerator.len() == 1 => matches!(ending, "a" | "ia" | "ej" | "iej"),
+                    b"2" | b"3" | b"4" if !teen => matches!(ending, "e" | "ie"),
+                    _ => matches!(ending, "ych" | "ich"),
+                };
+                if agrees { Ok(()) } else { Err(Error::NaN) }
+            }
+        }
+    }
+
+    fn is_fraction_link(&self, word: &str) -> bool {
+        word == "i"
+    }
+
+    fn format_and_value(&self, b: &DigitString) -> (String, f64) {
+        let repr = b.to_string();
+        let val: f64 = repr.parse().unwrap();
+        if let MorphologicalMarker::Ordinal(marker) = b.marker {
+            (format!("{repr}{marker}"), val)
+        } else {
+            (repr, val)
+        }
+    }
+
+    fn format_decimal_and_value(
+        &self,
+        int: &DigitString,
+        dec: &DigitString,
+        sep: char,
+    ) -> (String, f64) {
+        let sint = int.to_string();
+        let sdec = dec.to_string();
+        let val = format!("{sint}.{sdec}").parse().unwrap();
+        (format!("{sint}{sep}{sdec}"), val)
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        if split_ordinal(word).is_some() {
+            MorphologicalMarker::Ordinal(".")
+        } else {
+            MorphologicalMarker::None
+        }
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Polish;
+    use crate::ConversionOptions;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Polish {};
+            let res = text2digits($text, &f);
+            dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Polish {};
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_fractions {
+        ($text:expr, $res:expr) => {
+            assert_replace_fractions!($text, $res, 10.0)
+        };
+        ($text:expr, $res:expr, $threshold:expr) => {
+            let f = Polish {};
+            let options = ConversionOptions::from($threshold).convert_fractions(true);
+            assert_eq!(replace_numbers_in_text($text, &f, options), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Polish {};
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Polish {};
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!(
+            "pięćdziesiąt trzy miliardy dwieście czterdzieści trzy tysiące siedemset dwadzieścia cztery",
+            "53000243724"
+        );
+        assert_text2digits!(
+            "pięćdziesiąt jeden milionów pięćset siedemdziesiąt osiem tysięcy trzysta dwa",
+            "51578302"
+        );
+        assert_text2digits!("osiemdziesiąt pięć", "85");
+        assert_text2digits!("sto piętnaście", "115");
+        assert_text2digits!("tysiąc dziewięćset dwadzieścia", "1920");
+        assert_text2digits!("jeden miliard dwadzieścia pięć milionów", "1025000000");
+    }
+
+    #[test]
+    fn test_inflections() {
+        assert_text2digits!("dwa tysiące", "2000");
+        assert_text2digits!("pięć tysięcy", "5000");
+        assert_text2digits!("tysiąca", "1000");
+        assert_text2digits!("dwóch milionów", "2000000");
+        assert_text2digits!("trzy miliony", "3000000");
+        assert_text2digits!("pięciuset dwudziestu", "520");
+        assert_text2digits!("dwiema", "2");
+        assert_text2digits!("jedną", "1");
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_text2digits!("pierwszy", "1.");
+        assert_text2digits!("druga", "2.");
+        assert_text2digits!("trzeciego", "3.");
+        assert_text2digits!("dwudziesty pierwszy", "21.");
+        assert_text2digits!("dwudziestego", "20.");
+        assert_text2digits!("sto dwudziesta piąta", "125.");
+        assert_text2digits!("dwa tysiące dwudziestego trzeciego", "2023.");
+        assert_text2digits!("setny", "100.");
+        assert_text2digits!("tysięczny", "1000.");
+        assert_text2digits!("dwutysięczny", "2000.");
+        assert_replace_numbers!("W dwa tysiące dwudziestym trzecim roku", "W 2023. roku");
+    }
+
+    #[test]
+    fn test_decimals() {
+        assert_replace_numbers!("trzy przecinek czternaście", "3,14");
+        assert_replace_numbers!("zero przecinek zero pięć", "0,05");
+        assert_replace_numbers!("dwanaście przecinek pięć procent", "12,5 procent");
+        assert_replace_numbers!("Mówię przecinek trzy", "Mówię przecinek trzy");
+    }
+
+    #[test]
+    fn test_fractions() {
+        assert_replace_fractions!("trzy czwarte tortu", "3/4 tortu");
+        assert_replace_fractions!("jedna piąta", "1/5");
+        assert_replace_fractions!("pięć szóstych", "5/6");
+        assert_replace_fractions!("dwa i pół litra", "2 1/2 litra");
+        assert_replace_fractions!("pięć piąty", "5 5.", 0.0);
+    }
+
+    #[test]
+    fn test_zeroes() {
+        assert_text2digits!("zero", "0");
+        assert_text2digits!("zero osiem", "08");
+        assert_text2digits!("zero zero sto dwadzieścia pięć", "00125");
+        assert_invalid!("pięć zero");
+        assert_invalid!("pięćdziesiąt zero trzy");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("tysiąc tysiąc dwieście");
+        assert_invalid!("sześćdziesiąt piętnaście");
+        assert_invalid!("sto sto");
+        assert_invalid!("dziesięć pięć");
+        assert_invalid!("dwudziesty dwa");
+        assert_invalid!("piąty pierwszy");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!(
+            "dwadzieścia pięć krów, dwanaście kur i sto dwadzieścia pięć kilogramów ziemniaków.",
+            "25 krów, 12 kur i 125 kilogramów ziemniaków."
+        );
+        assert_replace_numbers!(
+            "Tysiąc dwieście sześćdziesiąt sześć złotych.",
+            "1266 złotych."
+        );
+        assert_replace_numbers!(
+            "jeden dwa trzy cztery dwadzieścia piętnaście",
+            "1 2 3 4 20 15"
+        );
+    }
+
+    #[test]
+    fn test_isolates() {
+        assert_replace_numbers!("To jest jeden z nich", "To jest jeden z nich");
+        assert_replace_all_numbers!("To jest jeden z nich", "To jest 1 z nich");
+        assert_replace_numbers!("Policzmy: jeden, dwa, trzy", "Policzmy: 1, 2, 3");
+    }
+}
@@ -0,0 +1,5 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "a", "dobrze", "eee", "hmm", "i", "minus", "no", "ok", "plus", "potem", "tak", "to", "więc", "yyy"
+};
@@ -10,18 +10,36 @@ use bitflags::bitflags;
 use crate::digit_string::DigitString;
 use crate::error::Error;
 
+mod verbalize;
 mod vocabulary;
 
-use super::{LangInterpreter, MorphologicalMarker};
+use super::{LangInterpreter, MorphologicalMarker, ordinal_denominator};
 use vocabulary::INSIGNIFICANT;
 
+/// Regional varieties of Portuguese.
+///
+/// Both varieties are understood when parsing; the region only selects the words used to spell out numbers
+/// (e.g. "*dezanove*" in Portugal and "*dezenove*" in Brazil).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PortugueseRegion {
+    #[default]
+    Portugal,
+    Brazil,
+}
+
 #[derive(Default)]
-pub struct Portuguese {}
+pub struct Portuguese {
+    region: PortugueseRegion,
+}
 
 impl Portuguese {
     pub fn new() -> Self {
         Default::default()
     }
+
+    pub fn with_region(region: PortugueseRegion) -> Self {
+        Self { region }
+    }
 }
 
 bitflags! {
@@ -174,11 +192,40 @@ impl LangInterpreter for Portuguese {
         if word == "vírgula" { Some(',') } else { None }
     }
 
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "menos" => Some('-'),
+            "mais" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn apply_denominator(
+        &self,
+        frac_func: &str,
+        numerator: &DigitString,
+        b: &mut DigitString,
+    ) -> Result<(), Error> {
+        match frac_func {
+            "meio" | "meia" | "meios" | "meias" => b.put(b"2"),
+            "terço" | "terços" => b.put(b"3"),
+            _ => ordinal_denominator(self, frac_func, numerator, true, b),
+        }
+    }
+
+    fn is_fraction_link(&self, word: &str) -> bool {
+        matches!(word, "e")
+    }
+
     fn format_and_value(&self, b: &DigitString) -> (String, f64) {
         let repr = b.to_string();
         let val = repr.parse().unwrap();
         if let MorphologicalMarker::Ordinal(marker) = b.marker {
-            (format!("{}{}", b.to_string(), marker), val)
+            (format!("{repr}{marker}"), val)
         } else {
             (repr, val)
         }
@@ -204,11 +251,12 @@ impl LangInterpreter for Portuguese {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ConversionOptions;
     use crate::word_to_digit::{replace_numbers_in_text, text2digits};
 
     macro_rules! assert_text2digits {
         ($text:expr, $res:expr) => {
-            let f = Portuguese {};
+            let f = Portuguese::new();
             let res = text2digits($text, &f);
             dbg!(&res);
             assert!(res.is_ok());
@@ -218,14 +266,25 @@ mod tests {
 
     macro_rules! assert_replace_numbers {
         ($text:expr, $res:expr) => {
-            let f = Portuguese {};
+            let f = Portuguese::new();
             assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
         };
     }
 
+    macro_rules! assert_replace_fractions {
+        ($text:expr, $res:expr) => {
+            assert_replace_fractions!($text, $res, 10.0)
+        };
+        ($text:expr, $res:expr, $threshold:expr) => {
+            let f = Portuguese::new();
+            let options = ConversionOptions::from($threshold).convert_fractions(true);
+            assert_eq!(replace_numbers_in_text($text, &f, options), $res)
+        };
+    }
+
     macro_rules! assert_invalid {
         ($text:expr) => {
-            let f = Portuguese {};
+            let f = Portuguese::new();
             let res = text2digits($text, &f);
             assert!(res.is_err());
         };
@@ -309,6 +368,13 @@ mod tests {
         assert_text2digits!("décimos sextos", "16ᵒˢ");
     }
 
+    #[test]
+    fn test_fractions() {
+        assert_replace_fractions!("três quartos e um terço", "3/4 e 1/3");
+        assert_replace_fractions!("dois e meio", "2 1/2");
+        assert_replace_fractions!("dois quintos", "2/5");
+    }
+
     #[test]
     fn test_replace_numbers_integers() {
         assert_replace_numbers!(
@@ -0,0 +1,315 @@
+//! Spelling out numbers in Portuguese.
+
+use super::{Portuguese, PortugueseRegion};
+use crate::decimal::Decimal;
+use crate::lang::{Gender, Verbalize, decimal_parts};
+
+const UNITS: [&str; 20] = [
+    "zero",
+    "um",
+    "dois",
+    "três",
+    "quatro",
+    "cinco",
+    "seis",
+    "sete",
+    "oito",
+    "nove",
+    "dez",
+    "onze",
+    "doze",
+    "treze",
+    "catorze",
+    "quinze",
+    "dezasseis",
+    "dezassete",
+    "dezoito",
+    "dezanove",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "vinte",
+    "trinta",
+    "quarenta",
+    "cinquenta",
+    "sessenta",
+    "setenta",
+    "oitenta",
+    "noventa",
+];
+
+const HUNDREDS: [&str; 10] = [
+    "",
+    "cento",
+    "duzentos",
+    "trezentos",
+    "quatrocentos",
+    "quinhentos",
+    "seiscentos",
+    "setecentos",
+    "oitocentos",
+    "novecentos",
+];
+
+/// Ordinal stems, to be completed with the gender and number ending.
+const ORDINAL_UNITS: [&str; 10] = [
+    "", "primeir", "segund", "terceir", "quart", "quint", "sext", "sétim", "oitav", "non",
+];
+
+const ORDINAL_TENS: [&str; 10] = [
+    "",
+    "décim",
+    "vigésim",
+    "trigésim",
+    "quadragésim",
+    "quinquagésim",
+    "sexagésim",
+    "septuagésim",
+    "octogésim",
+    "nonagésim",
+];
+
+const ORDINAL_HUNDREDS: [&str; 10] = [
+    "",
+    "centésim",
+    "ducentésim",
+    "trecentésim",
+    "quadringentésim",
+    "quingentésim",
+    "sexcentésim",
+    "septingentésim",
+    "octingentésim",
+    "noningentésim",
+];
+
+impl Portuguese {
+    fn below_hundred(&self, n: usize) -> String {
+        match (n / 10, n % 10) {
+            (1, 6) if self.region == PortugueseRegion::Brazil => "dezesseis".to_owned(),
+            (1, 7) if self.region == PortugueseRegion::Brazil => "dezessete".to_owned(),
+            (1, 9) if self.region == PortugueseRegion::Brazil => "dezenove".to_owned(),
+            _ if n < 20 => UNITS[n].to_owned(),
+            (tens, 0) => TENS[tens].to_owned(),
+            (tens, units) => format!("{} e {}", TENS[tens], UNITS[units]),
+        }
+    }
+
+    fn below_thousand(&self, n: usize) -> String {
+        match (n / 100, n % 100) {
+            (0, rest) => self.below_hundred(rest),
+            (1, 0) => "cem".to_owned(),
+            (hundreds, 0) => HUNDREDS[hundreds].to_owned(),
+            (hundreds, rest) => format!("{} e {}", HUNDREDS[hundreds], self.below_hundred(rest)),
+        }
+    }
+
+    /// Words for each non null multiplier group of `n`, with the value of the group.
+    fn groups(&self, n: u64) -> Vec<(String, usize)> {
+        let mut groups = Vec::with_capacity(4);
+        let mut scales = vec![(1_000_000, "um milhão", "milhões"), (1000, "mil", "mil")];
+        let mut int = n;
+        if self.region == PortugueseRegion::Brazil {
+            scales.insert(0, (1_000_000_000, "um bilhão", "bilhões"));
+        } else if n >= 1_000_000_000 {
+            // The long scale counts thousands of millions: "mil milhões".
+            let millions = (n / 1_000_000) as usize;
+            groups.push((
+                format!("{} milhões", self.join(self.groups(millions as u64))),
+                millions,
+            ));
+            int %= 1_000_000;
+            scales.remove(0);
+        }
+        for (scale, singular, plural) in scales {
+            match (int / scale % 1000) as usize {
+                0 => (),
+                1 => groups.push((singular.to_owned(), 1)),
+                count => groups.push((format!("{} {plural}", self.below_thousand(count)), count)),
+            }
+        }
+        let rest = (int % 1000) as usize;
+        if rest > 0 {
+            groups.push((self.below_thousand(rest), rest));
+        }
+        groups
+    }
+
+    /// Join the groups, with the conjunction "*e*" before the last one if it is round or small.
+    fn join(&self, mut groups: Vec<(String, usize)>) -> String {
+        let Some((last, value)) = groups.pop() else {
+            return UNITS[0].to_owned();
+        };
+        let mut words: Vec<String> = groups.into_iter().map(|(words, _)| words).collect();
+        if !words.is_empty() && (value < 100 || value % 100 == 0) {
+            words.push("e".to_owned());
+        }
+        words.push(last);
+        words.join(" ")
+    }
+
+    fn cardinal(&self, n: u64) -> Option<String> {
+        if n >= 1_000_000_000_000 {
+            return None;
+        }
+        Some(self.join(self.groups(n)))
+    }
+}
+
+/// Ordinal stems of `n` < 1000.
+fn ordinal_below_thousand(n: usize, stems: &mut Vec<String>) {
+    let (hundreds, tens, units) = (n / 100, n / 10 % 10, n % 10);
+    for (digit, table) in [
+        (hundreds, &ORDINAL_HUNDREDS),
+        (tens, &ORDINAL_TENS),
+        (units, &ORDINAL_UNITS),
+    ] {
+        if digit > 0 {
+            stems.push(table[digit].to_owned());
+        }
+    }
+}
+
+impl Verbalize for Portuguese {
+    fn verbalize(&self, n: u64) -> Option<String> {
+        self.cardinal(n)
+    }
+
+    fn verbalize_ordinal(&self, n: u64, gender: Gender, plural: bool) -> Option<String> {
+        if n == 0 || n >= 1_000_000_000_000 {
+            return None;
+        }
+        let mut stems: Vec<String> = Vec::with_capacity(4);
+        for (scale, stem) in [
+            (1_000_000_000, "bilionésim"),
+            (1_000_000, "milionésim"),
+            (1000, "milésim"),
+        ] {
+            match (n / scale % 1000) as usize {
+                0 => (),
+                1 => stems.push(stem.to_owned()),
+                count => stems.push(format!("{} {stem}", self.below_thousand(count))),
+            }
+        }
+        ordinal_below_thousand((n % 1000) as usize, &mut stems);
+        let ending = match (gender, plural) {
+            (Gender::Feminine, false) => "a",
+            (Gender::Feminine, true) => "as",
+            (_, false) => "o",
+            (_, true) => "os",
+        };
+        Some(
+            stems
+                .into_iter()
+                .map(|stem| format!("{stem}{ending}"))
+                .collect::<Vec<_>>()
+                .join(" "),
+        )
+    }
+
+    fn verbalize_decimal(&self, value: &Decimal) -> Option<String> {
+        let (int, dec) = decimal_parts(value)?;
+        let mut words = self.cardinal(int)?;
+        if !dec.is_empty() {
+            words.push_str(" vírgula");
+            let significant = dec.trim_start_matches('0');
+            for _ in 0..(dec.len() - significant.len()) {
+                words.push_str(" zero");
+            }
+            if !significant.is_empty() {
+                words.push(' ');
+                words.push_str(&self.cardinal(significant.parse().ok()?)?);
+            }
+        }
+        Some(words)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    #[test]
+    fn test_cardinals() {
+        let pt = Portuguese::new();
+        assert_eq!(pt.verbalize(100).unwrap(), "cem");
+        assert_eq!(pt.verbalize(101).unwrap(), "cento e um");
+        assert_eq!(pt.verbalize(1001).unwrap(), "mil e um");
+        assert_eq!(
+            pt.verbalize(1234).unwrap(),
+            "mil duzentos e trinta e quatro"
+        );
+        assert_eq!(pt.verbalize(2099).unwrap(), "dois mil e noventa e nove");
+        assert_eq!(
+            pt.verbalize(2_500_000).unwrap(),
+            "dois milhões e quinhentos mil"
+        );
+    }
+
+    #[test]
+    fn test_regions() {
+        let pt = Portuguese::with_region(PortugueseRegion::Portugal);
+        let br = Portuguese::with_region(PortugueseRegion::Brazil);
+        assert_eq!(pt.verbalize(19).unwrap(), "dezanove");
+        assert_eq!(br.verbalize(19).unwrap(), "dezenove");
+        assert_eq!(br.verbalize(16).unwrap(), "dezesseis");
+        assert_eq!(pt.verbalize(1_000_000_000).unwrap(), "mil milhões");
+        assert_eq!(br.verbalize(1_000_000_000).unwrap(), "um bilhão");
+        assert_eq!(
+            pt.verbalize(2_300_000_017).unwrap(),
+            "dois mil e trezentos milhões e dezassete"
+        );
+        assert_eq!(
+            br.verbalize(2_300_000_017).unwrap(),
+            "dois bilhões trezentos milhões e dezessete"
+        );
+    }
+
+    #[test]
+    fn test_ordinals() {
+        let pt = Portuguese::new();
+        assert_eq!(
+            pt.verbalize_ordinal(11, Gender::Masculine, false).unwrap(),
+            "décimo primeiro"
+        );
+        assert_eq!(
+            pt.verbalize_ordinal(21, Gender::Feminine, true).unwrap(),
+            "vigésimas primeiras"
+        );
+        assert_eq!(
+            pt.verbalize_ordinal(1000, Gender::Masculine, false)
+                .unwrap(),
+            "milésimo"
+        );
+    }
+
+    #[test]
+    fn test_decimals() {
+        let pt = Portuguese::new();
+        let value: Decimal = "0.05".parse().unwrap();
+        let words = pt.verbalize_decimal(&value).unwrap();
+        assert_eq!(words, "zero vírgula zero cinco");
+        assert_eq!(replace_numbers_in_text(&words, &pt, 0.0), "0,05");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for region in [PortugueseRegion::Portugal, PortugueseRegion::Brazil] {
+            let pt = Portuguese::with_region(region);
+            for n in (0..2000).chain((1..10_000_000_000).step_by(7_919_111)) {
+                let words = pt.verbalize(n).unwrap();
+                assert_eq!(text2digits(&words, &pt).unwrap(), n.to_string(), "{words}");
+            }
+            for n in 1..2000 {
+                let words = pt.verbalize_ordinal(n, Gender::Feminine, false).unwrap();
+                assert_eq!(
+                    text2digits(&words, &pt).unwrap(),
+                    format!("{n}ª"),
+                    "{words}"
+                );
+            }
+        }
+    }
+}
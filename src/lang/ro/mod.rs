@@ -0,0 +1,460 @@
+//! Romanian number interpreter
+//!
+//! Ordinals are written the Romanian way, keeping the article outside of the number: "*al doilea*" becomes "*al 2-lea*"
+//! and "*a doua*" becomes "*a 2-a*".
+use std::borrow::Cow;
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+
+mod vocabulary;
+
+use super::{LangInterpreter, MorphologicalMarker};
+use vocabulary::INSIGNIFICANT;
+
+/// Set on the number after a "*de*", which must be followed by a multiplier: "*douăzeci de mii*".
+const DE: u64 = 1;
+
+/// Romanian is written with comma below letters, but the legacy cedilla letters are still common.
+fn normalize(word: &str) -> Cow<'_, str> {
+    if word.contains(['ş', 'ţ']) {
+        Cow::Owned(word.replace('ş', "ș").replace('ţ', "ț"))
+    } else {
+        Cow::Borrowed(word)
+    }
+}
+
+/// Does a multiplier or a noun after the number need a "*de*"? That's the case from twenty on,
+/// unless the last two digits make a number under twenty.
+fn needs_de(b: &DigitString) -> bool {
+    match b.peek(2) {
+        [tens, _] if *tens >= b'2' => true,
+        b"00" => b.len() >= 3,
+        _ => false,
+    }
+}
+
+/// The ordinals that don't derive from their cardinal, and can only start a number.
+fn is_irregular_ordinal(word: &str) -> bool {
+    matches!(word, "primul" | "prima" | "întâiul" | "întâia")
+}
+
+/// Split an ordinal into the cardinal it's built on and its marker.
+fn split_ordinal(word: &str) -> Option<(Cow<'_, str>, &'static str)> {
+    let feminine = match word {
+        "primul" | "întâiul" => return Some(("unu".into(), "-ul")),
+        "prima" | "întâia" => return Some(("una".into(), "-a")),
+        "doua" => "două",
+        "treia" => "trei",
+        "patra" => "patru",
+        "cincea" => "cinci",
+        "șasea" => "șase",
+        "șaptea" => "șapte",
+        "opta" => "opt",
+        "noua" => "nouă",
+        "zecea" => "zece",
+        "suta" => "sută",
+        "mia" => "mie",
+        "milioana" => "milion",
+        "miliarda" => "miliard",
+        _ => "",
+    };
+    if !feminine.is_empty() {
+        return Some((feminine.into(), "-a"));
+    }
+    if let Some(stem) = word.strip_suffix("lea") {
+        // "optulea", "milionulea"…
+        let stem = match stem.strip_suffix('u') {
+            Some(cardinal) if stem != "unu" && stem != "patru" => cardinal,
+            _ => stem,
+        };
+        return Some((stem.into(), "-lea"));
+    }
+    if word.ends_with("sprezecea") {
+        return Some((word.trim_end_matches('a').into(), "-a"));
+    }
+    // "douăzecea"
+    let tens = word.strip_suffix("zecea")?;
+    Some((format!("{tens}zeci").into(), "-a"))
+}
+
+#[derive(Default)]
+pub struct Romanian {}
+
+impl Romanian {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    fn apply_cardinal(&self, word: &str, b: &mut DigitString) -> Result<(), Error> {
+        let is_multiplier = matches!(
+            word,
+            "mie" | "mii" | "milion" | "milioane" | "miliard" | "miliarde"
+        );
+        if b.flags & DE != 0 {
+            if !is_multiplier {
+                return Err(Error::NaN);
+            }
+            b.flags &= !DE;
+        } else if is_multiplier && needs_de(b) {
+            return Err(Error::Overlap);
+        }
+        match word {
+            "zero" => b.put(b"0"),
+            "un" | "o" if b.is_empty() => b.put(b"1"),
+            "unu" | "una" if b.peek(2) != b"10" => b.put(b"1"),
+            "doi" | "două" if b.peek(2) != b"10" => b.put(b"2"),
+            "trei" if b.peek(2) != b"10" => b.put(b"3"),
+            "patru" if b.peek(2) != b"10" => b.put(b"4"),
+            "cinci" if b.peek(2) != b"10" => b.put(b"5"),
+            "șase" if b.peek(2) != b"10" => b.put(b"6"),
+            "șapte" if b.peek(2) != b"10" => b.put(b"7"),
+            "opt" if b.peek(2) != b"10" => b.put(b"8"),
+            "nouă" if b.peek(2) != b"10" => b.put(b"9"),
+            "zece" => b.put(b"10"),
+            "unsprezece" | "unșpe" => b.put(b"11"),
+            "doisprezece" | "douăsprezece" | "doișpe" => b.put(b"12"),
+            "treisprezece" | "treișpe" => b.put(b"13"),
+            "paisprezece" | "patrusprezece" | "paișpe" => b.put(b"14"),
+            "cincisprezece" | "cinșpe" => b.put(b"15"),
+            "șaisprezece" | "șaișpe" => b.put(b"16"),
+            "șaptesprezece" | "șapteșpe" => b.put(b"17"),
+            "optsprezece" | "optișpe" => b.put(b"18"),
+            "nouăsprezece" | "nouășpe" => b.put(b"19"),
+            "douăzeci" => b.put(b"20"),
+            "treizeci" => b.put(b"30"),
+            "patruzeci" => b.put(b"40"),
+            "cincizeci" => b.put(b"50"),
+            "șaizeci" => b.put(b"60"),
+            "șaptezeci" => b.put(b"70"),
+            "optzeci" => b.put(b"80"),
+            "nouăzeci" => b.put(b"90"),
+            "sută" | "sute" => {
+                let peek = b.peek(2);
+                if peek.len() == 1 || peek != b"00" {
+                    b.shift(2)
+                } else {
+                    Err(Error::Overlap)
+                }
+            }
+            "mie" | "mii" if b.is_range_free(3, 5) => b.shift(3),
+            "milion" | "milioane" if b.is_range_free(6, 8) => b.shift(6),
+            "miliard" | "miliarde" => b.shift(9),
+            // "douăzeci și unu"
+            "și" if b.len() >= 2 && b.peek(1) == b"0" && b.peek(2) != b"00" => {
+                Err(Error::Incomplete)
+            }
+            "de" if needs_de(b) => {
+                b.flags |= DE;
+                Err(Error::Incomplete)
+            }
+
+            _ => Err(Error::NaN),
+        }
+    }
+}
+
+impl LangInterpreter for Romanian {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        let word = normalize(num_func);
+        let word = word.as_ref();
+        if is_irregular_ordinal(word) && !b.is_empty() {
+            return Err(Error::Overlap);
+        }
+        match split_ordinal(word) {
+            Some((cardinal, marker)) => {
+                self.apply_cardinal(&cardinal, b)?;
+                b.marker = MorphologicalMarker::Ordinal(marker);
+                b.freeze();
+                Ok(())
+            }
+            None => self.apply_cardinal(word, b),
+        }
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        self.apply(decimal_func, b)
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        match word {
+            "virgulă" | "virgula" => Some(','),
+            _ => None,
+        }
+    }
+
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "minus" => Some('-'),
+            "plus" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn apply_denominator(
+        &self,
+        frac_func: &str,
+        _numerator: &DigitString,
+        b: &mut DigitString,
+    ) -> Result<(), Error> {
+        let word = normalize(frac_func);
+        let word = word.as_ref();
+        if matches!(word, "jumătate" | "jumătăți") {
+            return b.put(b"2");
+        }
+        if matches!(word, "sfert" | "sferturi") {
+            return b.put(b"4");
+        }
+        // "treime", "cincimi", "zecimi"…
+        let stem = word
+            .strip_suffix("ime")
+            .or_else(|| word.strip_suffix("imi"))
+            .ok_or(Error::NaN)?;
+        match stem {
+            "tre" => b.put(b"3"),
+            "pătr" => b.put(b"4"),
+            "cinc" => b.put(b"5"),
+            "șes" => b.put(b"6"),
+            "șept" => b.put(b"7"),
+            "opt" => b.put(b"8"),
+            "no" => b.put(b"9"),
+            "zec" => b.put(b"10"),
+            "sut" => b.put(b"100"),
+            "mi" => b.put(b"1000"),
+            _ => Err(Error::NaN),
+        }
+    }
+
+    fn is_fraction_link(&self, word: &str) -> bool {
+        word == "și"
+    }
+
+    fn format_and_value(&self, b: &DigitString) -> (String, f64) {
+        let repr = b.to_string();
+        let val: f64 = repr.parse().unwrap();
+        if let MorphologicalMarker::Ordinal(marker) = b.marker {
+            (format!("{repr}{marker}"), val)
+        } else {
+            (repr, val)
+        }
+    }
+
+    fn format_decimal_and_value(
+        &self,
+        int: &DigitString,
+        dec: &DigitString,
+        sep: char,
+    ) -> (String, f64) {
+        let sint = int.to_string();
+        let sdec = dec.to_string();
+        let val = format!("{sint}.{sdec}").parse().unwrap();
+        (format!("{sint}{sep}{sdec}"), val)
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        match split_ordinal(&normalize(word)) {
+            Some((_, marker)) => MorphologicalMarker::Ordinal(marker),
+            None => MorphologicalMarker::None,
+        }
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConversionOptions;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Romanian {};
+            let res = text2digits($text, &f);
+            dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Romanian {};
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_fractions {
+        ($text:expr, $res:expr) => {
+            assert_replace_fractions!($text, $res, 10.0)
+        };
+        ($text:expr, $res:expr, $threshold:expr) => {
+            let f = Romanian {};
+            let options = ConversionOptions::from($threshold).convert_fractions(true);
+            assert_eq!(replace_numbers_in_text($text, &f, options), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Romanian {};
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Romanian {};
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply_steps() {
+        let f = Romanian {};
+        let mut b = DigitString::new();
+        assert!(f.apply("treizeci", &mut b).is_ok());
+        assert_eq!(f.apply("și", &mut b), Err(Error::Incomplete));
+        assert!(f.apply("patru", &mut b).is_ok());
+        assert!(f.apply("douăzeci", &mut b).is_err());
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("zero", "0");
+        assert_text2digits!("unu", "1");
+        assert_text2digits!("nouă", "9");
+        assert_text2digits!("zece", "10");
+        assert_text2digits!("unsprezece", "11");
+        assert_text2digits!("nouăsprezece", "19");
+        assert_text2digits!("douăzeci", "20");
+        assert_text2digits!("douăzeci și unu", "21");
+        assert_text2digits!("nouăzeci și nouă", "99");
+        assert_text2digits!("o sută", "100");
+        assert_text2digits!("o sută cincisprezece", "115");
+        assert_text2digits!("două sute unu", "201");
+        assert_text2digits!("o mie", "1000");
+        assert_text2digits!("două mii", "2000");
+        assert_text2digits!("douăsprezece mii", "12000");
+        assert_text2digits!("douăzeci de mii", "20000");
+        assert_text2digits!("douăzeci și cinci de mii", "25000");
+        assert_text2digits!("o sută de mii", "100000");
+        assert_text2digits!("trei sute cinci mii", "305000");
+        assert_text2digits!("o mie nouă sute douăzeci", "1920");
+        assert_text2digits!(
+            "nouă sute nouăzeci și nouă de mii nouă sute nouăzeci și nouă",
+            "999999"
+        );
+        assert_text2digits!(
+            "cincizeci și unu de milioane cinci sute șaptezeci și opt de mii trei sute doi",
+            "51578302"
+        );
+        assert_text2digits!("un milion", "1000000");
+        assert_text2digits!("două miliarde", "2000000000");
+    }
+
+    #[test]
+    fn test_variants() {
+        assert_text2digits!("două", "2");
+        assert_text2digits!("douăzeci și una", "21");
+        assert_text2digits!("douăsprezece", "12");
+        assert_text2digits!("paișpe", "14");
+        assert_text2digits!("şaizeci şi şase", "66");
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_text2digits!("primul", "1-ul");
+        assert_text2digits!("prima", "1-a");
+        assert_text2digits!("doilea", "2-lea");
+        assert_text2digits!("doua", "2-a");
+        assert_text2digits!("optulea", "8-lea");
+        assert_text2digits!("opta", "8-a");
+        assert_text2digits!("zecelea", "10-lea");
+        assert_text2digits!("unsprezecea", "11-a");
+        assert_text2digits!("douăzecilea", "20-lea");
+        assert_text2digits!("douăzecea", "20-a");
+        assert_text2digits!("douăzeci și unulea", "21-lea");
+        assert_text2digits!("o sutălea", "100-lea");
+        assert_text2digits!("două miilea", "2000-lea");
+        assert_text2digits!("milionulea", "1000000-lea");
+        assert_replace_all_numbers!(
+            "Al doilea război mondial, a treia oară, al douăzeci și cincilea an.",
+            "Al 2-lea război mondial, a 3-a oară, al 25-lea an."
+        );
+    }
+
+    #[test]
+    fn test_de() {
+        assert_replace_numbers!("douăzeci de oameni", "20 de oameni");
+        assert_replace_numbers!("două mii de lei", "2000 de lei");
+        assert_replace_numbers!("douăzeci de trei", "20 de 3");
+        assert_replace_all_numbers!("doi de mere", "2 de mere");
+        assert_invalid!("doisprezece de mii");
+        assert_invalid!("douăzeci mii");
+    }
+
+    #[test]
+    fn test_fractions() {
+        assert_replace_fractions!("trei pătrimi", "3/4");
+        assert_replace_fractions!("o cincime", "1/5");
+        assert_replace_fractions!("doi și jumătate", "2 1/2");
+        assert_replace_fractions!("trei sferturi de oră", "3/4 de oră");
+    }
+
+    #[test]
+    fn test_zeroes() {
+        assert_text2digits!("zero unu", "01");
+        assert_text2digits!("zero opt", "08");
+        assert_text2digits!("zero zero douăzeci și cinci", "0025");
+        assert_invalid!("cinci zero");
+        assert_invalid!("cincizeci zero trei");
+        assert_invalid!("zece zero");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("o mie o mie");
+        assert_invalid!("șaizeci cincisprezece");
+        assert_invalid!("zece patru");
+        assert_invalid!("douăzeci primul");
+        assert_invalid!("doi și");
+    }
+
+    #[test]
+    fn test_replace_numbers_integers() {
+        assert_replace_numbers!(
+            "Douăzeci și cinci de vaci, douăsprezece găini și o sută douăzeci și cinci de kg de cartofi.",
+            "25 de vaci, 12 găini și 125 de kg de cartofi."
+        );
+        assert_replace_numbers!("O mie două sute șaizeci și șase de lei.", "1266 de lei.");
+        assert_replace_numbers!(
+            "unu doi trei patru douăzeci cincisprezece.",
+            "1 2 3 4 20 15."
+        );
+    }
+
+    #[test]
+    fn test_replace_numbers_decimals() {
+        assert_replace_numbers!(
+            "doisprezece virgulă nouăzeci și nouă, o sută douăzeci virgulă zero cinci.",
+            "12,99, 120,05."
+        );
+        assert_replace_numbers!("zero virgulă cincisprezece", "0,15");
+    }
+
+    #[test]
+    fn test_isolates() {
+        assert_replace_numbers!(
+            "Un moment vă rog! Treizeci și una de pisici. Unu doi trei patru!",
+            "Un moment vă rog! 31 de pisici. 1 2 3 4!"
+        );
+    }
+}
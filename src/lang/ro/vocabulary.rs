@@ -0,0 +1,5 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "și", "sau", "deci", "păi", "adică", "apoi", "bine", "bun", "da", "nu", "ok", "cu", "plus", "minus", "eh", "ah", "hm"
+};
@@ -0,0 +1,598 @@
+//! Russian number interpreter
+//!
+//! Numerals are understood in all their cases and genders ("*две тысячи*", "*пятью*", "*тремястами*"…),
+//! and the letter "ё" may be spelled "е".
+
+use std::borrow::Cow;
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+
+mod vocabulary;
+
+use super::{LangInterpreter, MorphologicalMarker, ordinal_denominator};
+use vocabulary::INSIGNIFICANT;
+
+fn normalize(word: &str) -> Cow<'_, str> {
+    if word.contains('ё') {
+        Cow::Owned(word.replace('ё', "е"))
+    } else {
+        Cow::Borrowed(word)
+    }
+}
+
+/// Is `word` a form of the noun `stem` + one of the `endings`?
+fn is_inflection_of(word: &str, stem: &str, endings: &[&str]) -> bool {
+    word.strip_prefix(stem)
+        .is_some_and(|ending| endings.contains(&ending))
+}
+
+const THOUSAND_ENDINGS: [&str; 10] = ["а", "и", "", "у", "ей", "ею", "е", "ам", "ами", "ах"];
+const MILLION_ENDINGS: [&str; 10] = ["", "а", "ов", "у", "ом", "е", "ы", "ам", "ами", "ах"];
+
+/// Ordinal adjective endings, with the suffix of the abbreviated digit form ("*первого*" is "*1-го*").
+///
+/// The soft endings starting with "ь" are those of "*третий*".
+const ORDINAL_ENDINGS: [(&str, &str); 35] = [
+    ("ьими", "-ми"),
+    ("ьего", "-го"),
+    ("ьему", "-му"),
+    ("ьей", "-й"),
+    ("ьим", "-м"),
+    ("ьем", "-м"),
+    ("ьих", "-х"),
+    ("ья", "-я"),
+    ("ье", "-е"),
+    ("ьи", "-и"),
+    ("ью", "-ю"),
+    ("ыми", "-ми"),
+    ("ими", "-ми"),
+    ("ого", "-го"),
+    ("его", "-го"),
+    ("ому", "-му"),
+    ("ему", "-му"),
+    ("ый", "-й"),
+    ("ий", "-й"),
+    ("ой", "-й"),
+    ("ей", "-й"),
+    ("ая", "-я"),
+    ("яя", "-я"),
+    ("ое", "-е"),
+    ("ее", "-е"),
+    ("ые", "-е"),
+    ("ие", "-е"),
+    ("ую", "-ю"),
+    ("юю", "-ю"),
+    ("ым", "-м"),
+    ("им", "-м"),
+    ("ом", "-м"),
+    ("ем", "-м"),
+    ("ых", "-х"),
+    ("их", "-х"),
+];
+
+/// Split an ordinal adjective into its stem and the suffix of its digit form.
+fn split_ordinal(word: &str) -> Option<(&str, &'static str)> {
+    ORDINAL_ENDINGS.iter().find_map(|&(ending, suffix)| {
+        let stem = word.strip_suffix(ending)?;
+        if ending.starts_with('ь') && stem != "трет" {
+            None
+        } else {
+            Some((stem, suffix))
+        }
+    })
+}
+
+/// How an ordinal stem builds the number.
+enum Rank {
+    Digits(&'static [u8]),
+    /// A multiplier, like "*тысячный*", with its optional prefix, like "*двух*" in "*двухтысячный*".
+    Multiplier(usize, &'static [u8]),
+}
+
+fn ordinal_rank(stem: &str) -> Option<Rank> {
+    let digits: &[u8] = match stem {
+        "перв" => b"1",
+        "втор" => b"2",
+        "трет" => b"3",
+        "четверт" => b"4",
+        "пят" => b"5",
+        "шест" => b"6",
+        "седьм" => b"7",
+        "восьм" => b"8",
+        "девят" => b"9",
+        "десят" => b"10",
+        "одиннадцат" => b"11",
+        "двенадцат" => b"12",
+        "тринадцат" => b"13",
+        "четырнадцат" => b"14",
+        "пятнадцат" => b"15",
+        "шестнадцат" => b"16",
+        "семнадцат" => b"17",
+        "восемнадцат" => b"18",
+        "девятнадцат" => b"19",
+        "двадцат" => b"20",
+        "тридцат" => b"30",
+        "сороков" => b"40",
+        "пятидесят" => b"50",
+        "шестидесят" => b"60",
+        "семидесят" => b"70",
+        "восьмидесят" => b"80",
+        "девяност" => b"90",
+        "сот" => b"100",
+        "двухсот" => b"200",
+        "трехсот" => b"300",
+        "четырехсот" => b"400",
+        "пятисот" => b"500",
+        "шестисот" => b"600",
+        "семисот" => b"700",
+        "восьмисот" => b"800",
+        "девятисот" => b"900",
+        _ => {
+            let (prefix, positions) = if let Some(prefix) = stem.strip_suffix("тысячн") {
+                (prefix, 3)
+            } else if let Some(prefix) = stem.strip_suffix("миллионн") {
+                (prefix, 6)
+            } else if let Some(prefix) = stem.strip_suffix("миллиардн") {
+                (prefix, 9)
+            } else {
+                return None;
+            };
+            let multiplicand: &[u8] = match prefix {
+                "" => b"",
+                "двух" => b"2",
+                "трех" => b"3",
+                "четырех" => b"4",
+                "пяти" => b"5",
+                "шести" => b"6",
+                "семи" => b"7",
+                "восьми" => b"8",
+                "девяти" => b"9",
+                "десяти" => b"10",
+                "сто" => b"100",
+                _ => return None,
+            };
+            return Some(Rank::Multiplier(positions, multiplicand));
+        }
+    };
+    Some(Rank::Digits(digits))
+}
+
+/// Number of decimal places of the denominators of decimal fractions, like "*сотых*" (hundredths).
+fn decimal_places(word: &str) -> Option<usize> {
+    let (stem, _) = split_ordinal(word)?;
+    match stem {
+        "десят" => Some(1),
+        "сот" => Some(2),
+        "тысячн" => Some(3),
+        "десятитысячн" => Some(4),
+        "стотысячн" => Some(5),
+        "миллионн" => Some(6),
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+pub struct Russian {}
+
+impl Russian {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    fn apply_ordinal(&self, word: &str, b: &mut DigitString) -> Result<(), Error> {
+        let (stem, suffix) = split_ordinal(word).ok_or(Error::NaN)?;
+        let status = match ordinal_rank(stem).ok_or(Error::NaN)? {
+            Rank::Digits(digits) if digits.len() == 1 && b.peek(2) == b"10" => Err(Error::Overlap),
+            Rank::Digits(digits) => b.put(digits),
+            Rank::Multiplier(positions, multiplicand) => {
+                if !b.is_range_free(positions, positions + 2) {
+                    Err(Error::Overlap)
+                } else if multiplicand.is_empty() {
+                    b.shift(positions)
+                } else {
+                    b.put(multiplicand).and_then(|_| b.shift(positions))
+                }
+            }
+        };
+        if status.is_ok() {
+            b.marker = MorphologicalMarker::Ordinal(suffix);
+            b.freeze();
+        }
+        status
+    }
+}
+
+impl LangInterpreter for Russian {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        let word = normalize(num_func);
+        let word = word.as_ref();
+        match word {
+            "ноль" | "нуль" | "ноля" | "нуля" | "нолю" | "нулю" | "нолем" | "нулем" | "ноле"
+            | "нуле" => b.put(b"0"),
+            "один" | "одна" | "одно" | "одни" | "одного" | "одной" | "одному" | "одним"
+            | "одном" | "одну" | "одною" | "одних" | "одними"
+                if b.peek(2) != b"10" =>
+            {
+                b.put(b"1")
+            }
+            "два" | "две" | "двух" | "двум" | "двумя" if b.peek(2) != b"10" => {
+                b.put(b"2")
+            }
+            "три" | "трех" | "трем" | "тремя" if b.peek(2) != b"10" => b.put(b"3"),
+            "четыре" | "четырех" | "четырем" | "четырьмя" if b.peek(2) != b"10" => {
+                b.put(b"4")
+            }
+            "пять" | "пяти" | "пятью" if b.peek(2) != b"10" => b.put(b"5"),
+            "шесть" | "шести" | "шестью" if b.peek(2) != b"10" => b.put(b"6"),
+            "семь" | "семи" | "семью" if b.peek(2) != b"10" => b.put(b"7"),
+            "восемь" | "восьми" | "восемью" | "восьмью" if b.peek(2) != b"10" => {
+                b.put(b"8")
+            }
+            "девять" | "девяти" | "девятью" if b.peek(2) != b"10" => b.put(b"9"),
+            "десять" | "десяти" | "десятью" => b.put(b"10"),
+            "одиннадцать" | "одиннадцати" | "одиннадцатью" => {
+                b.put(b"11")
+            }
+            "двенадцать" | "двенадцати" | "двенадцатью" => {
+                b.put(b"12")
+            }
+            "тринадцать" | "тринадцати" | "тринадцатью" => {
+                b.put(b"13")
+            }
+            "четырнадцать" | "четырнадцати" | "четырнадцатью" => {
+                b.put(b"14")
+            }
+            "пятнадцать" | "пятнадцати" | "пятнадцатью" => {
+                b.put(b"15")
+            }
+            "шестнадцать" | "шестнадцати" | "шестнадцатью" => {
+                b.put(b"16")
+            }
+            "семнадцать" | "семнадцати" | "семнадцатью" => {
+                b.put(b"17")
+            }
+            "восемнадцать" | "восемнадцати" | "восемнадцатью" => {
+                b.put(b"18")
+            }
+            "девятнадцать" | "девятнадцати" | "девятнадцатью" => {
+                b.put(b"19")
+            }
+            "двадцать" | "двадцати" | "двадцатью" => b.put(b"20"),
+            "тридцать" | "тридцати" | "тридцатью" => b.put(b"30"),
+            "сорок" | "сорока" => b.put(b"40"),
+            "пятьдесят" | "пятидесяти" | "пятьюдесятью" => {
+                b.put(b"50")
+            }
+            "шестьдесят" | "шестидесяти" | "шестьюдесятью" => {
+                b.put(b"60")
+            }
+            "семьдесят" | "семидесяти" | "семьюдесятью" => {
+                b.put(b"70")
+            }
+            "восемьдесят" | "восьмидесяти" | "восемьюдесятью" | "восьмьюдесятью" => {
+                b.put(b"80")
+            }
+            "девяносто" | "девяноста" => b.put(b"90"),
+            "сто" | "ста" => b.put(b"100"),
+            "двести" | "двухсот" | "двумстам" | "двумястами" | "двухстах" => {
+                b.put(b"200")
+            }
+            "триста" | "трехсот" | "тремстам" | "тремястами" | "трехстах" => {
+                b.put(b"300")
+            }
+            "четыреста" | "четырехсот" | "четыремстам" | "четырьмястами" | "четырехстах" => {
+                b.put(b"400")
+            }
+            "пятьсот" | "пятисот" | "пятистам" | "пятьюстами" | "пятистах" => {
+                b.put(b"500")
+            }
+            "шестьсот" | "шестисот" | "шестистам" | "шестьюстами" | "шестистах" => {
+                b.put(b"600")
+            }
+            "семьсот" | "семисот" | "семистам" | "семьюстами" | "семистах" => {
+                b.put(b"700")
+            }
+            "восемьсот"
+            | "восьмисот"
+            | "восьмистам"
+            | "восемьюстами"
+            | "восьмьюстами"
+            | "восьмистах" => b.put(b"800"),
+            "девятьсот" | "девятисот" | "девятистам" | "девятьюстами" | "девятистах" => {
+                b.put(b"900")
+            }
+            _ if is_inflection_of(word, "тысяч", &THOUSAND_ENDINGS) => {
+                if b.is_range_free(3, 5) {
+                    b.shift(3)
+                } else {
+                    Err(Error::Overlap)
+                }
+            }
+            _ if is_inflection_of(word, "миллион", &MILLION_ENDINGS) => {
+                if b.is_range_free(6, 8) {
+                    b.shift(6)
+                } else {
+                    Err(Error::Overlap)
+                }
+            }
+            _ if is_inflection_of(word, "миллиард", &MILLION_ENDINGS) => b.shift(9),
+            _ => self.apply_ordinal(word, b),
+        }
+    }
+
+    /// The decimal part is a fraction whose denominator is a power of ten: "*четырнадцать сотых*" (fourteen hundredths).
+    /// The denominator may be left out, as in "*три целых четырнадцать*".
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        let word = normalize(decimal_func);
+        if let Some(places) = decimal_places(&word) {
+            let digits = b.to_string();
+            if b.is_empty() || digits.len() > places {
+                return Err(Error::NaN);
+            }
+            b.reset();
+            b.push("0".repeat(places - digits.len()).as_bytes())?;
+            b.push(digits.as_bytes())?;
+            b.freeze();
+            Ok(())
+        } else {
+            let status = self.apply(&word, b);
+            if b.is_ordinal() {
+                // Not a cardinal after all
+                b.reset();
+                Err(Error::NaN)
+            } else {
+                status
+            }
+        }
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        match word {
+            "целых" | "целая" | "целой" | "целую" | "запятая" => {
+                Some(',')
+            }
+            _ => None,
+        }
+    }
+
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "минус" => Some('-'),
+            "плюс" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn apply_denominator(
+        &self,
+        frac_func: &str,
+        numerator: &DigitString,
+        b: &mut DigitString,
+    ) -> Result<(), Error> {
+        let word = normalize(frac_func);
+        match word.as_ref() {
+            "половина" | "половины" | "половине" | "половину" | "половиной" | "половин" => {
+                b.put(b"2")
+            }
+            "треть" | "трети" | "третью" | "третей" => b.put(b"3"),
+            "четверть" | "четверти" | "четвертью" | "четвертей" => {
+                b.put(b"4")
+            }
+            word => {
+                ordinal_denominator(self, word, numerator, false, b)?;
+                // "одна пятая", but "две пятых"
+                let singular = numerator.peek(1) == b"1" && numerator.peek(2) != b"11";
+                match b.marker {
+                    MorphologicalMarker::Ordinal("-я" | "-ю" | "-й") if singular => Ok(()),
+                    MorphologicalMarker::Ordinal("-х" | "-ми") if !singular => Ok(()),
+                    _ => Err(Error::NaN),
+                }
+            }
+        }
+    }
+
+    fn is_fraction_link(&self, word: &str) -> bool {
+        word == "с"
+    }
+
+    fn format_and_value(&self, b: &DigitString) -> (String, f64) {
+        let repr = b.to_string();
+        let val: f64 = repr.parse().unwrap();
+        if let MorphologicalMarker::Ordinal(marker) = b.marker {
+            (format!("{repr}{marker}"), val)
+        } else {
+            (repr, val)
+        }
+    }
+
+    fn format_decimal_and_value(
+        &self,
+        int: &DigitString,
+        dec: &DigitString,
+        sep: char,
+    ) -> (String, f64) {
+        let sint = int.to_string();
+        let sdec = dec.to_string();
+        let val = format!("{sint}.{sdec}").parse().unwrap();
+        (format!("{sint}{sep}{sdec}"), val)
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        let word = normalize(word);
+        match split_ordinal(&word) {
+            Some((stem, suffix)) if ordinal_rank(stem).is_some() => {
+                MorphologicalMarker::Ordinal(suffix)
+            }
+            _ => MorphologicalMarker::None,
+        }
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Russian;
+    use crate::ConversionOptions;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Russian {};
+            let res = text2digits($text, &f);
+            dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Russian {};
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_fractions {
+        ($text:expr, $res:expr) => {
+            assert_replace_fractions!($text, $res, 10.0)
+        };
+        ($text:expr, $res:expr, $threshold:expr) => {
+            let f = Russian {};
+            let options = ConversionOptions::from($threshold).convert_fractions(true);
+            assert_eq!(replace_numbers_in_text($text, &f, options), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Russian {};
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Russian {};
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!(
+            "пятьдесят три миллиарда двести сорок три тысячи семьсот двадцать четыре",
+            "53000243724"
+        );
+        assert_text2digits!(
+            "пятьдесят один миллион пятьсот семьдесят восемь тысяч триста два",
+            "51578302"
+        );
+        assert_text2digits!("восемьдесят пять", "85");
+        assert_text2digits!("сто пятнадцать", "115");
+        assert_text2digits!("тысяча девятьсот двадцать", "1920");
+        assert_text2digits!("одна тысяча девятьсот двадцать", "1920");
+        assert_text2digits!("два миллиарда двадцать пять миллионов", "2025000000");
+        assert_text2digits!("сорок два", "42");
+    }
+
+    #[test]
+    fn test_inflections() {
+        assert_text2digits!("одно", "1");
+        assert_text2digits!("две тысячи", "2000");
+        assert_text2digits!("пять тысяч", "5000");
+        assert_text2digits!("трёх тысяч", "3000");
+        assert_text2digits!("двумя тысячами", "2000");
+        assert_text2digits!("миллиона", "1000000");
+        assert_text2digits!("шести миллионов", "6000000");
+        assert_text2digits!("пятьюстами двадцатью", "520");
+        assert_text2digits!("четырьмястами сорока", "440");
+        assert_text2digits!("девяноста восьми", "98");
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_text2digits!("двадцать первый", "21-й");
+        assert_text2digits!("первая", "1-я");
+        assert_text2digits!("второе", "2-е");
+        assert_text2digits!("третьего", "3-го");
+        assert_text2digits!("третья", "3-я");
+        assert_text2digits!("сорок четвёртому", "44-му");
+        assert_text2digits!("пятидесятых", "50-х");
+        assert_text2digits!("сотый", "100-й");
+        assert_text2digits!("тысячный", "1000-й");
+        assert_text2digits!("двухтысячный", "2000-й");
+        assert_text2digits!("две тысячи двадцать третьем", "2023-м");
+    }
+
+    #[test]
+    fn test_decimals() {
+        assert_text2digits!("три", "3");
+        assert_replace_numbers!("три целых четырнадцать сотых", "3,14");
+        assert_replace_numbers!("три целых пять сотых метра", "3,05 метра");
+        assert_replace_numbers!("ноль целых пять десятых", "0,5");
+        assert_replace_numbers!("одна целая двадцать пять тысячных", "1,025");
+        assert_replace_numbers!("двенадцать целых девять", "12,9");
+    }
+
+    #[test]
+    fn test_fractions() {
+        assert_replace_fractions!("три четвёртых торта", "3/4 торта");
+        assert_replace_fractions!("одна пятая часть", "1/5 часть");
+        assert_replace_fractions!("две трети", "2/3");
+        assert_replace_fractions!("два с половиной часа", "2 1/2 часа");
+        assert_replace_fractions!("пять первых", "5 1-х", 0.0);
+    }
+
+    #[test]
+    fn test_zeroes() {
+        assert_text2digits!("ноль", "0");
+        assert_text2digits!("ноль восемь", "08");
+        assert_text2digits!("ноль ноль сто двадцать пять", "00125");
+        assert_invalid!("пять ноль");
+        assert_invalid!("пятьдесят ноль три");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("тысяча тысяча двести");
+        assert_invalid!("шестьдесят пятнадцать");
+        assert_invalid!("сто сто");
+        assert_invalid!("десять пять");
+        assert_invalid!("десять пятый");
+        assert_invalid!("двадцатый два");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!(
+            "двадцать пять коров, двенадцать кур и сто двадцать пять килограммов картошки.",
+            "25 коров, 12 кур и 125 килограммов картошки."
+        );
+        assert_replace_numbers!(
+            "Одна тысяча двести шестьдесят шесть рублей.",
+            "1266 рублей."
+        );
+        assert_replace_numbers!("один два три четыре двадцать пятнадцать", "1 2 3 4 20 15");
+        assert_replace_numbers!("минус двадцать градусов", "минус 20 градусов");
+    }
+
+    #[test]
+    fn test_isolates() {
+        assert_replace_numbers!("Это один из них", "Это один из них");
+        assert_replace_all_numbers!("Это один из них", "Это 1 из них");
+        assert_replace_numbers!("Можно считать: один, два, три", "Можно считать: 1, 2, 3");
+        assert_replace_numbers!(
+            "Звоните по номеру восемь девятьсот пятнадцать ноль ноль семь",
+            "Звоните по номеру 8 915 007"
+        );
+    }
+}
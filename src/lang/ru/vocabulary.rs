@@ -0,0 +1,5 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "а", "ага", "да", "и", "ну", "вот", "так", "потом", "затем", "это", "э", "эм", "минус", "плюс", "ещё", "еще", "хорошо"
+};
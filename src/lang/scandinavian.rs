@@ -0,0 +1,198 @@
+//! Shared core of the Scandinavian interpreters
+//!
+//! Swedish, Danish and Norwegian build their numbers the same way, with solid compounds like "*tjugofem*" or
+//! "*femogtyve*": they only differ in their words and in the order of the units and the tens.
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+use crate::tokenizer::WordSplitter;
+
+use super::{LangInterpreter, MorphologicalMarker, ordinal_denominator};
+
+/// The meaning of a simple number word.
+#[derive(Debug, Clone, Copy)]
+pub(super) enum Numeral {
+    Zero,
+    /// A digit from 1 to 9
+    Unit(u8),
+    /// A number from 10 to 19
+    Teen(&'static [u8]),
+    /// The tens digit, from 2 to 9
+    Tens(u8),
+    Hundred,
+    Thousand,
+    Million,
+    Milliard,
+    /// The "*og*" of "*femogtyve*" or "*hundrede og fem*"
+    And,
+}
+
+/// Set after a unit that may be followed by "*og*" and the tens.
+const UNIT: u64 = 1;
+/// Set after "*og*" following a unit.
+const AND: u64 = 2;
+
+/// What a Scandinavian language provides to the shared core.
+pub(super) trait Dialect: LangInterpreter {
+    /// Units can come before the tens, linked by "*og*": "*femogtyve*"
+    const UNITS_FIRST: bool;
+    /// Tens can come before the units: "*tjugofem*"
+    const TENS_FIRST: bool;
+    /// Words for a half
+    const HALVES: &'static [&'static str];
+    /// Suffixes making a fraction from an ordinal, like "*del*" in "*tredjedel*"
+    const PARTS: &'static [&'static str];
+    /// Compounds whose parts share a letter, like "*ettusen*" (*ett* + *tusen*), with their parts
+    const MERGED: &'static [(&'static str, [&'static str; 2])] = &[];
+
+    fn word_splitter(&self) -> &WordSplitter;
+
+    /// The meaning of a simple number word, with its marker if it's an ordinal.
+    fn numeral(&self, word: &str) -> Option<(Numeral, Option<&'static str>)>;
+}
+
+/// The parts of the compound `word`, split on the number words and on the merged compounds.
+fn parts<'a, D: Dialect>(lang: &'a D, word: &'a str) -> Vec<&'a str> {
+    let mut parts = Vec::new();
+    for part in lang.word_splitter().split(word) {
+        match D::MERGED.iter().find(|(merged, _)| *merged == part) {
+            Some((_, merged)) => parts.extend(merged),
+            None => parts.push(part),
+        }
+    }
+    parts
+}
+
+pub(super) fn apply<D: Dialect>(
+    lang: &D,
+    num_func: &str,
+    b: &mut DigitString,
+) -> Result<(), Error> {
+    let parts = parts(lang, num_func);
+    if parts.len() > 1 {
+        return match lang.exec_group(parts.into_iter()) {
+            Ok(ds) => {
+                if ds.len() > 3 && ds.len() <= 6 && !b.is_range_free(3, 5) {
+                    return Err(Error::Overlap);
+                }
+                b.put(&ds)?;
+                b.flags = ds.flags;
+                if ds.marker.is_ordinal() {
+                    b.marker = ds.marker;
+                    b.freeze()
+                }
+                Ok(())
+            }
+            Err(err) => Err(err),
+        };
+    }
+    let (numeral, ordinal) = lang.numeral(num_func).ok_or(Error::NaN)?;
+    let mut flags = 0;
+    let status = match numeral {
+        Numeral::Zero => b.put(b"0"),
+        Numeral::Unit(digit) => {
+            let after_tens = b.len() >= 2 && b.peek(1) == b"0" && b.peek(2)[0] >= b'2';
+            if b.is_free(2) {
+                if D::UNITS_FIRST {
+                    flags = UNIT;
+                }
+                b.put(&[digit])
+            } else if D::TENS_FIRST && after_tens {
+                b.put(&[digit])
+            } else {
+                Err(Error::Overlap)
+            }
+        }
+        Numeral::Teen(digits) => b.put(digits),
+        Numeral::Tens(digit) => {
+            if b.flags & AND != 0 {
+                b.put_digit_at(digit, 1)
+            } else if b.flags & UNIT != 0 {
+                Err(Error::Overlap)
+            } else {
+                b.put(&[digit, b'0'])
+            }
+        }
+        Numeral::Hundred => {
+            let peek = b.peek(2);
+            if peek.len() == 1 || peek < b"20".as_slice() {
+                b.shift(2)
+            } else {
+                Err(Error::Overlap)
+            }
+        }
+        Numeral::Thousand if b.is_range_free(3, 5) => b.shift(3),
+        Numeral::Million if b.is_range_free(6, 8) => b.shift(6),
+        Numeral::Milliard => b.shift(9),
+        Numeral::And if b.flags & UNIT != 0 => {
+            flags = AND;
+            Err(Error::Incomplete)
+        }
+        // "hundrede og fem"
+        Numeral::And if !b.is_empty() => Err(Error::Incomplete),
+        _ => Err(Error::NaN),
+    };
+    match status {
+        Ok(()) => {
+            b.flags = flags;
+            if let Some(marker) = ordinal {
+                b.marker = MorphologicalMarker::Ordinal(marker);
+                b.freeze();
+            }
+        }
+        Err(Error::Incomplete) => b.flags = flags,
+        Err(_) => (),
+    }
+    status
+}
+
+pub(super) fn apply_denominator<D: Dialect>(
+    lang: &D,
+    frac_func: &str,
+    numerator: &DigitString,
+    b: &mut DigitString,
+) -> Result<(), Error> {
+    if D::HALVES.contains(&frac_func) {
+        return b.put(b"2");
+    }
+    if frac_func == "kvart" {
+        return b.put(b"4");
+    }
+    // "tredjedel", "fjerdedele"… are nouns, so "en tredjedel" is a fraction, not an ordinal
+    let ordinal = D::PARTS
+        .iter()
+        .find_map(|part| frac_func.strip_suffix(part))
+        .ok_or(Error::NaN)?;
+    ordinal_denominator(lang, ordinal, numerator, false, b)
+}
+
+pub(super) fn get_morph_marker<D: Dialect>(lang: &D, word: &str) -> MorphologicalMarker {
+    // Only the last part of a compound is ordinal
+    let last = parts(lang, word).pop().unwrap_or(word);
+    match lang.numeral(last) {
+        Some((_, Some(marker))) => MorphologicalMarker::Ordinal(marker),
+        _ => MorphologicalMarker::None,
+    }
+}
+
+pub(super) fn format_and_value(b: &DigitString) -> (String, f64) {
+    let repr = b.to_string();
+    let val: f64 = repr.parse().unwrap();
+    if let MorphologicalMarker::Ordinal(marker) = b.marker {
+        (format!("{repr}{marker}"), val)
+    } else {
+        (repr, val)
+    }
+}
+
+pub(super) fn format_decimal_and_value(
+    int: &DigitString,
+    dec: &DigitString,
+    sep: char,
+) -> (String, f64) {
+    let irepr = int.to_string();
+    let drepr = dec.to_string();
+    let frepr = format!("{irepr}{sep}{drepr}");
+    let val = format!("{irepr}.{drepr}").parse().unwrap();
+    (frepr, val)
+}
@@ -0,0 +1,273 @@
+//! Slovak number interpreter
+//!
+//! Slovak writes the numbers under a million in a single word, "dvestodvadsaťpäť", and the interpreter also accepts them
+//! split, as the main application, Speech-to-text recognition, may introduce spurious spaces.
+//! Ordinals are written with a final dot: "*piaty*" becomes "*5.*".
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+use crate::tokenizer::WordSplitter;
+
+mod vocabulary;
+
+use super::czechoslovak::{self, Dialect, Numeral};
+use super::{LangInterpreter, MorphologicalMarker};
+use vocabulary::INSIGNIFICANT;
+
+pub struct Slovak {
+    word_splitter: WordSplitter,
+}
+
+impl Default for Slovak {
+    fn default() -> Self {
+        Self {
+            word_splitter: WordSplitter::new([
+                "dvadsať",
+                "tridsať",
+                "štyridsať",
+                "päťdesiat",
+                "šesťdesiat",
+                "sedemdesiat",
+                "osemdesiat",
+                "deväťdesiat",
+                "sto",
+                "tisíc",
+                "tisíce",
+                "tisícov",
+            ])
+            .unwrap(),
+        }
+    }
+}
+
+impl Slovak {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl Dialect for Slovak {
+    const ORDINAL_ENDINGS: &'static [&'static str] = &[
+        "ieho", "ého", "eho", "ia", "ie", "ý", "y", "á", "a", "é", "e", "í", "i",
+    ];
+    const INVERTED_UNITS: &'static [(&'static str, u8)] = &[];
+
+    fn word_splitter(&self) -> Option<&WordSplitter> {
+        Some(&self.word_splitter)
+    }
+
+    fn cardinal(&self, word: &str) -> Option<Numeral> {
+        let numeral = match word {
+            "nula" => Numeral::Zero,
+            "jeden" | "jedna" | "jedno" | "jedného" | "jednej" | "jednu" => Numeral::Unit(b'1'),
+            "dva" | "dve" | "dvaja" | "dvoch" => Numeral::Unit(b'2'),
+            "tri" | "traja" | "troch" => Numeral::Unit(b'3'),
+            "štyri" | "štyria" | "štyroch" => Numeral::Unit(b'4'),
+            "päť" | "piatich" => Numeral::Unit(b'5'),
+            "šesť" | "šiestich" => Numeral::Unit(b'6'),
+            "sedem" | "siedmich" => Numeral::Unit(b'7'),
+            "osem" | "ôsmich" => Numeral::Unit(b'8'),
+            "deväť" | "deviatich" => Numeral::Unit(b'9'),
+            "desať" | "desiatich" => Numeral::Digits(b"10"),
+            "jedenásť" => Numeral::Digits(b"11"),
+            "dvanásť" => Numeral::Digits(b"12"),
+            "trinásť" => Numeral::Digits(b"13"),
+            "štrnásť" => Numeral::Digits(b"14"),
+            "pätnásť" => Numeral::Digits(b"15"),
+            "šestnásť" => Numeral::Digits(b"16"),
+            "sedemnásť" => Numeral::Digits(b"17"),
+            "osemnásť" => Numeral::Digits(b"18"),
+            "devätnásť" => Numeral::Digits(b"19"),
+            "dvadsať" => Numeral::Tens(b'2'),
+            "tridsať" => Numeral::Tens(b'3'),
+            "štyridsať" => Numeral::Tens(b'4'),
+            "päťdesiat" => Numeral::Tens(b'5'),
+            "šesťdesiat" => Numeral::Tens(b'6'),
+            "sedemdesiat" => Numeral::Tens(b'7'),
+            "osemdesiat" => Numeral::Tens(b'8'),
+            "deväťdesiat" => Numeral::Tens(b'9'),
+            "sto" => Numeral::Hundred,
+            "tisíc" | "tisíce" | "tisícov" => Numeral::Thousand,
+            "milión" | "milióny" | "miliónov" | "miliónu" => Numeral::Million,
+            "miliarda" | "miliardy" | "miliárd" => Numeral::Milliard,
+            _ => return None,
+        };
+        Some(numeral)
+    }
+
+    fn ordinal(&self, stem: &str) -> Option<Numeral> {
+        let numeral = match stem {
+            "prv" => Numeral::Unit(b'1'),
+            "druh" => Numeral::Unit(b'2'),
+            "tret" => Numeral::Unit(b'3'),
+            "štvrt" => Numeral::Unit(b'4'),
+            "piat" => Numeral::Unit(b'5'),
+            "šiest" => Numeral::Unit(b'6'),
+            "siedm" => Numeral::Unit(b'7'),
+            "ôsm" => Numeral::Unit(b'8'),
+            "deviat" => Numeral::Unit(b'9'),
+            "desiat" => Numeral::Digits(b"10"),
+            "jedenást" => Numeral::Digits(b"11"),
+            "dvanást" => Numeral::Digits(b"12"),
+            "trinást" => Numeral::Digits(b"13"),
+            "štrnást" => Numeral::Digits(b"14"),
+            "pätnást" => Numeral::Digits(b"15"),
+            "šestnást" => Numeral::Digits(b"16"),
+            "sedemnást" => Numeral::Digits(b"17"),
+            "osemnást" => Numeral::Digits(b"18"),
+            "devätnást" => Numeral::Digits(b"19"),
+            "dvadsiat" => Numeral::Tens(b'2'),
+            "tridsiat" => Numeral::Tens(b'3'),
+            "štyridsiat" => Numeral::Tens(b'4'),
+            "päťdesiat" => Numeral::Tens(b'5'),
+            "šesťdesiat" => Numeral::Tens(b'6'),
+            "sedemdesiat" => Numeral::Tens(b'7'),
+            "osemdesiat" => Numeral::Tens(b'8'),
+            "deväťdesiat" => Numeral::Tens(b'9'),
+            "st" => Numeral::Hundred,
+            "miliónt" => Numeral::Million,
+            _ => return None,
+        };
+        Some(numeral)
+    }
+}
+
+impl LangInterpreter for Slovak {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        czechoslovak::apply(self, num_func, b)
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        self.apply(decimal_func, b)
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        czechoslovak::check_decimal_separator(word)
+    }
+
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "mínus" | "minus" => Some('-'),
+            "plus" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn format_and_value(&self, b: &DigitString) -> (String, f64) {
+        czechoslovak::format_and_value(b)
+    }
+
+    fn format_decimal_and_value(
+        &self,
+        int: &DigitString,
+        dec: &DigitString,
+        sep: char,
+    ) -> (String, f64) {
+        czechoslovak::format_decimal_and_value(int, dec, sep)
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        czechoslovak::get_morph_marker(self, word)
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Slovak;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Slovak::new();
+            let res = text2digits($text, &f);
+            dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Slovak::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Slovak::new();
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("nula", "0");
+        assert_text2digits!("dvanásť", "12");
+        assert_text2digits!("dvadsaťjeden", "21");
+        assert_text2digits!("dvadsať jeden", "21");
+        assert_text2digits!("sto", "100");
+        assert_text2digits!("stopätnásť", "115");
+        assert_text2digits!("dvestotridsať", "230");
+        assert_text2digits!("dve sto tridsať", "230");
+        assert_text2digits!("tisíc deväťstoosemdesiatštyri", "1984");
+        assert_text2digits!("dvetisícpäťsto", "2500");
+        assert_text2digits!("stotisíc", "100000");
+        assert_text2digits!(
+            "päťdesiattri miliárd dvestoštyridsaťtri tisíc sedemstodvadsaťštyri",
+            "53000243724"
+        );
+    }
+
+    #[test]
+    fn test_plurals() {
+        assert_text2digits!("milión", "1000000");
+        assert_text2digits!("dva milióny", "2000000");
+        assert_text2digits!("päť miliónov", "5000000");
+        assert_text2digits!("tri miliardy", "3000000000");
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_text2digits!("prvý", "1.");
+        assert_text2digits!("druhá", "2.");
+        assert_text2digits!("tretí", "3.");
+        assert_text2digits!("piaty", "5.");
+        assert_text2digits!("dvadsiaty prvý", "21.");
+        assert_text2digits!("stý", "100.");
+        assert_replace_numbers!("dvadsiateho ôsmeho októbra", "28. októbra");
+    }
+
+    #[test]
+    fn test_decimals() {
+        assert_replace_numbers!("tri celé štrnásť", "3,14");
+        assert_replace_numbers!("dvanásť celých päť", "12,5");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("tisíc tisíc dvesto");
+        assert_invalid!("šesťdesiat pätnásť");
+        assert_invalid!("desať päť");
+        assert_invalid!("päť dvadsať");
+        assert_invalid!("miesto");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!(
+            "dvadsaťpäť kráv, dvanásť sliepok a stodvadsaťpäť kíl zemiakov.",
+            "25 kráv, 12 sliepok a 125 kíl zemiakov."
+        );
+        assert_replace_numbers!("jeden dva tri štyri dvadsať pätnásť", "1 2 3 4 20 15");
+    }
+}
@@ -0,0 +1,5 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "a", "no", "tak", "ehm", "hm", "hej", "áno", "minus", "plus", "je", "potom", "teda"
+};
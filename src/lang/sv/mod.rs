@@ -0,0 +1,339 @@
+//! Swedish number interpreter
+//!
+//! Swedish numbers are solid compounds, "tjugofem", "tvåhundratrettio", and the interpreter also accepts them split,
+//! as the main application, Speech-to-text recognition, may introduce spurious spaces.
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+use crate::tokenizer::WordSplitter;
+
+mod vocabulary;
+
+use super::scandinavian::{self, Dialect, Numeral};
+use super::{LangInterpreter, MorphologicalMarker};
+use vocabulary::INSIGNIFICANT;
+
+pub struct Swedish {
+    word_splitter: WordSplitter,
+}
+
+impl Default for Swedish {
+    fn default() -> Self {
+        Self {
+            word_splitter: WordSplitter::new([
+                "tjugo",
+                "tjugonde",
+                "trettio",
+                "trettionde",
+                "fyrtio",
+                "fyrtionde",
+                "femtio",
+                "femtionde",
+                "sextio",
+                "sextionde",
+                "sjuttio",
+                "sjuttionde",
+                "åttio",
+                "åttionde",
+                "nittio",
+                "nittionde",
+                "hundra",
+                "hundrade",
+                "tusen",
+                "tusende",
+                "ettusen",
+                "ettusende",
+                "miljon",
+                "miljoner",
+                "miljonte",
+                "miljard",
+                "miljarder",
+                "miljardte",
+            ])
+            .unwrap(),
+        }
+    }
+}
+
+impl Swedish {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl Dialect for Swedish {
+    const UNITS_FIRST: bool = false;
+    const TENS_FIRST: bool = true;
+    const HALVES: &'static [&'static str] = &["halv", "halva", "halvt", "halvor"];
+    const PARTS: &'static [&'static str] = &["del", "delar"];
+    const MERGED: &'static [(&'static str, [&'static str; 2])] = &[
+        ("ettusen", ["ett", "tusen"]),
+        ("ettusende", ["ett", "tusende"]),
+    ];
+
+    fn word_splitter(&self) -> &WordSplitter {
+        &self.word_splitter
+    }
+
+    fn numeral(&self, word: &str) -> Option<(Numeral, Option<&'static str>)> {
+        let numeral = match word {
+            "noll" => (Numeral::Zero, None),
+            "en" | "ett" => (Numeral::Unit(b'1'), None),
+            "första" | "förste" => (Numeral::Unit(b'1'), Some(":a")),
+            "två" => (Numeral::Unit(b'2'), None),
+            "andra" | "andre" => (Numeral::Unit(b'2'), Some(":a")),
+            "tre" => (Numeral::Unit(b'3'), None),
+            "tredje" => (Numeral::Unit(b'3'), Some(":e")),
+            "fyra" => (Numeral::Unit(b'4'), None),
+            "fjärde" => (Numeral::Unit(b'4'), Some(":e")),
+            "fem" => (Numeral::Unit(b'5'), None),
+            "femte" => (Numeral::Unit(b'5'), Some(":e")),
+            "sex" => (Numeral::Unit(b'6'), None),
+            "sjätte" => (Numeral::Unit(b'6'), Some(":e")),
+            "sju" => (Numeral::Unit(b'7'), None),
+            "sjunde" => (Numeral::Unit(b'7'), Some(":e")),
+            "åtta" => (Numeral::Unit(b'8'), None),
+            "åttonde" => (Numeral::Unit(b'8'), Some(":e")),
+            "nio" => (Numeral::Unit(b'9'), None),
+            "nionde" => (Numeral::Unit(b'9'), Some(":e")),
+            "tio" => (Numeral::Teen(b"10"), None),
+            "tionde" => (Numeral::Teen(b"10"), Some(":e")),
+            "elva" => (Numeral::Teen(b"11"), None),
+            "elfte" => (Numeral::Teen(b"11"), Some(":e")),
+            "tolv" => (Numeral::Teen(b"12"), None),
+            "tolfte" => (Numeral::Teen(b"12"), Some(":e")),
+            "tretton" => (Numeral::Teen(b"13"), None),
+            "trettonde" => (Numeral::Teen(b"13"), Some(":e")),
+            "fjorton" => (Numeral::Teen(b"14"), None),
+            "fjortonde" => (Numeral::Teen(b"14"), Some(":e")),
+            "femton" => (Numeral::Teen(b"15"), None),
+            "femtonde" => (Numeral::Teen(b"15"), Some(":e")),
+            "sexton" => (Numeral::Teen(b"16"), None),
+            "sextonde" => (Numeral::Teen(b"16"), Some(":e")),
+            "sjutton" => (Numeral::Teen(b"17"), None),
+            "sjuttonde" => (Numeral::Teen(b"17"), Some(":e")),
+            "arton" => (Numeral::Teen(b"18"), None),
+            "artonde" => (Numeral::Teen(b"18"), Some(":e")),
+            "nitton" => (Numeral::Teen(b"19"), None),
+            "nittonde" => (Numeral::Teen(b"19"), Some(":e")),
+            "tjugo" => (Numeral::Tens(b'2'), None),
+            "tjugonde" => (Numeral::Tens(b'2'), Some(":e")),
+            "trettio" => (Numeral::Tens(b'3'), None),
+            "trettionde" => (Numeral::Tens(b'3'), Some(":e")),
+            "fyrtio" => (Numeral::Tens(b'4'), None),
+            "fyrtionde" => (Numeral::Tens(b'4'), Some(":e")),
+            "femtio" => (Numeral::Tens(b'5'), None),
+            "femtionde" => (Numeral::Tens(b'5'), Some(":e")),
+            "sextio" => (Numeral::Tens(b'6'), None),
+            "sextionde" => (Numeral::Tens(b'6'), Some(":e")),
+            "sjuttio" => (Numeral::Tens(b'7'), None),
+            "sjuttionde" => (Numeral::Tens(b'7'), Some(":e")),
+            "åttio" => (Numeral::Tens(b'8'), None),
+            "åttionde" => (Numeral::Tens(b'8'), Some(":e")),
+            "nittio" => (Numeral::Tens(b'9'), None),
+            "nittionde" => (Numeral::Tens(b'9'), Some(":e")),
+            "hundra" => (Numeral::Hundred, None),
+            "hundrade" => (Numeral::Hundred, Some(":e")),
+            "tusen" => (Numeral::Thousand, None),
+            "tusende" => (Numeral::Thousand, Some(":e")),
+            "miljon" | "miljoner" => (Numeral::Million, None),
+            "miljonte" => (Numeral::Million, Some(":e")),
+            "miljard" | "miljarder" => (Numeral::Milliard, None),
+            "miljardte" => (Numeral::Milliard, Some(":e")),
+            _ => return None,
+        };
+        Some(numeral)
+    }
+}
+
+impl LangInterpreter for Swedish {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        scandinavian::apply(self, num_func, b)
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        self.apply(decimal_func, b)
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        if word == "komma" { Some(',') } else { None }
+    }
+
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "minus" => Some('-'),
+            "plus" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn apply_denominator(
+        &self,
+        frac_func: &str,
+        numerator: &DigitString,
+        b: &mut DigitString,
+    ) -> Result<(), Error> {
+        scandinavian::apply_denominator(self, frac_func, numerator, b)
+    }
+
+    fn is_fraction_link(&self, word: &str) -> bool {
+        matches!(word, "och" | "en" | "ett")
+    }
+
+    fn format_and_value(&self, b: &DigitString) -> (String, f64) {
+        scandinavian::format_and_value(b)
+    }
+
+    fn format_decimal_and_value(
+        &self,
+        int: &DigitString,
+        dec: &DigitString,
+        sep: char,
+    ) -> (String, f64) {
+        scandinavian::format_decimal_and_value(int, dec, sep)
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        scandinavian::get_morph_marker(self, word)
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Swedish;
+    use crate::ConversionOptions;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Swedish::new();
+            let res = text2digits($text, &f);
+            dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Swedish::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_fractions {
+        ($text:expr, $res:expr) => {
+            assert_replace_fractions!($text, $res, 10.0)
+        };
+        ($text:expr, $res:expr, $threshold:expr) => {
+            let f = Swedish::new();
+            let options = ConversionOptions::from($threshold).convert_fractions(true);
+            assert_eq!(replace_numbers_in_text($text, &f, options), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Swedish::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Swedish::new();
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("noll", "0");
+        assert_text2digits!("ett", "1");
+        assert_text2digits!("tolv", "12");
+        assert_text2digits!("tjugo", "20");
+        assert_text2digits!("tjugofem", "25");
+        assert_text2digits!("tjugo fem", "25");
+        assert_text2digits!("nittionio", "99");
+        assert_text2digits!("hundra", "100");
+        assert_text2digits!("etthundrafemton", "115");
+        assert_text2digits!("tvåhundratrettio", "230");
+        assert_text2digits!("nittonhundraåttiofyra", "1984");
+        assert_text2digits!("tusen", "1000");
+        assert_text2digits!("ettusen", "1000");
+        assert_text2digits!("ettusen tvåhundra", "1200");
+        assert_text2digits!("tjugoettusen", "21000");
+        assert_text2digits!("ettusende", "1000:e");
+        assert_text2digits!("tretusen", "3000");
+        assert_text2digits!("tjugotusen fyrahundra", "20400");
+        assert_text2digits!(
+            "femtiotre miljarder tvåhundrafyrtiotre tusen sjuhundratjugofyra",
+            "53000243724"
+        );
+        assert_text2digits!(
+            "femtioen miljoner femhundrasjuttioåtta tusen trehundratvå",
+            "51578302"
+        );
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_text2digits!("första", "1:a");
+        assert_text2digits!("andra", "2:a");
+        assert_text2digits!("tredje", "3:e");
+        assert_text2digits!("tjugoförsta", "21:a");
+        assert_text2digits!("tjugonde", "20:e");
+        assert_text2digits!("trettiofemte", "35:e");
+        assert_text2digits!("hundrade", "100:e");
+        assert_replace_numbers!("den tjugofjärde maj", "den 24:e maj");
+    }
+
+    #[test]
+    fn test_fractions() {
+        assert_replace_fractions!("tre fjärdedelar", "3/4");
+        assert_replace_fractions!("två och en halv", "2 1/2");
+        assert_replace_fractions!("en tredjedel", "1/3");
+    }
+
+    #[test]
+    fn test_decimals() {
+        assert_replace_numbers!("tolv komma fem", "12,5");
+        assert_replace_numbers!("noll komma noll fem", "0,05");
+    }
+
+    #[test]
+    fn test_zeroes() {
+        assert_text2digits!("noll åtta", "08");
+        assert_text2digits!("noll noll hundratjugofem", "00125");
+        assert_invalid!("fem noll");
+        assert_invalid!("femtionolltre");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("tusen tusen tvåhundra");
+        assert_invalid!("sextiofemton");
+        assert_invalid!("tio fem");
+        assert_invalid!("femtjugo");
+        assert_invalid!("tjugoförsta tre");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!(
+            "tjugofem kor, tolv höns och hundratjugofem kg potatis.",
+            "25 kor, 12 höns och 125 kg potatis."
+        );
+        assert_replace_numbers!("ett två tre fyra tjugo femton", "1 2 3 4 20 15");
+        assert_replace_all_numbers!("en katt", "1 katt");
+    }
+}
@@ -0,0 +1,5 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "och", "eller", "så", "alltså", "liksom", "typ", "ja", "nej", "okej", "eh", "öh", "hm", "plus", "minus", "med"
+};
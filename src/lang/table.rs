@@ -0,0 +1,598 @@
+//! Data-driven interpreter, whose vocabulary is read from a TOML or JSON definition.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+
+use super::{LangInterpreter, MorphologicalMarker};
+
+/// An ordinal suffix of the definition.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OrdinalSuffix {
+    suffix: String,
+    marker: String,
+    #[serde(default)]
+    cardinal_ending: String,
+}
+
+/// An irregular ordinal of the definition.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Ordinal {
+    cardinal: String,
+    marker: String,
+}
+
+/// The declarative definition, as written in the files.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Definition {
+    units: HashMap<String, u8>,
+    teens: HashMap<String, u8>,
+    tens: HashMap<String, u8>,
+    multipliers: HashMap<String, usize>,
+    conjunctions: Vec<String>,
+    ordinal_suffixes: Vec<OrdinalSuffix>,
+    ordinals: HashMap<String, Ordinal>,
+    decimal_separators: HashMap<String, char>,
+    linking: Vec<String>,
+    compound_separator: Option<char>,
+}
+
+/// The meaning of a cardinal word.
+#[derive(Debug, Clone)]
+enum Numeral {
+    Unit(u8),
+    /// The teens and the tens
+    Digits(Vec<u8>),
+    /// A multiplier, with its position, and the last position below the next multiplier, if any
+    Multiplier(usize, Option<usize>),
+}
+
+/// Language interpreter built from a declarative definition.
+///
+/// It understands the languages that build their numbers like English: units, teens and tens words
+/// that fill the lowest positions, and multipliers that shift what was said before them. It allows prototyping a dialect
+/// or a new language without recompiling, before writing a dedicated interpreter.
+///
+/// The definition has the following entries, all optional:
+///
+/// - `units`: the words of the digits, from 0 to 9;
+/// - `teens`: the words of the numbers from 10 to 19;
+/// - `tens`: the words of the tens, from 20 to 90;
+/// - `multipliers`: the words that shift the number before them, with their position, like 3 for "*thousand*";
+/// - `conjunctions`: the words that may join the parts of a number, like "*and*";
+/// - `ordinal_suffixes`: the suffixes that turn a cardinal into an ordinal, with their `marker` kept on the digit form,
+///   and the `cardinal_ending` that the suffix replaces, if any;
+/// - `ordinals`: the irregular ordinals, with their `cardinal` and their `marker`;
+/// - `decimal_separators`: the decimal separator words, with the symbol they are figured as;
+/// - `linking`: the words that don't break a sequence of numbers, like "*uh*";
+/// - `compound_separator`: the character that joins the words of a compound, like `-` in "*twenty-one*".
+///
+/// As the ordinal markers of the digit forms are static, the marker suffixes are leaked once per loaded definition:
+/// load a language once and keep its interpreter.
+///
+/// ```rust
+/// use text2num::lang::TableInterpreter;
+/// use text2num::{replace_numbers_in_text, text2digits};
+///
+/// let lang = TableInterpreter::from_toml(r#"
+///     conjunctions = ["and"]
+///     linking = ["uh"]
+///     compound_separator = "-"
+///
+///     [units]
+///     one = 1
+///     two = 2
+///     five = 5
+///
+///     [tens]
+///     twenty = 20
+///
+///     [multipliers]
+///     hundred = 2
+///     thousand = 3
+///
+///     [[ordinal_suffixes]]
+///     suffix = "th"
+///     marker = "th"
+///
+///     [ordinals]
+///     first = { cardinal = "one", marker = "st" }
+/// "#).unwrap();
+///
+/// assert_eq!(text2digits("two thousand five hundred and twenty-one", &lang).unwrap(), "2521");
+/// assert_eq!(replace_numbers_in_text("the twenty-first day", &lang, 10.0), "the 21st day");
+/// ```
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "Definition")]
+pub struct TableInterpreter {
+    cardinals: HashMap<String, Numeral>,
+    ordinals: HashMap<String, (String, &'static str)>,
+    /// The ordinal suffixes, longest first, with their marker and the cardinal ending they replace
+    ordinal_suffixes: Vec<(String, &'static str, String)>,
+    conjunctions: HashSet<String>,
+    decimal_separators: HashMap<String, char>,
+    linking: HashSet<String>,
+    compound_separator: Option<char>,
+}
+
+impl TryFrom<Definition> for TableInterpreter {
+    type Error = String;
+
+    fn try_from(definition: Definition) -> Result<Self, Self::Error> {
+        let mut cardinals = HashMap::new();
+        let mut insert =
+            |word: String, numeral: Numeral| match cardinals.insert(word.to_lowercase(), numeral) {
+                Some(_) => Err(format!("\"{word}\" is defined twice")),
+                None => Ok(()),
+            };
+        for (word, value) in definition.units {
+            if value > 9 {
+                return Err(format!("the unit \"{word}\" is not between 0 and 9"));
+            }
+            insert(word, Numeral::Unit(b'0' + value))?;
+        }
+        for (word, value) in definition.teens {
+            if !(10..=19).contains(&value) {
+                return Err(format!("the teen \"{word}\" is not between 10 and 19"));
+            }
+            insert(word, Numeral::Digits(value.to_string().into_bytes()))?;
+        }
+        for (word, value) in definition.tens {
+            if !(20..=90).contains(&value) || value % 10 != 0 {
+                return Err(format!(
+                    "the tens \"{word}\" is not a multiple of 10 from 20 to 90"
+                ));
+            }
+            insert(word, Numeral::Digits(value.to_string().into_bytes()))?;
+        }
+        // A multiplier must leave the positions of the next multiplier free
+        let positions: BTreeSet<usize> = definition.multipliers.values().copied().collect();
+        for (word, position) in definition.multipliers {
+            if position == 0 {
+                return Err(format!("the multiplier \"{word}\" has no position"));
+            }
+            let end = positions.range(position + 1..).next().map(|next| next - 1);
+            insert(word, Numeral::Multiplier(position, end))?;
+        }
+        // The markers are static: each distinct suffix of the table is leaked once
+        let mut markers: HashMap<String, &'static str> = HashMap::new();
+        let mut intern = |marker: String| {
+            *markers
+                .entry(marker)
+                .or_insert_with_key(|marker| Box::leak(marker.clone().into_boxed_str()))
+        };
+        let mut ordinals = HashMap::new();
+        for (word, ordinal) in definition.ordinals {
+            let cardinal = ordinal.cardinal.to_lowercase();
+            if !cardinals.contains_key(&cardinal) {
+                return Err(format!(
+                    "the cardinal \"{cardinal}\" of the ordinal \"{word}\" is not defined"
+                ));
+            }
+            ordinals.insert(word.to_lowercase(), (cardinal, intern(ordinal.marker)));
+        }
+        let mut ordinal_suffixes: Vec<_> = definition
+            .ordinal_suffixes
+            .into_iter()
+            .map(|suffix| {
+                (
+                    suffix.suffix.to_lowercase(),
+                    intern(suffix.marker),
+                    suffix.cardinal_ending.to_lowercase(),
+                )
+            })
+            .collect();
+        ordinal_suffixes.sort_by_key(|(suffix, _, _)| std::cmp::Reverse(suffix.len()));
+        Ok(Self {
+            cardinals,
+            ordinals,
+            ordinal_suffixes,
+            conjunctions: lowercase(definition.conjunctions),
+            decimal_separators: definition
+                .decimal_separators
+                .into_iter()
+                .map(|(word, separator)| (word.to_lowercase(), separator))
+                .collect(),
+            linking: lowercase(definition.linking),
+            compound_separator: definition.compound_separator,
+        })
+    }
+}
+
+fn lowercase(words: Vec<String>) -> HashSet<String> {
+    words.into_iter().map(|word| word.to_lowercase()).collect()
+}
+
+impl TableInterpreter {
+    /// Load the definition from a TOML document.
+    pub fn from_toml(definition: &str) -> Result<Self, TableError> {
+        toml::from_str(definition).map_err(TableError::Toml)
+    }
+
+    /// Load the definition from a JSON document.
+    pub fn from_json(definition: &str) -> Result<Self, TableError> {
+        serde_json::from_str(definition).map_err(TableError::Json)
+    }
+
+    /// Load the definition from a `.toml` or `.json` file.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, TableError> {
+        let path = path.as_ref();
+        let definition = std::fs::read_to_string(path).map_err(TableError::Io)?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => Self::from_toml(&definition),
+            Some("json") => Self::from_json(&definition),
+            _ => Err(TableError::UnknownFormat),
+        }
+    }
+
+    /// The cardinal of `word` if it is an ordinal, with its marker.
+    fn ordinal(&self, word: &str) -> Option<(String, &'static str)> {
+        if let Some((cardinal, marker)) = self.ordinals.get(word) {
+            return Some((cardinal.clone(), marker));
+        }
+        self.ordinal_suffixes
+            .iter()
+            .find_map(|(suffix, marker, ending)| {
+                let cardinal = format!("{}{ending}", word.strip_suffix(suffix.as_str())?);
+                self.cardinals
+                    .contains_key(&cardinal)
+                    .then_some((cardinal, *marker))
+            })
+    }
+}
+
+impl LangInterpreter for TableInterpreter {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        if let Some(separator) = self.compound_separator
+            && num_func.contains(separator)
+        {
+            return match self.exec_group(num_func.split(separator)) {
+                Ok(ds) => {
+                    // put alone would allow "14000" inside "200000"
+                    if ds.len() > 3 && ds.len() <= 6 && !b.is_range_free(3, 5) {
+                        return Err(Error::Overlap);
+                    }
+                    b.put(&ds)?;
+                    if ds.marker.is_ordinal() {
+                        b.marker = ds.marker;
+                        b.freeze()
+                    }
+                    Ok(())
+                }
+                Err(err) => Err(err),
+            };
+        }
+        let ordinal = self.ordinal(num_func);
+        let cardinal = ordinal.as_ref().map_or(num_func, |(cardinal, _)| cardinal);
+        match self.cardinals.get(cardinal) {
+            Some(Numeral::Unit(b'0')) => b.put(b"0"),
+            // "ten five" is not a number
+            Some(Numeral::Unit(digit)) if b.peek(2) != b"10" => b.put(&[*digit]),
+            Some(Numeral::Digits(digits)) => b.put(digits),
+            Some(&Numeral::Multiplier(position, end)) => match end {
+                // Nothing to multiply in the positions below
+                Some(end) if end == position && !b.is_null() && b.is_free(position) => {
+                    Err(Error::Overlap)
+                }
+                // The positions up to the next multiplier must stay free
+                Some(end) if end > position && !b.is_range_free(position, end) => {
+                    Err(Error::Overlap)
+                }
+                _ => b.shift(position),
+            },
+            None if ordinal.is_none() && self.conjunctions.contains(cardinal) && b.len() >= 2 => {
+                Err(Error::Incomplete)
+            }
+            _ => Err(Error::NaN),
+        }?;
+        if let Some((_, marker)) = ordinal {
+            b.marker = MorphologicalMarker::Ordinal(marker);
+            b.freeze();
+        }
+        Ok(())
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        match self.cardinals.get(decimal_func) {
+            Some(Numeral::Unit(digit)) => b.push(&[*digit]),
+            _ => Err(Error::NaN),
+        }
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        self.decimal_separators.get(word).copied()
+    }
+
+    fn format_and_value(&self, b: &DigitString) -> (String, f64) {
+        let repr = b.to_string();
+        let val: f64 = repr.parse().unwrap();
+        if let MorphologicalMarker::Ordinal(marker) = b.marker {
+            (format!("{repr}{marker}"), val)
+        } else {
+            (repr, val)
+        }
+    }
+
+    fn format_decimal_and_value(
+        &self,
+        int: &DigitString,
+        dec: &DigitString,
+        sep: char,
+    ) -> (String, f64) {
+        let irepr = int.to_string();
+        let drepr = dec.to_string();
+        let frepr = format!("{irepr}{sep}{drepr}");
+        let val = format!("{irepr}.{drepr}").parse().unwrap();
+        (frepr, val)
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        // Only the last part of a compound is ordinal
+        let last = match self.compound_separator {
+            Some(separator) => word.rsplit(separator).next().unwrap_or(word),
+            None => word,
+        };
+        match self.ordinal(last) {
+            Some((_, marker)) => MorphologicalMarker::Ordinal(marker),
+            None => MorphologicalMarker::None,
+        }
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        self.linking.contains(word)
+    }
+}
+
+/// Failure to load a [`TableInterpreter`] definition.
+#[derive(Debug)]
+pub enum TableError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The TOML definition is malformed or inconsistent
+    Toml(toml::de::Error),
+    /// The JSON definition is malformed or inconsistent
+    Json(serde_json::Error),
+    /// The file is neither `.toml` nor `.json`
+    UnknownFormat,
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "cannot read the definition: {error}"),
+            Self::Toml(error) => write!(f, "invalid TOML definition: {error}"),
+            Self::Json(error) => write!(f, "invalid JSON definition: {error}"),
+            Self::UnknownFormat => {
+                f.write_str("the definition is neither a .toml nor a .json file")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TableError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Toml(error) => Some(error),
+            Self::Json(error) => Some(error),
+            Self::UnknownFormat => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TableError, TableInterpreter};
+    use crate::lang::{English, MorphologicalMarker, OwnedMarker};
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    /// The definition of English, as far as a table can tell.
+    const ENGLISH: &str = r#"
+        conjunctions = ["and"]
+        linking = ["and", "ha", "ah", "hu", "hum", "more", "ok", "so", "that's", "then", "uh", "well", "yeah", "yes", "is"]
+        compound_separator = "-"
+
+        [units]
+        zero = 0
+        one = 1
+        two = 2
+        three = 3
+        four = 4
+        five = 5
+        six = 6
+        seven = 7
+        eight = 8
+        nine = 9
+
+        [teens]
+        ten = 10
+        eleven = 11
+        twelve = 12
+        thirteen = 13
+        fourteen = 14
+        fifteen = 15
+        sixteen = 16
+        seventeen = 17
+        eighteen = 18
+        nineteen = 19
+
+        [tens]
+        twenty = 20
+        thirty = 30
+        forty = 40
+        fifty = 50
+        sixty = 60
+        seventy = 70
+        eighty = 80
+        ninety = 90
+
+        [multipliers]
+        hundred = 2
+        hundreds = 2
+        thousand = 3
+        thousands = 3
+        million = 6
+        millions = 6
+        billion = 9
+        billions = 9
+
+        [[ordinal_suffixes]]
+        suffix = "th"
+        marker = "th"
+
+        [[ordinal_suffixes]]
+        suffix = "ieth"
+        marker = "th"
+        cardinal_ending = "y"
+
+        [ordinals]
+        first = { cardinal = "one", marker = "st" }
+        second = { cardinal = "two", marker = "nd" }
+        third = { cardinal = "three", marker = "rd" }
+        fifth = { cardinal = "five", marker = "th" }
+        eighth = { cardinal = "eight", marker = "th" }
+        ninth = { cardinal = "nine", marker = "th" }
+        twelfth = { cardinal = "twelve", marker = "th" }
+
+        [decimal_separators]
+        point = "."
+    "#;
+
+    #[test]
+    fn test_against_english() {
+        let table = TableInterpreter::from_toml(ENGLISH).unwrap();
+        let english = English::new();
+        for text in [
+            "twenty-five cows, twelve chickens and one hundred twenty five kg of potatoes.",
+            "one thousand two hundred and sixty six dollars.",
+            "fifty-three billion two hundred forty-three thousand seven hundred twenty-four",
+            "fifty-one million five hundred seventy-eight thousand three hundred two",
+            "nineteen hundred seventy-three and forty five hundred thirty eight",
+            "Fifth third twenty-first hundredth one thousand two hundred thirtieth.",
+            "first, third, fourth, fifth, sixth, seventh, eighth, ninth, tenth.",
+            "twelve point nine nine, one hundred twenty point zero five",
+            "one two three four twenty fifteen",
+            "I want five hundred and sixty six rupees",
+            "thirteen thousand zero ninety",
+            "four and five so eleven then three uh six uh well seven",
+        ] {
+            assert_eq!(
+                replace_numbers_in_text(text, &table, 10.0),
+                replace_numbers_in_text(text, &english, 10.0),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        let table = TableInterpreter::from_toml(ENGLISH).unwrap();
+        for text in [
+            "thousand thousand two hundreds",
+            "sixty fifteen",
+            "hundred hundreds",
+            "ten five",
+            "twentieth two",
+        ] {
+            assert!(text2digits(text, &table).is_err(), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_json() {
+        let table = TableInterpreter::from_json(
+            r#"{
+                "units": {"un": 1, "deux": 2, "cinq": 5},
+                "teens": {"dix": 10},
+                "tens": {"vingt": 20, "trente": 30},
+                "multipliers": {"cent": 2, "cents": 2, "mille": 3, "million": 6},
+                "conjunctions": ["et"],
+                "ordinal_suffixes": [{"suffix": "ième", "marker": "e"}],
+                "ordinals": {"premier": {"cardinal": "un", "marker": "er"}},
+                "decimal_separators": {"virgule": ","},
+                "compound_separator": "-"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            text2digits("deux mille cinq cents", &table).unwrap(),
+            "2500"
+        );
+        assert_eq!(text2digits("trente et un", &table).unwrap(), "31");
+        assert_eq!(text2digits("vingt-deuxième", &table).unwrap(), "22e");
+        assert_eq!(text2digits("premier", &table).unwrap(), "1er");
+        assert_eq!(
+            replace_numbers_in_text("vingt virgule cinq", &table, 10.0),
+            "20,5"
+        );
+    }
+
+    #[test]
+    fn test_markers() {
+        let table = TableInterpreter::from_json(
+            r#"{"units": {"un": 1, "deux": 2}, "ordinals": {"première": {"cardinal": "un", "marker": "ʳᵉ"}},
+                "ordinal_suffixes": [{"suffix": "ième", "marker": "ᵉ"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(text2digits("première", &table).unwrap(), "1ʳᵉ");
+        assert_eq!(text2digits("deuxième", &table).unwrap(), "2ᵉ");
+        let marker: OwnedMarker = serde_json::from_str(r#"{"Ordinal":"ʳᵉ"}"#).unwrap();
+        assert_eq!(marker, MorphologicalMarker::Ordinal("ʳᵉ"));
+    }
+
+    #[test]
+    fn test_definition_errors() {
+        assert!(matches!(
+            TableInterpreter::from_toml("[units]\neleven = 11"),
+            Err(TableError::Toml(_))
+        ));
+        assert!(matches!(
+            TableInterpreter::from_json(r#"{"tens": {"twenty": 25}}"#),
+            Err(TableError::Json(_))
+        ));
+        assert!(matches!(
+            TableInterpreter::from_json(r#"{"units": {"one": 1}, "teens": {"one": 11}}"#),
+            Err(TableError::Json(_))
+        ));
+        assert!(matches!(
+            TableInterpreter::from_json(
+                r#"{"ordinals": {"first": {"cardinal": "one", "marker": "st"}}}"#
+            ),
+            Err(TableError::Json(_))
+        ));
+        assert!(matches!(
+            TableInterpreter::from_json(r#"{"unknown": []}"#),
+            Err(TableError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_path() {
+        let dir = std::env::temp_dir().join(format!("text2num-tables-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let json = dir.join("definition.json");
+        std::fs::write(&json, r#"{"units": {"one": 1}}"#).unwrap();
+        let yaml = dir.join("definition.yaml");
+        std::fs::write(&yaml, "units:\n  one: 1\n").unwrap();
+
+        let table = TableInterpreter::from_path(&json).unwrap();
+        assert_eq!(text2digits("one", &table).unwrap(), "1");
+        assert!(matches!(
+            TableInterpreter::from_path(&yaml),
+            Err(TableError::UnknownFormat)
+        ));
+        assert!(matches!(
+            TableInterpreter::from_path(dir.join("missing.json")),
+            Err(TableError::Io(_))
+        ));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,346 @@
+//! Turkish number interpreter
+//!
+//! Turkish numbers are strictly additive, and their words may be written solid, "yirmibeş", or apart, "yirmi beş".
+//! Ordinals are written with a final dot: "*beşinci*" becomes "*5.*".
+
+use std::borrow::Cow;
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+use crate::tokenizer::WordSplitter;
+
+mod vocabulary;
+
+use super::{LangInterpreter, MorphologicalMarker};
+use vocabulary::INSIGNIFICANT;
+
+/// The cardinals that can be part of a solid compound.
+const CARDINALS: [&str; 22] = [
+    "bir", "iki", "üç", "dört", "beş", "altı", "yedi", "sekiz", "dokuz", "on", "yirmi", "otuz",
+    "kırk", "elli", "altmış", "yetmiş", "seksen", "doksan", "yüz", "bin", "milyon", "milyar",
+];
+
+/// The ordinal suffixes, in all their vowel harmony variants.
+const ORDINAL_SUFFIXES: [&str; 8] = ["inci", "ıncı", "uncu", "üncü", "nci", "ncı", "ncu", "ncü"];
+
+/// The uppercase dotted I is lowercased with a combining dot.
+fn normalize(word: &str) -> Cow<'_, str> {
+    if word.contains('\u{307}') {
+        Cow::Owned(word.replace('\u{307}', ""))
+    } else {
+        Cow::Borrowed(word)
+    }
+}
+
+/// The cardinal an ordinal is built on.
+fn ordinal_stem(word: &str) -> Option<&str> {
+    ORDINAL_SUFFIXES.iter().find_map(|suffix| {
+        let stem = word.strip_suffix(suffix)?;
+        match stem {
+            // "dördüncü"
+            "dörd" => Some("dört"),
+            _ if CARDINALS.contains(&stem) => Some(stem),
+            _ => None,
+        }
+    })
+}
+
+pub struct Turkish {
+    word_splitter: WordSplitter,
+}
+
+impl Default for Turkish {
+    fn default() -> Self {
+        Self {
+            word_splitter: WordSplitter::new(CARDINALS.iter().copied().chain([
+                "birinci",
+                "ikinci",
+                "üçüncü",
+                "dördüncü",
+                "beşinci",
+                "altıncı",
+                "yedinci",
+                "sekizinci",
+                "dokuzuncu",
+                "onuncu",
+                "yirminci",
+                "otuzuncu",
+                "kırkıncı",
+                "ellinci",
+                "altmışıncı",
+                "yetmişinci",
+                "sekseninci",
+                "doksanıncı",
+                "yüzüncü",
+                "bininci",
+                "milyonuncu",
+                "milyarıncı",
+            ]))
+            .unwrap(),
+        }
+    }
+}
+
+impl Turkish {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    fn apply_cardinal(&self, word: &str, b: &mut DigitString) -> Result<(), Error> {
+        match word {
+            "sıfır" => b.put(b"0"),
+            "bir" => b.put(b"1"),
+            "iki" => b.put(b"2"),
+            "üç" => b.put(b"3"),
+            "dört" => b.put(b"4"),
+            "beş" => b.put(b"5"),
+            "altı" => b.put(b"6"),
+            "yedi" => b.put(b"7"),
+            "sekiz" => b.put(b"8"),
+            "dokuz" => b.put(b"9"),
+            "on" => b.put(b"10"),
+            "yirmi" => b.put(b"20"),
+            "otuz" => b.put(b"30"),
+            "kırk" => b.put(b"40"),
+            "elli" => b.put(b"50"),
+            "altmış" => b.put(b"60"),
+            "yetmiş" => b.put(b"70"),
+            "seksen" => b.put(b"80"),
+            "doksan" => b.put(b"90"),
+            "yüz" => {
+                // "iki yüz" multiplies, "bin yüz" adds
+                match b.peek(3) {
+                    [unit] | [b'0', unit] | [b'0', b'0', unit] if *unit >= b'2' => b.shift(2),
+                    _ => b.put(b"100"),
+                }
+            }
+            "bin" if b.is_range_free(3, 5) => b.shift(3),
+            "milyon" if b.is_range_free(6, 8) => b.shift(6),
+            "milyar" if b.is_range_free(9, 11) => b.shift(9),
+            "trilyon" => b.shift(12),
+            _ => Err(Error::NaN),
+        }
+    }
+}
+
+impl LangInterpreter for Turkish {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        let word = normalize(num_func);
+        let word = word.as_ref();
+        // Turkish numbers may be written solid
+        if self.word_splitter.is_splittable(word) {
+            return match self.exec_group(self.word_splitter.split(word)) {
+                Ok(ds) => {
+                    if ds.len() > 3 && ds.len() <= 6 && !b.is_range_free(3, 5) {
+                        return Err(Error::Overlap);
+                    }
+                    b.put(&ds)?;
+                    if ds.marker.is_ordinal() {
+                        b.marker = ds.marker;
+                        b.freeze()
+                    }
+                    Ok(())
+                }
+                Err(err) => Err(err),
+            };
+        }
+        match ordinal_stem(word) {
+            Some(cardinal) => {
+                self.apply_cardinal(cardinal, b)?;
+                b.marker = MorphologicalMarker::Ordinal(".");
+                b.freeze();
+                Ok(())
+            }
+            None => self.apply_cardinal(word, b),
+        }
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        self.apply(decimal_func, b)
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        match word {
+            "virgül" => Some(','),
+            "nokta" => Some('.'),
+            _ => None,
+        }
+    }
+
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "eksi" => Some('-'),
+            "artı" => Some('+'),
+            _ => None,
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn format_and_value(&self, b: &DigitString) -> (String, f64) {
+        let repr = b.to_string();
+        let val: f64 = repr.parse().unwrap();
+        if let MorphologicalMarker::Ordinal(marker) = b.marker {
+            (format!("{repr}{marker}"), val)
+        } else {
+            (repr, val)
+        }
+    }
+
+    fn format_decimal_and_value(
+        &self,
+        int: &DigitString,
+        dec: &DigitString,
+        sep: char,
+    ) -> (String, f64) {
+        let irepr = int.to_string();
+        let drepr = dec.to_string();
+        let frepr = format!("{irepr}{sep}{drepr}");
+        let val = format!("{irepr}.{drepr}").parse().unwrap();
+        (frepr, val)
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        let word = normalize(word);
+        // Only the last part of a compound is ordinal
+        let last = self.word_splitter.split(&word).last().map(ordinal_stem);
+        if let Some(Some(_)) = last {
+            MorphologicalMarker::Ordinal(".")
+        } else {
+            MorphologicalMarker::None
+        }
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Turkish;
+    use crate::ConversionOptions;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Turkish::new();
+            let res = text2digits($text, &f);
+            dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Turkish::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Turkish::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Turkish::new();
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("sıfır", "0");
+        assert_text2digits!("on bir", "11");
+        assert_text2digits!("yirmi beş", "25");
+        assert_text2digits!("yirmibeş", "25");
+        assert_text2digits!("doksan dokuz", "99");
+        assert_text2digits!("yüz", "100");
+        assert_text2digits!("yüz on beş", "115");
+        assert_text2digits!("iki yüz otuz", "230");
+        assert_text2digits!("ikiyüzotuz", "230");
+        assert_text2digits!("bin", "1000");
+        assert_text2digits!("bin yüz", "1100");
+        assert_text2digits!("bin dokuz yüz seksen dört", "1984");
+        assert_text2digits!("bindokuzyüzseksendört", "1984");
+        assert_text2digits!("iki bin iki yüz", "2200");
+        assert_text2digits!("yüz bin", "100000");
+        assert_text2digits!(
+            "elli üç milyar iki yüz kırk üç bin yedi yüz yirmi dört",
+            "53000243724"
+        );
+        assert_text2digits!("bir milyon", "1000000");
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_text2digits!("birinci", "1.");
+        assert_text2digits!("ikinci", "2.");
+        assert_text2digits!("üçüncü", "3.");
+        assert_text2digits!("dördüncü", "4.");
+        assert_text2digits!("altıncı", "6.");
+        assert_text2digits!("dokuzuncu", "9.");
+        assert_text2digits!("onuncu", "10.");
+        assert_text2digits!("kırkıncı", "40.");
+        assert_text2digits!("yirmi beşinci", "25.");
+        assert_text2digits!("yirmibeşinci", "25.");
+        assert_text2digits!("yüzüncü", "100.");
+        assert_text2digits!("bininci", "1000.");
+        assert_replace_numbers!(
+            "Cumhuriyetin yüzüncü yılı, yirmi dokuzuncu Ekim",
+            "Cumhuriyetin 100. yılı, 29. Ekim"
+        );
+    }
+
+    #[test]
+    fn test_decimals() {
+        assert_replace_numbers!("on iki virgül beş", "12,5");
+        assert_replace_numbers!("sıfır virgül sıfır beş", "0,05");
+    }
+
+    #[test]
+    fn test_uppercase() {
+        assert_replace_numbers!("İKİ YÜZ", "200");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("bin bin iki yüz");
+        assert_invalid!("altmış on beş");
+        assert_invalid!("yüz yüz");
+        assert_invalid!("beş yirmi");
+        assert_invalid!("birinci iki");
+        assert_invalid!("bonbon");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!(
+            "yirmi beş inek, on iki tavuk ve yüz yirmi beş kilo patates.",
+            "25 inek, 12 tavuk ve 125 kilo patates."
+        );
+        assert_replace_numbers!("bir iki üç dört yirmi on beş", "1 2 3 4 20 15");
+        assert_replace_all_numbers!("bir kedi", "1 kedi");
+        assert_replace_numbers!("bir kedi", "bir kedi");
+    }
+
+    #[test]
+    fn test_fractions_unsupported() {
+        // Fraction words are not known yet, so they stay as words even when fractions are converted.
+        let f = Turkish::new();
+        let options = ConversionOptions::from(0.0).convert_fractions(true);
+        assert_eq!(replace_numbers_in_text("iki buçuk", &f, options), "2 buçuk");
+        assert_eq!(
+            replace_numbers_in_text("üç çeyrek", &f, options),
+            "3 çeyrek"
+        );
+    }
+}
@@ -0,0 +1,5 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "ve", "ile", "yani", "şey", "eee", "hmm", "tamam", "evet", "hayır", "artı", "eksi", "sonra", "da", "de"
+};
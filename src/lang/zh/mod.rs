@@ -0,0 +1,242 @@
+//! Chinese number interpreter
+//!
+//! The numbers are grouped by myriads, "二十三万四千" is 234000, and the digits after the last multiplier may be
+//! abbreviated: "三千五" is 3500 and "一万五" is 15000. A zero stands for the missing positions, "一千零五" is 1005.
+//! Both the simplified and the traditional characters are understood, as well as the financial numerals like "贰".
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+use crate::tokenizer::WordSplitter;
+
+mod vocabulary;
+
+use super::cjk::{self, Dialect, Numeral};
+use super::{LangInterpreter, MorphologicalMarker};
+use vocabulary::INSIGNIFICANT;
+
+pub struct Chinese {
+    word_splitter: WordSplitter,
+}
+
+impl Default for Chinese {
+    fn default() -> Self {
+        Self {
+            word_splitter: WordSplitter::new([
+                "零", "〇", "一", "壹", "二", "贰", "貳", "两", "兩", "三", "叁", "參", "四", "肆",
+                "五", "伍", "六", "陆", "陸", "七", "柒", "八", "捌", "九", "玖", "十", "拾", "百",
+                "佰", "千", "仟", "万", "萬", "亿", "億", "兆", "点", "點", "负", "負",
+            ])
+            .unwrap(),
+        }
+    }
+}
+
+impl Chinese {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl Dialect for Chinese {
+    const ABBREVIATED: bool = true;
+    const IDIOMS: &'static [&'static str] = &["万一", "千万", "万分"];
+
+    fn word_splitter(&self) -> &WordSplitter {
+        &self.word_splitter
+    }
+
+    fn numeral(&self, word: &str) -> Option<Numeral> {
+        let numeral = match word {
+            "零" | "〇" => Numeral::Zero,
+            "一" | "壹" => Numeral::Digit(b'1'),
+            "二" | "贰" | "貳" | "两" | "兩" => Numeral::Digit(b'2'),
+            "三" | "叁" | "參" => Numeral::Digit(b'3'),
+            "四" | "肆" => Numeral::Digit(b'4'),
+            "五" | "伍" => Numeral::Digit(b'5'),
+            "六" | "陆" | "陸" => Numeral::Digit(b'6'),
+            "七" | "柒" => Numeral::Digit(b'7'),
+            "八" | "捌" => Numeral::Digit(b'8'),
+            "九" | "玖" => Numeral::Digit(b'9'),
+            // "十五" is 15, but "百" and "千" need their count
+            "十" | "拾" => Numeral::Multiplier(1, true),
+            "百" | "佰" => Numeral::Multiplier(2, false),
+            "千" | "仟" => Numeral::Multiplier(3, false),
+            "万" | "萬" => Numeral::Myriad(4),
+            "亿" | "億" => Numeral::Myriad(8),
+            "兆" => Numeral::Myriad(12),
+            _ => return None,
+        };
+        Some(numeral)
+    }
+}
+
+impl LangInterpreter for Chinese {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        cjk::apply(self, num_func, b)
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        cjk::apply_decimal(self, decimal_func, b)
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        match word {
+            "点" | "點" => Some('.'),
+            _ => None,
+        }
+    }
+
+    fn check_sign(&self, word: &str) -> Option<char> {
+        match word {
+            "负" | "負" => Some('-'),
+            _ => None,
+        }
+    }
+
+    fn format_and_value(&self, b: &DigitString) -> (String, f64) {
+        cjk::format_and_value(b)
+    }
+
+    fn format_decimal_and_value(
+        &self,
+        int: &DigitString,
+        dec: &DigitString,
+        sep: char,
+    ) -> (String, f64) {
+        cjk::format_decimal_and_value(int, dec, sep)
+    }
+
+    fn get_morph_marker(&self, _word: &str) -> MorphologicalMarker {
+        cjk::get_morph_marker()
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(word)
+    }
+
+    fn segment<'a>(&'a self, word: &'a str) -> Option<Vec<&'a str>> {
+        cjk::segment(self, word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Chinese;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Chinese::new();
+            let res = text2digits($text, &f);
+            dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Chinese::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Chinese::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Chinese::new();
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("零", "0");
+        assert_text2digits!("七", "7");
+        assert_text2digits!("十", "10");
+        assert_text2digits!("十五", "15");
+        assert_text2digits!("二十", "20");
+        assert_text2digits!("九十九", "99");
+        assert_text2digits!("一百", "100");
+        assert_text2digits!("三百一十", "310");
+        assert_text2digits!("三千五百", "3500");
+        assert_text2digits!("一万", "10000");
+        assert_text2digits!("两万", "20000");
+        assert_text2digits!("二十三万四千", "234000");
+        assert_text2digits!("一亿二千万", "120000000");
+        assert_text2digits!("一千二百三十四万五千六百七十八", "12345678");
+        assert_text2digits!("三兆", "3000000000000");
+    }
+
+    #[test]
+    fn test_zero() {
+        assert_text2digits!("一千零五", "1005");
+        assert_text2digits!("一万零三百", "10300");
+        assert_text2digits!("一亿零五万", "100050000");
+        assert_text2digits!("一百零一", "101");
+    }
+
+    #[test]
+    fn test_abbreviated() {
+        assert_text2digits!("三千五", "3500");
+        assert_text2digits!("一万五", "15000");
+        assert_text2digits!("两百五", "250");
+        assert_text2digits!("一百一", "110");
+    }
+
+    #[test]
+    fn test_traditional() {
+        assert_text2digits!("兩萬", "20000");
+        assert_text2digits!("壹仟贰佰", "1200");
+        assert_text2digits!("參億", "300000000");
+    }
+
+    #[test]
+    fn test_sequence() {
+        assert_text2digits!("二〇二四", "2024");
+        assert_text2digits!("一九八四", "1984");
+        assert_replace_numbers!("二〇二四年", "2024年");
+    }
+
+    #[test]
+    fn test_decimals() {
+        assert_replace_numbers!("三点一四", "3.14");
+        assert_replace_numbers!("零点五", "0.5");
+        assert_replace_numbers!("十二点五度", "12.5度");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("万");
+        assert_invalid!("百");
+        assert_invalid!("十百");
+        assert_invalid!("二百三百");
+        assert_invalid!("五万五万");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!("我有二十三万四千元", "我有234000元");
+        assert_replace_numbers!("他买了三千五百本书", "他买了3500本书");
+        assert_replace_numbers!("万一下雨", "万一下雨");
+        assert_replace_all_numbers!("三个苹果和五个梨", "3个苹果和5个梨");
+    }
+
+    #[test]
+    fn test_idioms() {
+        assert_replace_numbers!("他十分高兴", "他十分高兴");
+        assert_replace_all_numbers!("万一下雨", "万一下雨");
+        assert_replace_all_numbers!("千万不要忘记", "千万不要忘记");
+        assert_replace_all_numbers!("万分感谢", "万分感谢");
+        assert_replace_numbers!("十五个人", "15个人");
+        assert_replace_numbers!("三千万人", "30000000人");
+        assert_replace_numbers!("一万人", "10000人");
+    }
+}
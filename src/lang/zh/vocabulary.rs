@@ -0,0 +1,5 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "和", "加", "减", "是", "嗯", "啊", "呃", "到", "至"
+};
@@ -67,7 +67,7 @@ assert!(text2digits("fifty-five", &en).is_ok());
 
 # Example: find and replace numbers in a natural speech string.
 
-Most often, you just want to rewrite a string containing natural speech so that the numbers it contains (cardinals,
+Most often, you just want to rewrite a string containing natural speech so that the numbers it contains (cardinals, fractions,
 ordinals, decimal numbers) appear in digit (base 10) form instead.
 
 As isolated smaller numbers may be easier to read in plain text, you can specify a threshold under which isolated simple cardinals and ordinals are
@@ -91,6 +91,24 @@ assert_eq!(
 );
 ```
 
+Finer control over what is converted is given by [`ConversionOptions`]:
+
+```rust
+use text2num::{ConversionOptions, Language, replace_numbers_in_text};
+
+let en = Language::english();
+
+let options = ConversionOptions::new()
+    .cardinal_threshold(10.0)
+    .convert_ordinals(false)
+    .keep_leading_zeros(false);
+
+assert_eq!(
+    replace_numbers_in_text("the first room is at zero seven, the second at one hundred", &en, options),
+    "the first room is at 7, the second at 100"
+);
+```
+
 # More advanced usage: operations on token streams.
 
 Among the real life applications of this library are the post-processing of Automatic Speech Recognition (ASR)
@@ -201,34 +219,75 @@ assert_eq!(found.end, 4);
 // Match values
 assert_eq!(found.text, "24");
 assert_eq!(found.value, 24.0);
+assert_eq!(found.exact_value.as_ref().unwrap().to_i128(), Some(24));
 assert!(!found.is_ordinal);
 ```
 
+# Example: spell out numbers.
+
+The builtin languages also implement the [`Verbalize`] trait, that does the opposite conversion.
+
+```rust
+use text2num::{Gender, Language, Verbalize, text2digits};
+
+let es = Language::spanish();
+
+let words = es.verbalize(85).unwrap();
+assert_eq!(words, "ochenta y cinco");
+assert_eq!(text2digits(&words, &es).unwrap(), "85");
+
+assert_eq!(es.verbalize_ordinal(3, Gender::Feminine, true).unwrap(), "terceras");
+```
+
 
 */
 
+pub mod decimal;
 pub mod digit_string;
 pub mod error;
 pub mod lang;
+mod options;
+mod timed_token;
 mod tokenizer;
+pub mod trace;
 pub mod word_to_digit;
 
-pub use lang::{BasicAnnotate, LangInterpreter, Language};
+pub use decimal::Decimal;
+pub use lang::{BasicAnnotate, Gender, LangInterpreter, Language, Verbalize};
+pub use options::ConversionOptions;
+pub use timed_token::TimedToken;
 pub use word_to_digit::{
-    Occurence, Replace, Token, find_numbers, find_numbers_iter, replace_numbers_in_stream,
-    replace_numbers_in_text, text2digits,
+    Occurence, OccurenceKind, Replace, TextOccurence, Token, find_numbers, find_numbers_in_text,
+    find_numbers_iter, replace_numbers_in_stream, replace_numbers_in_text, text2digits,
+    trace_numbers_in_text,
 };
 
 /// Get an interpreter for the language represented by the `language_code` ISO code.
 pub fn get_interpreter_for(language_code: &str) -> Option<Language> {
     match language_code {
+        "ar" => Some(Language::arabic()),
+        "ca" => Some(Language::catalan()),
+        "cs" => Some(Language::czech()),
+        "da" => Some(Language::danish()),
         "de" => Some(Language::german()),
+        "el" => Some(Language::greek()),
         "en" => Some(Language::english()),
+        "en-IN" => Some(Language::indian_english()),
         "es" => Some(Language::spanish()),
         "fr" => Some(Language::french()),
+        "hi" => Some(Language::hindi()),
         "it" => Some(Language::italian()),
+        "ja" => Some(Language::japanese()),
+        "nb" | "no" => Some(Language::norwegian()),
         "nl" => Some(Language::dutch()),
+        "pl" => Some(Language::polish()),
         "pt" => Some(Language::portuguese()),
+        "ro" => Some(Language::romanian()),
+        "ru" => Some(Language::russian()),
+        "sk" => Some(Language::slovak()),
+        "sv" => Some(Language::swedish()),
+        "tr" => Some(Language::turkish()),
+        "zh" => Some(Language::chinese()),
         _ => None,
     }
 }
@@ -0,0 +1,158 @@
+/*!
+Conversion options for the search and replace functions.
+
+*/
+
+/**
+Settings of the number search and replacement functions, built with chained setters.
+
+The *lone number* policy concerns numbers that are isolated — that is, surrounded by significant
+non-number words: as smaller isolated numbers may be easier to read in plain text (or may not be numbers at all,
+like the French "*un*"), single digit cardinals and ordinals under their respective threshold are ignored.
+
+The default options convert everything, except the sign words and the fractions, which are opt-in.
+
+# Example
+
+```rust
+use text2num::{ConversionOptions, Language, replace_numbers_in_text};
+
+let en = Language::english();
+let options = ConversionOptions::new()
+    .cardinal_threshold(10.0)
+    .convert_ordinals(false);
+
+assert_eq!(
+    replace_numbers_in_text("the first two rooms and twenty-one chairs", &en, options),
+    "the first two rooms and 21 chairs"
+);
+```
+
+For backward compatibility, a bare `f64` converts into options where it is both the cardinal and ordinal threshold.
+
+With the `serde` feature, the options (de)serialize as a map of the setting names to their values, like
+`{"ordinals": true, "cardinal_threshold": 10.0}`; the missing settings take their default values.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct ConversionOptions {
+    pub(crate) ordinals: bool,
+    pub(crate) decimals: bool,
+    pub(crate) leading_zeros: bool,
+    pub(crate) cardinal_threshold: f64,
+    pub(crate) ordinal_threshold: f64,
+    pub(crate) lists_as_isolated: bool,
+    pub(crate) signs: bool,
+    pub(crate) fractions: bool,
+    pub(crate) fractions_as_decimals: bool,
+}
+
+impl Default for ConversionOptions {
+    fn default() -> Self {
+        Self {
+            ordinals: true,
+            decimals: true,
+            leading_zeros: true,
+            cardinal_threshold: 0.0,
+            ordinal_threshold: 0.0,
+            lists_as_isolated: false,
+            signs: false,
+            fractions: false,
+            fractions_as_decimals: false,
+        }
+    }
+}
+
+impl ConversionOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Whether ordinals are converted (default `true`). If not, they are left as words.
+    pub fn convert_ordinals(mut self, yes: bool) -> Self {
+        self.ordinals = yes;
+        self
+    }
+
+    /// Whether decimal numbers are converted as a whole (default `true`).
+    ///
+    /// If not, the decimal separator word is not interpreted and the integral and decimal parts
+    /// are considered as distinct numbers.
+    pub fn convert_decimals(mut self, yes: bool) -> Self {
+        self.decimals = yes;
+        self
+    }
+
+    /// Whether the spoken leading zeros are kept in the digit representation (default `true`),
+    /// e.g. "*zero seven*" gives "07" instead of "7".
+    pub fn keep_leading_zeros(mut self, yes: bool) -> Self {
+        self.leading_zeros = yes;
+        self
+    }
+
+    /// Isolated single digit cardinals strictly under `threshold` are not converted (default `0.0`).
+    pub fn cardinal_threshold(mut self, threshold: f64) -> Self {
+        self.cardinal_threshold = threshold;
+        self
+    }
+
+    /// Isolated ordinals strictly under `threshold` are not converted (default `0.0`).
+    pub fn ordinal_threshold(mut self, threshold: f64) -> Self {
+        self.ordinal_threshold = threshold;
+        self
+    }
+
+    /// Set both the cardinal and the ordinal thresholds.
+    pub fn threshold(self, threshold: f64) -> Self {
+        self.cardinal_threshold(threshold)
+            .ordinal_threshold(threshold)
+    }
+
+    /// Whether the numbers of a list, like "*one, two, three*", are each considered isolated (default `false`).
+    ///
+    /// By default, a sequence of numbers of the same kind is not subject to the lone number policy.
+    pub fn lists_as_isolated(mut self, yes: bool) -> Self {
+        self.lists_as_isolated = yes;
+        self
+    }
+
+    /// Whether a sign word right before a number, like "*minus*", is part of the number (default `false`).
+    ///
+    /// Signed numbers are given in digits with their sign, e.g. "-15" or "+20", and are never considered isolated.
+    pub fn convert_signs(mut self, yes: bool) -> Self {
+        self.signs = yes;
+        self
+    }
+
+    /// Whether fractions, like "*three quarters*", and mixed numbers, like "*two and a half*", are converted
+    /// (default `false`).
+    ///
+    /// Fractions are given in digits like "3/4" or "2 1/2". As many fraction words also have an everyday meaning,
+    /// like "*un quart d'heure*" or "*ein halbes Jahr*", they are left as words by default.
+    ///
+    /// Only Catalan, Danish, Dutch, English, French, German, Italian, Norwegian, Polish, Portuguese, Romanian,
+    /// Russian, Spanish and Swedish know their fraction words so far. With the other languages, and with table
+    /// languages, this option has no effect: in Czech, "*tři čtvrtiny*" gives "3 čtvrtiny".
+    pub fn convert_fractions(mut self, yes: bool) -> Self {
+        self.fractions = yes;
+        self
+    }
+
+    /// Whether fractions are given as decimal numbers, like "2.5", rather than as fractions, like "2 1/2" (default `false`).
+    ///
+    /// Fractions without a finite decimal representation, like "1/3", are always given as fractions.
+    pub fn fractions_as_decimals(mut self, yes: bool) -> Self {
+        self.fractions_as_decimals = yes;
+        self
+    }
+}
+
+impl From<f64> for ConversionOptions {
+    fn from(threshold: f64) -> Self {
+        Self::new().threshold(threshold)
+    }
+}
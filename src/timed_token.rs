@@ -0,0 +1,118 @@
+/*!
+A ready-made token type for timed speech recognition output.
+
+*/
+use crate::word_to_digit::{Replace, Token};
+
+/**
+A word with its timing and recognition confidence, as output by an ASR engine.
+
+When numbers are substituted, the tokens of a number are merged into one spanning them all:
+its `start` is the earliest start, its `end` is the latest end and its `confidence` is the mean
+of the confidences of the merged tokens.
+
+# Example
+
+```rust
+use text2num::{Language, TimedToken, replace_numbers_in_stream};
+
+let en = Language::english();
+let words = vec![
+    TimedToken::new("I", 0.0, 0.1, 0.99),
+    TimedToken::new("have", 0.1, 0.3, 0.95),
+    TimedToken::new("twenty", 0.4, 0.7, 0.9),
+    TimedToken::new("four", 0.7, 0.9, 0.8),
+    TimedToken::new("dollars", 0.9, 1.3, 0.97),
+];
+
+let replaced = replace_numbers_in_stream(words, &en, 10.0);
+
+assert_eq!(replaced.len(), 4);
+assert_eq!(replaced[2].text(), "24");
+assert_eq!(replaced[2].start, 0.4);
+assert_eq!(replaced[2].end, 0.9);
+assert!((replaced[2].confidence - 0.85).abs() < 1e-9);
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimedToken {
+    text: String,
+    lowercase: String,
+    /// Start time (the unit is up to the caller)
+    pub start: f64,
+    /// End time
+    pub end: f64,
+    /// Recognition confidence
+    pub confidence: f64,
+}
+
+impl TimedToken {
+    pub fn new(text: &str, start: f64, end: f64, confidence: f64) -> Self {
+        Self {
+            text: text.to_owned(),
+            lowercase: text.to_lowercase(),
+            start,
+            end,
+            confidence,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl Token for &TimedToken {
+    fn text(&self) -> &str {
+        &self.text
+    }
+
+    fn text_lowercase(&self) -> &str {
+        &self.lowercase
+    }
+}
+
+impl Replace for TimedToken {
+    fn replace<I: Iterator<Item = Self>>(replaced: I, data: String) -> Self {
+        let mut merged = Self::new(&data, f64::INFINITY, f64::NEG_INFINITY, 0.0);
+        let mut count = 0;
+        for token in replaced {
+            merged.start = merged.start.min(token.start);
+            merged.end = merged.end.max(token.end);
+            merged.confidence += token.confidence;
+            count += 1;
+        }
+        if count > 0 {
+            merged.confidence /= count as f64;
+        }
+        merged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang::Language;
+    use crate::word_to_digit::replace_numbers_in_stream;
+
+    #[test]
+    fn test_merge() {
+        let fr = Language::french();
+        let words = vec![
+            TimedToken::new("Il", 0.0, 0.2, 1.0),
+            TimedToken::new("est", 0.2, 0.4, 1.0),
+            TimedToken::new("dix-huit", 0.5, 1.0, 0.6),
+            TimedToken::new("heures", 1.0, 1.3, 1.0),
+            TimedToken::new("trente", 1.4, 1.8, 0.9),
+            TimedToken::new("et", 1.8, 1.9, 0.7),
+            TimedToken::new("une", 1.9, 2.1, 0.8),
+        ];
+        let replaced = replace_numbers_in_stream(words, &fr, 10.0);
+        let texts: Vec<&str> = replaced.iter().map(TimedToken::text).collect();
+        assert_eq!(texts, ["Il", "est", "18", "heures", "31"]);
+        assert_eq!((replaced[2].start, replaced[2].end), (0.5, 1.0));
+        assert_eq!(replaced[2].confidence, 0.6);
+        assert_eq!((replaced[4].start, replaced[4].end), (1.4, 2.1));
+        assert!((replaced[4].confidence - 0.8).abs() < 1e-9);
+    }
+}
@@ -1,7 +1,7 @@
 //! Some tokenizers
 use daachorse::{
     CharwiseDoubleArrayAhoCorasick, CharwiseDoubleArrayAhoCorasickBuilder, MatchKind,
-    charwise::iter::LestmostFindIterator, errors::Result,
+    charwise::iter::LeftmostFindIterator, errors::Result,
 };
 
 #[derive(Debug)]
@@ -33,6 +33,11 @@ impl std::borrow::Borrow<str> for BasicToken {
     }
 }
 
+/// Is `c` a combining mark that is not alphabetic but still part of the word, like the Devanagari virama in "*पच्चीस*"?
+fn is_word_mark(c: char) -> bool {
+    matches!(c, '\u{93c}' | '\u{94d}')
+}
+
 /// Plain text tokenizer on word boundaries.
 #[derive(Debug)]
 pub struct Tokenize<'a> {
@@ -55,7 +60,7 @@ impl<'a> Tokenize<'a> {
     fn match_word(&mut self) -> usize {
         loop {
             if let Some((pos, c)) = self.chars.peek() {
-                if !(c.is_alphanumeric() || *c == '-' || *c == '\'') {
+                if !(c.is_alphanumeric() || is_word_mark(*c) || *c == '-' || *c == '\'') {
                     break *pos;
                 }
                 self.chars.next();
@@ -98,13 +103,13 @@ impl Iterator for Tokenize<'_> {
 
 pub struct WordSplitIterator<'a> {
     source: &'a str,
-    matches: LestmostFindIterator<'a, &'a str, usize>,
+    matches: LeftmostFindIterator<'a, &'a str, usize>,
     end: usize,
     cursor: usize,
 }
 
 impl<'a> WordSplitIterator<'a> {
-    fn new(source: &'a str, matches: LestmostFindIterator<'a, &'a str, usize>) -> Self {
+    fn new(source: &'a str, matches: LeftmostFindIterator<'a, &'a str, usize>) -> Self {
         Self {
             source,
             matches,
@@ -196,9 +201,17 @@ mod tests {
         assert_eq!(tokens[7].text, "!");
     }
 
+    #[test]
+    fn test_tokenizer_marks() {
+        let tokens: Vec<BasicToken> = Tokenize::new("पच्चीस हज़ार").collect();
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(tokens[0].text, "पच्चीस");
+        assert_eq!(tokens[2].text, "हज़ार");
+    }
+
     #[test]
     fn test_word_splitter() {
-        let german_splitter = WordSplitter::new(&[
+        let german_splitter = WordSplitter::new([
             "billion",
             "milliarde",
             "millionen",
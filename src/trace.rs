@@ -0,0 +1,236 @@
+/*!
+Parse traces, to explain why some words were (or were not) converted.
+
+A [`Trace`] records, step by step, what the number search did with each token: the result of the interpreter
+on each word, with the state of the number being built before and after, the detection of decimal separators and
+fractions, and the decisions about the found numbers (kept, put on hold by the *lone number* policy, dropped…).
+
+Tracing is opt-in: see [`trace_numbers_in_text`](crate::trace_numbers_in_text) and [`FindNumbers::traced`](crate::word_to_digit::FindNumbers::traced).
+With the `serde` feature, traces can be exported as structured data: each event is a map whose `event` field gives its kind.
+
+# Example
+
+```rust
+use text2num::{Language, trace_numbers_in_text};
+use text2num::trace::TraceEvent;
+
+let fr = Language::french();
+let (text, trace) = trace_numbers_in_text("zéro zéro trente quatre vingt", &fr, 10.0);
+
+assert_eq!(text, "0034 20");
+// "vingt" can't follow "quatre" as "0034" is complete, so a new number starts there.
+assert!(trace.events().iter().any(|event| matches!(
+    event,
+    TraceEvent::Apply { word, after, result: Some(_), .. } if word == "vingt" && after == "0034"
+)));
+println!("{trace}");
+```
+*/
+
+use std::fmt;
+
+use crate::error::Error;
+
+/// A step of the number search.
+///
+/// Positions are token offsets in the input stream.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "event", rename_all = "snake_case")
+)]
+pub enum TraceEvent {
+    /// The `word` was given to the interpreter, to be added to the integral part of the number
+    /// (or to its decimal part if `decimal` is set), whose digits were `before` and became `after`.
+    Apply {
+        position: usize,
+        word: String,
+        decimal: bool,
+        before: String,
+        after: String,
+        /// `None` on success
+        result: Option<Error>,
+    },
+    /// The `word` was understood as a decimal separator.
+    DecimalSeparator {
+        position: usize,
+        word: String,
+        separator: char,
+    },
+    /// The `word` introduces the fractional part of a mixed number, like "*and*" in "*two and a half*".
+    FractionLink { position: usize, word: String },
+    /// The `word` was understood as the denominator of a fraction.
+    Denominator {
+        position: usize,
+        word: String,
+        denominator: String,
+    },
+    /// The `word`, outside of any number, breaks a sequence of numbers.
+    SequenceBreaker { position: usize, word: String },
+    /// A `decision` was made about the number `text` spanning the tokens `start..end`.
+    Number {
+        start: usize,
+        end: usize,
+        text: String,
+        decision: Decision,
+    },
+}
+
+/// What becomes of a number found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Decision {
+    /// The number is reported.
+    Kept,
+    /// The number is isolated and under the threshold: it's on hold, until we know whether it's part of a list.
+    OnHold,
+    /// The number on hold is part of a list, so it's reported after all.
+    Released,
+    /// The number on hold was really isolated and is not reported.
+    IsolatedDropped,
+    /// The number is an ordinal, and ordinals are not converted.
+    OrdinalDropped,
+}
+
+impl fmt::Display for Decision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Kept => "kept",
+            Self::OnHold => "isolated, on hold",
+            Self::Released => "part of a list, released",
+            Self::IsolatedDropped => "isolated, dropped",
+            Self::OrdinalDropped => "ordinal, dropped",
+        })
+    }
+}
+
+impl fmt::Display for TraceEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Apply {
+                position,
+                word,
+                decimal,
+                before,
+                after,
+                result,
+            } => {
+                let part = if *decimal { "decimal part" } else { "number" };
+                write!(f, "[{position}] {word:?}: {part} {before:?} -> {after:?}")?;
+                match result {
+                    Some(error) => write!(f, " ({error})"),
+                    None => Ok(()),
+                }
+            }
+            Self::DecimalSeparator {
+                position,
+                word,
+                separator,
+            } => write!(f, "[{position}] {word:?}: decimal separator {separator:?}"),
+            Self::FractionLink { position, word } => {
+                write!(f, "[{position}] {word:?}: fraction link")
+            }
+            Self::Denominator {
+                position,
+                word,
+                denominator,
+            } => write!(f, "[{position}] {word:?}: denominator {denominator}"),
+            Self::SequenceBreaker { position, word } => {
+                write!(f, "[{position}] {word:?}: sequence breaker")
+            }
+            Self::Number {
+                start,
+                end,
+                text,
+                decision,
+            } => write!(f, "[{start}..{end}] {text:?} {decision}"),
+        }
+    }
+}
+
+/// The sequence of steps of a number search.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trace {
+    events: Vec<TraceEvent>,
+    /// Position of the token being processed
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) position: usize,
+}
+
+impl Trace {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The recorded events, in chronological order.
+    pub fn events(&self) -> &[TraceEvent] {
+        &self.events
+    }
+
+    pub fn into_events(self) -> Vec<TraceEvent> {
+        self.events
+    }
+
+    pub(crate) fn record(&mut self, event: TraceEvent) {
+        self.events.push(event);
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for event in &self.events {
+            writeln!(f, "{event}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Language, trace_numbers_in_text};
+
+    #[test]
+    fn test_display() {
+        let (_, trace) = trace_numbers_in_text("vingt et un points", &Language::french(), 0.0);
+        assert_eq!(
+            trace.to_string(),
+            concat!(
+                "[0] \"vingt\": number \"\" -> \"20\"\n",
+                "[2] \"et\": number \"20\" -> \"20\" (incomplete number)\n",
+                "[4] \"un\": number \"20\" -> \"21\"\n",
+                "[6] \"points\": number \"21\" -> \"21\" (not a number)\n",
+                "[0..5] \"21\" kept\n",
+                "[6] \"points\": number \"\" -> \"\" (not a number)\n",
+                "[6] \"points\": sequence breaker\n",
+            )
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let (_, trace) = trace_numbers_in_text("deux virgule cinq", &Language::french(), 0.0);
+        let value = serde_json::to_value(&trace).unwrap();
+        assert_eq!(
+            value["events"][1],
+            serde_json::json!({"event": "apply", "position": 2, "word": "virgule", "decimal": false,
+                               "before": "2", "after": "2", "result": "NaN"})
+        );
+        assert_eq!(
+            value["events"][2],
+            serde_json::json!({"event": "decimal_separator", "position": 2, "word": "virgule", "separator": ","})
+        );
+        assert_eq!(
+            value["events"][4],
+            serde_json::json!({"event": "number", "start": 0, "end": 5, "text": "2,5", "decision": "kept"})
+        );
+        let round_trip: super::Trace = serde_json::from_value(value).unwrap();
+        assert_eq!(round_trip.events(), trace.events());
+    }
+}
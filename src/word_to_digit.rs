@@ -6,16 +6,33 @@ For an overview with examples and use cases, see the [crate level documentation]
 */
 use std::collections::VecDeque;
 use std::iter::Enumerate;
+use std::ops::Deref;
 
+use crate::decimal::Decimal;
 use crate::digit_string::DigitString;
-use crate::error::Error;
+use crate::error::{Error, ParseError};
 use crate::lang::{BasicAnnotate, LangInterpreter};
+use crate::options::ConversionOptions;
 use crate::tokenizer::{BasicToken, tokenize};
+use crate::trace::{Decision, Trace, TraceEvent};
 
 struct WordToDigitParser<'a, T: LangInterpreter> {
     int_part: DigitString,
     dec_part: DigitString,
     dec_separator: Option<char>,
+    /// Numerator of a fraction, or of the fractional part of a mixed number.
+    num_part: DigitString,
+    den_part: DigitString,
+    /// A fraction link was met after the integral part.
+    mixed: bool,
+    /// Whether decimal separators are interpreted.
+    decimals: bool,
+    /// Whether fractions are interpreted.
+    fractions: bool,
+    /// Whether fractions are given as decimal numbers, when possible.
+    fractions_as_decimals: bool,
+    /// The steps of the parsing, if traced.
+    trace: Option<Trace>,
     lang: &'a T,
 }
 
@@ -25,6 +42,13 @@ impl<'a, T: LangInterpreter> WordToDigitParser<'a, T> {
             int_part: DigitString::new(),
             dec_part: DigitString::new(),
             dec_separator: None,
+            num_part: DigitString::new(),
+            den_part: DigitString::new(),
+            mixed: false,
+            decimals: true,
+            fractions: false,
+            fractions_as_decimals: false,
+            trace: None,
             lang,
         }
     }
@@ -34,18 +58,48 @@ impl<'a, T: LangInterpreter> WordToDigitParser<'a, T> {
         self.int_part.reset();
         self.dec_part.reset();
         self.dec_separator = None;
+        self.num_part.reset();
+        self.den_part.reset();
+        self.mixed = false;
     }
 
     pub fn push(&mut self, word: &str) -> Result<(), Error> {
-        let status = if self.dec_separator.is_some() {
-            self.lang.apply_decimal(word, &mut self.dec_part)
-        } else {
-            self.lang.apply(word, &mut self.int_part)
-        };
+        if !self.den_part.is_empty() {
+            // Denominators are single words
+            return Err(Error::Frozen);
+        }
+        if self.mixed {
+            return self.push_mixed(word);
+        }
+        if self.fractions
+            && self.dec_separator.is_none()
+            && !self.int_part.is_empty()
+            && self.int_part.marker.is_none()
+            && self.lang.is_multiplier_denominator(word)
+            && self.check_denominator(word)
+        {
+            // "five hundredths" is 5/100, not 500th
+            std::mem::swap(&mut self.int_part, &mut self.num_part);
+            return Ok(());
+        }
+        let mut status = self.apply(word, self.dec_separator.is_some());
+        if status.is_err() && self.dec_separator.is_none() && self.fractions {
+            status = self.check_split_mixed(word, status);
+        }
         if status.is_err() && self.dec_separator.is_none() && !self.int_part.is_empty() {
-            self.dec_separator = self.lang.check_decimal_separator(word);
-            if self.dec_separator.is_some() {
-                Err(Error::Incomplete)
+            if self.decimals {
+                self.dec_separator = self.lang.check_decimal_separator(word);
+                if let Some(separator) = self.dec_separator {
+                    self.record(|position| TraceEvent::DecimalSeparator {
+                        position,
+                        word: word.to_owned(),
+                        separator,
+                    });
+                    return Err(Error::Incomplete);
+                }
+            }
+            if self.fractions {
+                self.check_fraction(word, status)
             } else {
                 status
             }
@@ -54,9 +108,201 @@ impl<'a, T: LangInterpreter> WordToDigitParser<'a, T> {
         }
     }
 
+    /// Apply the `word` to the integral part, or to the decimal part if `decimal`.
+    fn apply(&mut self, word: &str, decimal: bool) -> Result<(), Error> {
+        let part = if decimal {
+            &mut self.dec_part
+        } else {
+            &mut self.int_part
+        };
+        let before = self.trace.as_ref().map(|_| part.to_string());
+        let status = if decimal {
+            self.lang.apply_decimal(word, part)
+        } else {
+            self.lang.apply(word, part)
+        };
+        if let (Some(trace), Some(before)) = (&mut self.trace, before) {
+            trace.record(TraceEvent::Apply {
+                position: trace.position,
+                word: word.to_owned(),
+                decimal,
+                before,
+                after: part.to_string(),
+                result: status.clone().err(),
+            });
+        }
+        status
+    }
+
+    /// Record the event built from the current position, if traced.
+    fn record<F: FnOnce(usize) -> TraceEvent>(&mut self, event: F) {
+        if let Some(trace) = &mut self.trace {
+            trace.record(event(trace.position));
+        }
+    }
+
+    /// After an integer, `word` may be a denominator, or introduce the fractional part of a mixed number.
+    fn check_fraction(&mut self, word: &str, status: Result<(), Error>) -> Result<(), Error> {
+        if !self.int_part.marker.is_none() {
+            return status;
+        }
+        if !matches!(status, Err(Error::Incomplete)) && self.check_denominator(word) {
+            // What we parsed so far is the numerator
+            std::mem::swap(&mut self.int_part, &mut self.num_part);
+            Ok(())
+        } else if self.lang.is_fraction_link(word) {
+            self.mixed = true;
+            self.record(|position| TraceEvent::FractionLink {
+                position,
+                word: word.to_owned(),
+            });
+            Err(Error::Incomplete)
+        } else {
+            status
+        }
+    }
+
+    fn push_mixed(&mut self, word: &str) -> Result<(), Error> {
+        if self.num_part.is_empty() {
+            // The link may be a linking word of the integer too, like "and" in "one hundred and five"
+            let status = self.apply(word, false);
+            if status.is_ok() {
+                self.mixed = false;
+                return status;
+            }
+            if self.lang.is_fraction_link(word) {
+                return Err(Error::Incomplete);
+            }
+            // The fractional part may have an implicit numerator of one, like in "two and a half"
+            self.num_part.put(b"1")?;
+            if self.check_denominator(word) {
+                return Ok(());
+            }
+            self.num_part.reset();
+        }
+        // or an explicit one, like in "two and three quarters"
+        if !self.num_part.is_empty()
+            && self.lang.is_multiplier_denominator(word)
+            && self.check_denominator(word)
+        {
+            return Ok(());
+        }
+        match self.lang.apply(word, &mut self.num_part) {
+            Ok(()) | Err(Error::Incomplete) => Err(Error::Incomplete),
+            Err(_) if !self.num_part.is_empty() && self.check_denominator(word) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Try `word` as a whole mixed number, like the German "*zweieinhalb*".
+    fn check_split_mixed(&mut self, word: &str, status: Result<(), Error>) -> Result<(), Error> {
+        let Some((int, frac)) = self.lang.split_mixed(word) else {
+            return status;
+        };
+        let int_part = self.int_part.clone();
+        if self.apply(int, false).is_ok() {
+            self.mixed = true;
+            self.num_part.put(b"1")?;
+            if self.check_denominator(frac) {
+                return Ok(());
+            }
+        }
+        self.int_part = int_part;
+        self.num_part.reset();
+        self.mixed = false;
+        status
+    }
+
+    /// Whether a fraction link was met, and the fractional part of the mixed number is still to be completed.
+    pub fn is_mixed_pending(&self) -> bool {
+        self.mixed && self.den_part.is_empty()
+    }
+
+    fn check_denominator(&mut self, word: &str) -> bool {
+        let numerator = if self.mixed {
+            &self.num_part
+        } else {
+            &self.int_part
+        };
+        let is_denominator = self
+            .lang
+            .apply_denominator(word, numerator, &mut self.den_part)
+            .is_ok();
+        if is_denominator {
+            let denominator = self.den_part.to_string();
+            self.record(|position| TraceEvent::Denominator {
+                position,
+                word: word.to_owned(),
+                denominator,
+            });
+        } else {
+            self.den_part.reset();
+        }
+        is_denominator
+    }
+
+    /// Integral part (may be empty), numerator and denominator of the fraction being parsed, if any.
+    fn fraction_parts(&self) -> Option<(String, String, String)> {
+        if !self.den_part.is_empty() {
+            Some((
+                self.int_part.to_string(),
+                self.num_part.to_string(),
+                self.den_part.to_string(),
+            ))
+        } else if self.int_part.marker.is_fraction() {
+            Some((String::new(), "1".to_owned(), self.int_part.to_string()))
+        } else {
+            None
+        }
+    }
+
+    /// Numerator and denominator of the fraction being parsed, if any, mixed numbers being given as improper fractions.
+    pub fn fraction(&self) -> Option<(u128, u128)> {
+        let (int, num, den) = self.fraction_parts()?;
+        let den: u128 = den.parse().ok()?;
+        let mut num: u128 = num.parse().ok()?;
+        if !int.is_empty() {
+            num = int
+                .parse::<u128>()
+                .ok()?
+                .checked_mul(den)?
+                .checked_add(num)?;
+        }
+        Some((num, den))
+    }
+
+    fn fraction_string_and_value(&self, int: String, num: String, den: String) -> (String, f64) {
+        let value =
+            int.parse().unwrap_or(0.0) + num.parse::<f64>().unwrap() / den.parse::<f64>().unwrap();
+        let decimal = self.exact_value().filter(|_| self.fractions_as_decimals);
+        if let Some(decimal) = decimal {
+            let repr = decimal.to_string();
+            (
+                repr.replace('.', &self.lang.decimal_separator().to_string()),
+                value,
+            )
+        } else if int.is_empty() {
+            (format!("{num}/{den}"), value)
+        } else {
+            (format!("{int} {num}/{den}"), value)
+        }
+    }
+
+    /// Exact value of the number being parsed, if it has a finite base 10 representation.
+    pub fn exact_value(&self) -> Option<Decimal> {
+        if self.fraction_parts().is_some() {
+            self.fraction()
+                .and_then(|(num, den)| Decimal::from_fraction(num, den))
+        } else {
+            Some(Decimal::from_parts(&self.int_part, &self.dec_part))
+        }
+    }
+
     /// Return representation and value and reset itself.
     pub fn string_and_value(&mut self) -> (String, f64) {
-        let res = if !self.dec_part.is_empty() {
+        let res = if let Some((int, num, den)) = self.fraction_parts() {
+            self.fraction_string_and_value(int, num, den)
+        } else if !self.dec_part.is_empty() {
             let sep = self.dec_separator.unwrap();
             self.lang
                 .format_decimal_and_value(&self.int_part, &self.dec_part, sep)
@@ -68,20 +314,60 @@ impl<'a, T: LangInterpreter> WordToDigitParser<'a, T> {
     }
 
     pub fn has_number(&self) -> bool {
-        !self.int_part.is_empty()
+        !self.int_part.is_empty() || !self.den_part.is_empty()
     }
 
     pub fn is_ordinal(&self) -> bool {
         self.int_part.is_ordinal()
     }
+
+    pub fn kind(&self) -> OccurenceKind {
+        if self.int_part.is_ordinal() {
+            OccurenceKind::Ordinal
+        } else if self.fraction_parts().is_some() {
+            OccurenceKind::Fraction
+        } else if !self.dec_part.is_empty() {
+            OccurenceKind::Decimal
+        } else if self.int_part.has_leading_zeroes() {
+            OccurenceKind::DigitSequence
+        } else {
+            OccurenceKind::Cardinal
+        }
+    }
 }
 
 /// Interpret the `text` as a integer number or ordinal, and translate it into digits.
-/// Return an error if the text couldn't be undestood as a valid number.
-pub fn text2digits<T: LangInterpreter>(text: &str, lang: &T) -> Result<String, Error> {
-    match lang.exec_group(text.to_lowercase().split_whitespace()) {
-        Ok(ds) => Ok(lang.format_and_value(&ds).0),
-        Err(err) => Err(err),
+/// Return an error if the text couldn't be undestood as a valid number, telling which word failed.
+/// A text without any word is an incomplete number.
+pub fn text2digits<T: LangInterpreter>(text: &str, lang: &T) -> Result<String, ParseError> {
+    let text = text.to_lowercase();
+    let mut b = DigitString::new();
+    let mut incomplete = false;
+    let mut count = 0;
+    for (index, word) in text.split_whitespace().enumerate() {
+        incomplete = match lang.apply(word, &mut b) {
+            Err(Error::Incomplete) => true,
+            Ok(()) => false,
+            Err(error) => {
+                return Err(ParseError {
+                    error,
+                    index,
+                    word: Some(word.to_owned()),
+                    state: b,
+                });
+            }
+        };
+        count = index + 1;
+    }
+    if incomplete || count == 0 {
+        Err(ParseError {
+            error: Error::Incomplete,
+            index: count,
+            word: None,
+            state: b,
+        })
+    } else {
+        Ok(lang.format_and_value(&b).0)
     }
 }
 
@@ -157,9 +443,19 @@ pub trait Token {
     }
 }
 
-pub trait Replace {
+pub trait Replace: Sized {
     /// Represents a type that can be created from a `String` and an Iterator on elements of same type.
     fn replace<I: Iterator<Item = Self>>(replaced: I, data: String) -> Self;
+
+    /// Create the token that stands for the number `occurence`, from the `replaced` tokens it spans.
+    ///
+    /// Override this method to take advantage of the details of the occurence (kind, value…) when merging the
+    /// replaced tokens and their metadata.
+    /// The default implementation calls [`Self::replace`] with the digit representation of the number:
+    /// see [`TimedToken`](crate::TimedToken) for a token type that merges timing and confidence that way.
+    fn replace_occurence<I: Iterator<Item = Self>>(replaced: I, occurence: &Occurence) -> Self {
+        Self::replace(replaced, occurence.text.clone())
+    }
 }
 
 impl Token for &BasicToken {
@@ -200,8 +496,38 @@ impl BasicAnnotate for BasicToken {
     }
 }
 
-#[derive(Debug)]
+/// The kinds of numbers.
+///
+/// With the `serde` feature, they are serialized in snake case, like `"digit_sequence"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum OccurenceKind {
+    /// An integer
+    Cardinal,
+    /// A rank, like "*twentieth*"
+    Ordinal,
+    /// A number with a decimal part, like "*three point one four*"
+    Decimal,
+    /// A fraction or a mixed number, like "*three quarters*" or "*two and a half*"
+    Fraction,
+    /// Digits spoken with leading zeros, like "*zero zero thirty*", typically part of a phone number or a code
+    DigitSequence,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// This type describes a number found in a token stream.
+///
+/// With the `serde` feature, it is serialized as a map of its fields, the exact value being given as a string:
+///
+/// ```json
+/// {"start": 3, "end": 6, "text": "1.5", "value": 1.5, "exact_value": "1.5", "numerator": null,
+///  "denominator": null, "is_ordinal": false, "kind": "decimal"}
+/// ```
 pub struct Occurence {
     /// The offset of the first token of the number in the stream
     pub start: usize,
@@ -212,8 +538,48 @@ pub struct Occurence {
     /// The value of the number. If the number is an ordinal, the value
     /// is the rank it represents.
     pub value: f64,
+    /// The exact value of the number, without any loss of precision.
+    ///
+    /// It is `None` if the value has no finite base 10 representation (e.g. fractions like 1/12).
+    pub exact_value: Option<Decimal>,
+    /// The numerator, if the number is a fraction. Mixed numbers like "*two and a half*"
+    /// are given as improper fractions (`5/2`).
+    pub numerator: Option<i64>,
+    /// The denominator, if the number is a fraction.
+    pub denominator: Option<u64>,
     /// A flag to distinguish ordinals
     pub is_ordinal: bool,
+    /// The kind of number
+    pub kind: OccurenceKind,
+}
+
+/// This type describes a number found in a plain text, with its location in the source string.
+///
+/// It dereferences to the underlying [`Occurence`], whose `start` and `end` are token offsets.
+///
+/// With the `serde` feature, the fields of the occurence are flattened in the map of the text locations.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextOccurence {
+    /// The number found, as in a token stream
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub occurence: Occurence,
+    /// The byte offset of the number in the source text
+    pub byte_start: usize,
+    /// The byte offset after the number in the source text
+    pub byte_end: usize,
+    /// The character offset of the number in the source text
+    pub char_start: usize,
+    /// The character offset after the number in the source text
+    pub char_end: usize,
+}
+
+impl Deref for TextOccurence {
+    type Target = Occurence;
+
+    fn deref(&self) -> &Self::Target {
+        &self.occurence
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -256,44 +622,61 @@ impl NumTracker {
         self.match_end = pos + 1;
     }
 
+    /// Record the `occurence` of the number that just ended, at the current match location.
     fn number_end(
         &mut self,
-        is_ordinal: bool,
-        digits: String,
-        value: f64,
+        mut occurence: Occurence,
         forget_if_isolate: bool,
+        lists_as_isolated: bool,
+        mut trace: Option<&mut Trace>,
     ) {
-        let occurence = Occurence {
-            start: self.match_start,
-            end: self.match_end,
-            text: digits,
-            is_ordinal,
-            value,
-        };
-        let kind = if is_ordinal {
+        occurence.start = self.match_start;
+        occurence.end = self.match_end;
+        let kind = if occurence.is_ordinal {
             MatchKind::Ordinal
         } else {
             MatchKind::Cardinal
         };
-        if self.last_contiguous_match != kind {
+        if self.last_contiguous_match != kind || lists_as_isolated {
             self.last_contiguous_match = MatchKind::None;
         }
         if !self.last_contiguous_match.is_none() {
             if let Some(prev) = self.on_hold.take() {
+                record(&mut trace, Decision::Released, &prev);
                 self.matches.push_back(prev);
             }
+            record(&mut trace, Decision::Kept, &occurence);
             self.matches.push_back(occurence);
         } else if forget_if_isolate {
-            self.on_hold.replace(occurence);
+            record(&mut trace, Decision::OnHold, &occurence);
+            if let Some(prev) = self.on_hold.replace(occurence) {
+                record(&mut trace, Decision::IsolatedDropped, &prev);
+            }
         } else {
+            record(&mut trace, Decision::Kept, &occurence);
             self.matches.push_back(occurence);
-            self.on_hold.take();
+            if let Some(prev) = self.on_hold.take() {
+                record(&mut trace, Decision::IsolatedDropped, &prev);
+            }
         }
         //
         self.last_contiguous_match = kind;
         self.match_start = self.match_end;
     }
 
+    /// The number that just ended is not to be reported.
+    fn number_dropped(&mut self) {
+        self.last_contiguous_match = MatchKind::None;
+        self.match_start = self.match_end;
+    }
+
+    /// The input is exhausted: the number on hold, if any, was isolated.
+    fn end(&mut self, mut trace: Option<&mut Trace>) {
+        if let Some(prev) = self.on_hold.take() {
+            record(&mut trace, Decision::IsolatedDropped, &prev);
+        }
+    }
+
     fn sequence_breaker(&mut self) {
         self.last_contiguous_match = MatchKind::None
     }
@@ -307,11 +690,9 @@ impl NumTracker {
     }
 
     fn replace<T: Replace>(self, tokens: &mut Vec<T>) {
-        for Occurence {
-            start, end, text, ..
-        } in self.matches.into_iter().rev()
-        {
-            let repr: T = Replace::replace(tokens.drain(start..end), text);
+        for occurence in self.matches.into_iter().rev() {
+            let start = occurence.start;
+            let repr = T::replace_occurence(tokens.drain(start..occurence.end), &occurence);
             tokens.insert(start, repr);
         }
     }
@@ -321,6 +702,18 @@ impl NumTracker {
     }
 }
 
+/// Record a tracker decision about the `occurence`, if traced.
+fn record(trace: &mut Option<&mut Trace>, decision: Decision, occurence: &Occurence) {
+    if let Some(trace) = trace {
+        trace.record(TraceEvent::Number {
+            start: occurence.start,
+            end: occurence.end,
+            text: occurence.text.clone(),
+            decision,
+        });
+    }
+}
+
 /// An Iterator that yields all the number occurences found in a token stream for a given language.
 /// It lazily consumes the token stream.
 pub struct FindNumbers<'a, L, T, I>
@@ -334,7 +727,13 @@ where
     parser: WordToDigitParser<'a, L>,
     tracker: NumTracker,
     previous: Option<T>,
-    threshold: f64,
+    options: ConversionOptions,
+    /// A sign word just seen, with its position.
+    pending_sign: Option<(usize, char)>,
+    /// The sign of the number being parsed.
+    sign: Option<char>,
+    /// The words skipped since the fraction link of a pending mixed number, as position, text and lowercase text.
+    mixed_words: Vec<(usize, String, String)>,
 }
 
 impl<'a, L, T, I> FindNumbers<'a, L, T, I>
@@ -343,26 +742,45 @@ where
     T: Token,
     I: Iterator<Item = (usize, T)>,
 {
-    fn new(input: I, lang: &'a L, threshold: f64) -> Self {
+    fn new(input: I, lang: &'a L, options: ConversionOptions) -> Self {
+        let mut parser = WordToDigitParser::new(lang);
+        parser.decimals = options.decimals;
+        parser.fractions = options.fractions;
+        parser.fractions_as_decimals = options.fractions_as_decimals;
         Self {
             lang,
             input,
-            parser: WordToDigitParser::new(lang),
+            parser,
             tracker: NumTracker::new(),
             previous: None,
-            threshold,
+            options,
+            pending_sign: None,
+            sign: None,
+            mixed_words: Vec::new(),
         }
     }
 
+    /// Record the steps of the search in a [`Trace`], to be retrieved with [`FindNumbers::trace`].
+    pub fn traced(mut self) -> Self {
+        self.parser.trace = Some(Trace::new());
+        self
+    }
+
+    /// The steps of the search so far, if traced.
+    pub fn trace(&self) -> Option<&Trace> {
+        self.parser.trace.as_ref()
+    }
+
     fn push(&mut self, pos: usize, token: T) {
         if token.text() == "-" || is_whitespace(token.text()) {
             return;
         }
         if token.not_a_number_part() {
-            if self.parser.has_number() {
-                self.number_end()
+            if let Some(trace) = &mut self.parser.trace {
+                trace.position = pos;
             }
-            self.outside_number(&token);
+            self.close_number();
+            self.outside_number(token.text());
             self.previous.replace(token);
             return;
         }
@@ -376,56 +794,172 @@ where
         } else {
             lo_token
         };
+        self.push_word(pos, token.text(), lo_token, test);
+        self.previous.replace(token);
+    }
+
+    /// Push the word `text`, whose lowercase form is `lo_token`, to the parser as `test`.
+    fn push_word(&mut self, pos: usize, text: &str, lo_token: &str, test: &str) {
+        if let Some(trace) = &mut self.parser.trace {
+            trace.position = pos;
+        }
+        let pending_sign = self.pending_sign.take();
+        let starting = !self.parser.has_number();
         match self.parser.push(test) {
             // Set match_start on first successful parse
-            Ok(()) => self.tracker.number_advanced(pos),
+            Ok(()) => {
+                if let (true, Some((sign_pos, sign))) = (starting, pending_sign) {
+                    self.tracker.number_advanced(sign_pos);
+                    self.sign = Some(sign);
+                }
+                self.mixed_words.clear();
+                self.tracker.number_advanced(pos)
+            }
             // Skip potential linking words
-            Err(Error::Incomplete) => (),
+            Err(Error::Incomplete) => {
+                if self.parser.is_mixed_pending() {
+                    self.mixed_words
+                        .push((pos, text.to_owned(), lo_token.to_owned()));
+                }
+            }
             // First failed parse after one or more successful ones:
             // we reached the end of a number.
             Err(_) if self.parser.has_number() => {
-                self.number_end();
+                self.end_number_and_replay();
+                if self.parser.has_number() {
+                    // The replayed words started another number
+                    self.push_word(pos, text, lo_token, lo_token);
                 // The end of that match may be the start of another
-                if self.parser.push(lo_token).is_ok() {
+                // A sign right after a number is an operator, not a prefix
+                } else if self.parser.push(lo_token).is_ok() {
                     self.tracker.number_advanced(pos);
                 } else {
-                    self.outside_number(&token)
+                    self.outside_number(text);
                 }
             }
-            Err(_) => self.outside_number(&token),
+            Err(_) => {
+                self.outside_number(text);
+                self.check_sign(pos, lo_token);
+            }
         }
-        self.previous.replace(token);
     }
 
-    fn finalize(&mut self) {
-        if self.parser.has_number() {
-            self.number_end()
+    /// End the current number, and push again the words after the fraction link of an unfinished mixed number,
+    /// like "*and three*" in "*two and three dogs*", as they don't belong to it.
+    fn end_number_and_replay(&mut self) {
+        let replay = if self.parser.is_mixed_pending() {
+            std::mem::take(&mut self.mixed_words)
+        } else {
+            self.mixed_words.clear();
+            Vec::new()
+        };
+        self.number_end();
+        for (pos, text, lo_token) in replay {
+            self.push_word(pos, &text, &lo_token, &lo_token);
+        }
+    }
+
+    /// End the current number, and those started by the replayed words, if any.
+    fn close_number(&mut self) {
+        while self.parser.has_number() {
+            self.end_number_and_replay()
         }
     }
 
+    fn finalize(&mut self) {
+        self.close_number();
+        self.tracker.end(self.parser.trace.as_mut());
+    }
+
     fn number_end(&mut self) {
         let is_ordinal = self.parser.is_ordinal();
-        let (digits, value) = self.parser.string_and_value();
-        let forget_if_isolate = (digits.len() == 1 || is_ordinal) && value < self.threshold;
-        self.tracker
-            .number_end(is_ordinal, digits, value, forget_if_isolate);
+        let kind = self.parser.kind();
+        let exact_value = self.parser.exact_value();
+        let fraction = self.parser.fraction();
+        let (mut digits, value) = self.parser.string_and_value();
+        let sign = self.sign.take();
+        if is_ordinal && !self.options.ordinals {
+            if let Some(trace) = &mut self.parser.trace {
+                trace.record(TraceEvent::Number {
+                    start: self.tracker.match_start,
+                    end: self.tracker.match_end,
+                    text: digits,
+                    decision: Decision::OrdinalDropped,
+                });
+            }
+            self.tracker.number_dropped();
+            return;
+        }
+        // Spoken signs and leading zeros are evidence enough that this is a number
+        let forget_if_isolate = match (sign, is_ordinal) {
+            (Some(_), _) => false,
+            (None, true) => value < self.options.ordinal_threshold,
+            (None, false) => digits.len() == 1 && value < self.options.cardinal_threshold,
+        };
+        if !self.options.leading_zeros {
+            let zeros = leading_zeros(&digits);
+            digits.drain(..zeros);
+        }
+        let (mut numerator, denominator) = match fraction
+            .and_then(|(num, den)| Some((i64::try_from(num).ok()?, u64::try_from(den).ok()?)))
+        {
+            Some((num, den)) => (Some(num), Some(den)),
+            None => (None, None),
+        };
+        let (value, exact_value) = match sign {
+            Some('-') => {
+                numerator = numerator.map(|num| -num);
+                (-value, exact_value.map(|exact| -exact))
+            }
+            _ => (value, exact_value),
+        };
+        if let Some(sign) = sign {
+            digits.insert(0, sign);
+        }
+        let occurence = Occurence {
+            start: 0,
+            end: 0,
+            text: digits,
+            value,
+            exact_value,
+            numerator,
+            denominator,
+            is_ordinal,
+            kind,
+        };
+        self.tracker.number_end(
+            occurence,
+            forget_if_isolate,
+            self.options.lists_as_isolated,
+            self.parser.trace.as_mut(),
+        );
     }
 
-    fn outside_number(&mut self, token: &T) {
-        let text = token.text();
-        if !(text.chars().all(|c| !c.is_alphabetic()) && text.trim() != "."
-            || self.lang.is_linking(text))
+    fn check_sign(&mut self, pos: usize, word: &str) {
+        if self.options.signs {
+            self.pending_sign = self.lang.check_sign(word).map(|sign| (pos, sign));
+        }
+    }
+
+    fn outside_number(&mut self, text: &str) {
+        if self.lang.is_separator(text)
+            || !(text.chars().all(|c| !c.is_alphabetic()) && text.trim() != "."
+                || self.lang.is_linking(text))
         {
+            self.parser.record(|position| TraceEvent::SequenceBreaker {
+                position,
+                word: text.to_owned(),
+            });
             self.tracker.sequence_breaker()
         };
     }
 
-    fn track_numbers(mut self) -> NumTracker {
+    fn track_numbers(mut self) -> (NumTracker, Option<Trace>) {
         while let Some((pos, token)) = self.input.next() {
             self.push(pos, token);
         }
         self.finalize();
-        self.tracker
+        (self.tracker, self.parser.trace)
     }
 }
 
@@ -453,75 +987,183 @@ where
 }
 
 /// Find spelled numbers (including decimal numbers) in the input token stream.
-/// Isolated digits strictly under `threshold` are not converted (set to 0.0 to convert everything).
 fn track_numbers<L: LangInterpreter, T: Token, I: Iterator<Item = T>>(
     input: I,
     lang: &L,
-    threshold: f64,
+    options: ConversionOptions,
 ) -> NumTracker {
-    let scanner = FindNumbers::new(input.enumerate(), lang, threshold);
-    scanner.track_numbers()
+    let scanner = FindNumbers::new(input.enumerate(), lang, options);
+    scanner.track_numbers().0
 }
 
 /**
 Find the spelled numbers (including decimal numbers) in a token stream.
 
 Return a list of the successive [`Occurence`]s of numbers in the stream.
-The `threshold` drives the *lone number* policy: if a number is isolated — that is,
+The `options` drive what is converted, including the *lone number* policy (see [`ConversionOptions`]).
+A bare `f64` threshold can be given instead: if a number is isolated — that is,
 surrounded by significant non-number words — and lower than `threshold`, then it
 is ignored.
 */
-pub fn find_numbers<L: LangInterpreter, T: Token, I: Iterator<Item = T>>(
-    input: I,
-    lang: &L,
-    threshold: f64,
-) -> Vec<Occurence> {
-    track_numbers(input, lang, threshold).into_vec()
+pub fn find_numbers<L, T, I, O>(input: I, lang: &L, options: O) -> Vec<Occurence>
+where
+    L: LangInterpreter,
+    T: Token,
+    I: Iterator<Item = T>,
+    O: Into<ConversionOptions>,
+{
+    track_numbers(input, lang, options.into()).into_vec()
 }
 
 /**
 Return an iterator over all the number occurences (including decimal numbers) found in a speech token stream.
 
 Return an iterator of the successive [`Occurence`]s of numbers in the stream.
-The `threshold` drives the *lone number* policy: if a number is isolated — that is,
+The `options` drive what is converted, including the *lone number* policy (see [`ConversionOptions`]).
+A bare `f64` threshold can be given instead: if a number is isolated — that is,
 surrounded by significant non-number words — and lower than `threshold`, then it
 is ignored.
 */
-pub fn find_numbers_iter<L, T, I>(
+pub fn find_numbers_iter<L, T, I, O>(
     input: I,
     lang: &L,
-    threshold: f64,
+    options: O,
 ) -> FindNumbers<'_, L, T, Enumerate<I>>
 where
     L: LangInterpreter,
     T: Token,
     I: Iterator<Item = T>,
+    O: Into<ConversionOptions>,
 {
-    FindNumbers::new(input.enumerate(), lang, threshold)
+    FindNumbers::new(input.enumerate(), lang, options.into())
 }
 
 /// Find spelled numbers (including decimal) in the token stream and replace them by their digit representation.
-/// Isolated digits strictly under `threshold` are not converted (set to 0.0 to convert everything).
-pub fn replace_numbers_in_stream<'a, L, T>(mut input: Vec<T>, lang: &L, threshold: f64) -> Vec<T>
+/// The tokens that make up a number are merged by [`Replace::replace_occurence`].
+/// What is converted is set by the `options` (see [`ConversionOptions`]); as a shortcut, isolated digits
+/// strictly under a bare `f64` threshold are not converted (set to 0.0 to convert everything).
+pub fn replace_numbers_in_stream<'a, L, T, O>(mut input: Vec<T>, lang: &L, options: O) -> Vec<T>
 where
     L: LangInterpreter,
     T: Replace + 'a,
     for<'b> &'b T: Token,
+    O: Into<ConversionOptions>,
 {
-    let tracker = track_numbers(input.iter(), lang, threshold);
+    let tracker = track_numbers(input.iter(), lang, options.into());
     tracker.replace(&mut input);
     input
 }
 
 /// Find spelled numbers (including decimal) in the `text` and replace them by their digit representation.
-/// Isolated digits strictly under `threshold` are not converted (set to 0.0 to convert everything).
-pub fn replace_numbers_in_text<L: LangInterpreter>(text: &str, lang: &L, threshold: f64) -> String {
-    let mut tokens = tokenize(text).collect();
+/// What is converted is set by the `options` (see [`ConversionOptions`]); as a shortcut, isolated digits
+/// strictly under a bare `f64` threshold are not converted (set to 0.0 to convert everything).
+pub fn replace_numbers_in_text<L, O>(text: &str, lang: &L, options: O) -> String
+where
+    L: LangInterpreter,
+    O: Into<ConversionOptions>,
+{
+    let mut tokens = tokenize_for(text, lang);
     lang.basic_annotate(&mut tokens);
-    let out = replace_numbers_in_stream(tokens, lang, threshold);
+    let out = replace_numbers_in_stream(tokens, lang, options);
     out.join("")
 }
 
+/**
+Replace the spelled numbers in the `text`, like [`replace_numbers_in_text`], and return the [`Trace`] of the search too.
+
+The trace explains why the words were, or were not, converted (see the [`trace`](crate::trace) module).
+*/
+pub fn trace_numbers_in_text<L, O>(text: &str, lang: &L, options: O) -> (String, Trace)
+where
+    L: LangInterpreter,
+    O: Into<ConversionOptions>,
+{
+    let mut tokens = tokenize_for(text, lang);
+    lang.basic_annotate(&mut tokens);
+    let scanner = FindNumbers::new(tokens.iter().enumerate(), lang, options.into()).traced();
+    let (tracker, trace) = scanner.track_numbers();
+    tracker.replace(&mut tokens);
+    (tokens.join(""), trace.unwrap_or_default())
+}
+
+/**
+Find the spelled numbers (including decimal numbers) in the `text`.
+
+Return a list of the successive [`TextOccurence`]s of numbers in the text, located by their
+byte and character offsets in `text`.
+The `options` drive what is converted, as in [`find_numbers`].
+
+# Example
+
+```rust
+use text2num::{find_numbers_in_text, Language};
+
+let fr = Language::french();
+let text = "Le vélo coûte deux cent trente euros.";
+
+let occurences = find_numbers_in_text(text, &fr, 10.0);
+
+assert_eq!(occurences.len(), 1);
+let found = &occurences[0];
+assert_eq!(found.text, "230");
+assert_eq!(&text[found.byte_start..found.byte_end], "deux cent trente");
+assert_eq!(found.char_start, 14);
+assert_eq!(found.char_end, 30);
+```
+*/
+pub fn find_numbers_in_text<L, O>(text: &str, lang: &L, options: O) -> Vec<TextOccurence>
+where
+    L: LangInterpreter,
+    O: Into<ConversionOptions>,
+{
+    let mut tokens = tokenize_for(text, lang);
+    lang.basic_annotate(&mut tokens);
+    // The tokenizer covers the whole source, so token boundaries are cumulative lengths.
+    let mut bounds = Vec::with_capacity(tokens.len() + 1);
+    let (mut bytes, mut chars) = (0, 0);
+    bounds.push((bytes, chars));
+    for token in &tokens {
+        bytes += token.text.len();
+        chars += token.text.chars().count();
+        bounds.push((bytes, chars));
+    }
+    find_numbers(tokens.iter(), lang, options)
+        .into_iter()
+        .map(|occurence| {
+            let (byte_start, char_start) = bounds[occurence.start];
+            let (byte_end, char_end) = bounds[occurence.end];
+            TextOccurence {
+                occurence,
+                byte_start,
+                byte_end,
+                char_start,
+                char_end,
+            }
+        })
+        .collect()
+}
+
+/// Tokenize the `text`, splitting the words that `lang` writes without spaces.
+fn tokenize_for<L: LangInterpreter>(text: &str, lang: &L) -> Vec<BasicToken> {
+    let mut tokens = Vec::new();
+    for token in tokenize(text) {
+        match lang.segment(&token.text) {
+            Some(words) => tokens.extend(words.into_iter().map(BasicToken::new)),
+            None => tokens.push(token),
+        }
+    }
+    tokens
+}
+
+/// Number of leading zeros that can be dropped from a digit representation, keeping at least one digit.
+fn leading_zeros(digits: &str) -> usize {
+    let bytes = digits.as_bytes();
+    bytes
+        .windows(2)
+        .take_while(|pair| pair[0] == b'0' && pair[1].is_ascii_digit())
+        .count()
+}
+
 fn is_whitespace(token: &str) -> bool {
     token.chars().all(char::is_whitespace)
 }
@@ -538,7 +1180,7 @@ mod tests {
         }
 
         fn text_lowercase(&self) -> &str {
-            &self.lowercase.as_str()
+            self.lowercase.as_str()
         }
 
         fn nt_separated(&self, _previous: &Self) -> bool {
@@ -605,6 +1247,478 @@ mod tests {
         assert_eq!(ocs[1].text, "02");
     }
 
+    #[test]
+    fn test_options_ordinals_and_decimals() {
+        let en = Language::english();
+        let text = "the first three are one point five and the twenty-second";
+        assert_eq!(
+            replace_numbers_in_text(text, &en, ConversionOptions::new()),
+            "the 1st 3 are 1.5 and the 22nd"
+        );
+        let options = ConversionOptions::new().convert_ordinals(false);
+        assert_eq!(
+            replace_numbers_in_text(text, &en, options),
+            "the first 3 are 1.5 and the twenty-second"
+        );
+        let options = ConversionOptions::new().convert_decimals(false);
+        assert_eq!(
+            replace_numbers_in_text(text, &en, options),
+            "the 1st 3 are 1 point 5 and the 22nd"
+        );
+    }
+
+    #[test]
+    fn test_options_leading_zeros() {
+        let fr = Language::french();
+        let options = ConversionOptions::new().keep_leading_zeros(false);
+        assert_eq!(
+            replace_numbers_in_text("zéro zéro trente, zéro, zéro virgule cinq", &fr, options),
+            "30, 0, 0,5"
+        );
+        let options = options.threshold(10.0);
+        assert_eq!(
+            replace_numbers_in_text("appelle le zéro neuf", &fr, options),
+            "appelle le 9"
+        );
+    }
+
+    #[test]
+    fn test_options_thresholds() {
+        let en = Language::english();
+        let text = "Three people came first to the party";
+        let options = ConversionOptions::new().cardinal_threshold(10.0);
+        assert_eq!(
+            replace_numbers_in_text(text, &en, options),
+            "Three people came 1st to the party"
+        );
+        let options = ConversionOptions::new().ordinal_threshold(10.0);
+        assert_eq!(
+            replace_numbers_in_text(text, &en, options),
+            "3 people came first to the party"
+        );
+        assert_eq!(
+            replace_numbers_in_text(text, &en, ConversionOptions::from(10.0)),
+            replace_numbers_in_text(text, &en, 10.0)
+        );
+    }
+
+    #[test]
+    fn test_options_lists() {
+        let fr = Language::french();
+        let text = "une suite de chiffres : un, deux, trois !";
+        let options = ConversionOptions::new().threshold(10.0);
+        assert_eq!(
+            replace_numbers_in_text(text, &fr, options),
+            "une suite de chiffres : 1, 2, 3 !"
+        );
+        assert_eq!(
+            replace_numbers_in_text(text, &fr, options.lists_as_isolated(true)),
+            text
+        );
+    }
+
+    #[test]
+    fn test_options_signs() {
+        let de = Language::german();
+        let text = "Es ist drinnen plus zwanzig Grad und draußen minus fünfzehn Grad.";
+        let options = ConversionOptions::new().threshold(10.0).convert_signs(true);
+        assert_eq!(
+            replace_numbers_in_text(text, &de, options),
+            "Es ist drinnen +20 Grad und draußen -15 Grad."
+        );
+        let ocs = find_numbers(tokenize(text), &de, options);
+        assert_eq!(ocs.len(), 2);
+        assert_eq!(ocs[1].value, -15.0);
+        assert_eq!(ocs[1].exact_value.as_ref().unwrap().to_i128(), Some(-15));
+        let fr = Language::french();
+        assert_eq!(
+            replace_numbers_in_text(
+                "il fait moins deux virgule cinq, moins que hier",
+                &fr,
+                options
+            ),
+            "il fait -2,5, moins que hier"
+        );
+        assert_eq!(
+            replace_numbers_in_text("il fait moins deux", &fr, 10.0),
+            "il fait moins deux"
+        );
+    }
+
+    #[test]
+    fn test_options_signs_between_numbers() {
+        let en = Language::english();
+        let options = ConversionOptions::new().convert_signs(true);
+        assert_eq!(
+            replace_numbers_in_text("five minus three is two", &en, options),
+            "5 minus 3 is 2"
+        );
+        assert_eq!(
+            replace_numbers_in_text("ten plus one", &en, options),
+            "10 plus 1"
+        );
+        assert_eq!(
+            replace_numbers_in_text("five, minus three", &en, options),
+            "5, -3"
+        );
+        // A dropped ordinal doesn't pass its sign on
+        let options = options.convert_ordinals(false);
+        assert_eq!(
+            replace_numbers_in_text("minus first then five", &en, options),
+            "minus first then 5"
+        );
+    }
+
+    #[test]
+    fn test_fractions() {
+        let en = Language::english();
+        let options = ConversionOptions::from(10.0).convert_fractions(true);
+        let ocs = find_numbers(tokenize("two and a half cups and one third"), &en, options);
+        assert_eq!(ocs.len(), 2);
+        assert_eq!(ocs[0].text, "2 1/2");
+        assert_eq!(ocs[0].value, 2.5);
+        assert_eq!((ocs[0].numerator, ocs[0].denominator), (Some(5), Some(2)));
+        assert_eq!(ocs[0].exact_value.as_ref().unwrap().to_string(), "2.5");
+        assert_eq!(ocs[1].text, "1/3");
+        assert_eq!((ocs[1].numerator, ocs[1].denominator), (Some(1), Some(3)));
+        assert!(ocs[1].exact_value.is_none());
+        let ocs = find_numbers(tokenize("one hundred and five"), &en, options);
+        assert_eq!(ocs[0].text, "105");
+        assert_eq!(ocs[0].denominator, None);
+    }
+
+    #[test]
+    fn test_fractions_off_by_default() {
+        let es = Language::spanish();
+        assert_eq!(
+            replace_numbers_in_text("son las tres y media", &es, 10.0),
+            "son las tres y media"
+        );
+        assert_eq!(
+            replace_numbers_in_text("ein halbes Jahr", &Language::german(), 10.0),
+            "ein halbes Jahr"
+        );
+        let fr = Language::french();
+        assert_eq!(
+            replace_numbers_in_text("un quart d'heure", &fr, 7.0),
+            "un quart d'heure"
+        );
+        assert_eq!(
+            replace_numbers_in_text("trois quarts d'heure", &fr, 7.0),
+            "trois quarts d'heure"
+        );
+        let ocs = find_numbers(tokenize("deux et demi"), &fr, 0.0);
+        assert_eq!(ocs.len(), 1);
+        assert_eq!(ocs[0].text, "2");
+        assert_eq!(ocs[0].denominator, None);
+    }
+
+    #[test]
+    fn test_fractions_as_decimals() {
+        let options = ConversionOptions::new()
+            .convert_fractions(true)
+            .fractions_as_decimals(true)
+            .convert_signs(true);
+        let fr = Language::french();
+        assert_eq!(
+            replace_numbers_in_text("deux et demi, moins trois quarts et un tiers", &fr, options),
+            "2,5, -0,75 et 1/3"
+        );
+        let ocs = find_numbers(tokenize("moins trois quarts"), &fr, options);
+        assert_eq!((ocs[0].numerator, ocs[0].denominator), (Some(-3), Some(4)));
+        assert_eq!(ocs[0].value, -0.75);
+        let en = Language::english();
+        assert_eq!(
+            replace_numbers_in_text("two and a half", &en, options),
+            "2.5"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use crate::lang::{MorphologicalMarker, OwnedMarker};
+        use serde_json::{from_str, json, to_string, to_value};
+
+        let en = Language::english();
+        let options = ConversionOptions::new().convert_fractions(true);
+        let found = find_numbers_in_text("the third of two and a half pies", &en, options);
+        assert_eq!(
+            to_value(&found).unwrap(),
+            json!([
+                {"start": 2, "end": 3, "text": "3rd", "value": 3.0, "exact_value": "3",
+                 "numerator": null, "denominator": null, "is_ordinal": true, "kind": "ordinal",
+                 "byte_start": 4, "byte_end": 9, "char_start": 4, "char_end": 9},
+                {"start": 6, "end": 13, "text": "2 1/2", "value": 2.5, "exact_value": "2.5",
+                 "numerator": 5, "denominator": 2, "is_ordinal": false, "kind": "fraction",
+                 "byte_start": 13, "byte_end": 27, "char_start": 13, "char_end": 27}
+            ])
+        );
+        let round_trip: Vec<TextOccurence> = from_str(&to_string(&found).unwrap()).unwrap();
+        assert_eq!(round_trip, found);
+
+        let occurence = found.into_iter().next().unwrap().occurence;
+        let round_trip: Occurence = from_str(&to_string(&occurence).unwrap()).unwrap();
+        assert_eq!(round_trip, occurence);
+
+        let options = ConversionOptions::new().threshold(10.0).convert_signs(true);
+        let round_trip: ConversionOptions = from_str(&to_string(&options).unwrap()).unwrap();
+        assert_eq!(round_trip, options);
+        let partial: ConversionOptions = from_str(r#"{"ordinals": false}"#).unwrap();
+        assert_eq!(partial, ConversionOptions::new().convert_ordinals(false));
+
+        assert_eq!(to_string(&Error::Overlap).unwrap(), r#""Overlap""#);
+        assert_eq!(
+            from_str::<Error>(r#""Incomplete""#).unwrap(),
+            Error::Incomplete
+        );
+
+        let marker = MorphologicalMarker::Ordinal("th");
+        let repr = to_string(&marker).unwrap();
+        assert_eq!(repr, r#"{"Ordinal":"th"}"#);
+        assert_eq!(from_str::<OwnedMarker>(&repr).unwrap(), marker);
+        assert_eq!(
+            from_str::<OwnedMarker>(&repr).unwrap(),
+            OwnedMarker::from(marker)
+        );
+        let owned = String::from(r#"{"Fraction":"avo"}"#);
+        assert_eq!(
+            from_str::<OwnedMarker>(&owned).unwrap(),
+            MorphologicalMarker::Fraction("avo")
+        );
+        assert_eq!(
+            from_str::<OwnedMarker>(r#""None""#).unwrap(),
+            MorphologicalMarker::None
+        );
+        assert_eq!(
+            to_string(&OwnedMarker::Ordinal("-го".to_owned())).unwrap(),
+            to_string(&MorphologicalMarker::Ordinal("-го")).unwrap()
+        );
+        assert_ne!(
+            from_str::<OwnedMarker>(r#"{"Ordinal":"th"}"#).unwrap(),
+            MorphologicalMarker::Fraction("th")
+        );
+    }
+
+    #[test]
+    fn test_text2digits_errors() {
+        let en = Language::english();
+        let error = text2digits("one hundred twenty twelve", &en).unwrap_err();
+        assert_eq!(error.error, Error::Overlap);
+        assert_eq!(error.index, 3);
+        assert_eq!(error.word.as_deref(), Some("twelve"));
+        assert_eq!(error.state.to_string(), "120");
+        assert_eq!(
+            error.to_string(),
+            "overlaps the number being decoded at word 3 \"twelve\" (decoded so far: 120)"
+        );
+        let error = text2digits("twenty apples", &en).unwrap_err();
+        assert_eq!(error.error, Error::NaN);
+        assert_eq!(error.word.as_deref(), Some("apples"));
+        for blank in ["", "  "] {
+            let error = text2digits(blank, &en).unwrap_err();
+            assert_eq!(error.error, Error::Incomplete);
+            assert_eq!(error.index, 0);
+            assert_eq!(error.word, None);
+        }
+
+        fn check(text: &str) -> Result<String, Box<dyn std::error::Error>> {
+            let digits = text2digits(text, &Language::english())?;
+            Ok(digits)
+        }
+        assert_eq!(check("forty-two").unwrap(), "42");
+        let error = check("Pizza").unwrap_err();
+        assert_eq!(error.to_string(), "not a number at word 0 \"pizza\"");
+        assert_eq!(error.source().unwrap().to_string(), "not a number");
+    }
+
+    #[test]
+    fn test_trace() {
+        let en = Language::english();
+        let (text, trace) = trace_numbers_in_text(
+            "two apples, one, two, three point five",
+            &en,
+            ConversionOptions::new().threshold(10.0),
+        );
+        assert_eq!(text, "two apples, 1, 2, 3.5");
+        let decisions: Vec<_> = trace
+            .events()
+            .iter()
+            .filter_map(|event| match event {
+                TraceEvent::Number { text, decision, .. } => Some((text.as_str(), *decision)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            decisions,
+            [
+                ("2", Decision::OnHold),
+                ("1", Decision::OnHold),
+                ("2", Decision::IsolatedDropped),
+                ("1", Decision::Released),
+                ("2", Decision::Kept),
+                ("3.5", Decision::Kept),
+            ]
+        );
+        assert!(trace.events().contains(&TraceEvent::DecimalSeparator {
+            position: 10,
+            word: "point".to_owned(),
+            separator: '.',
+        }));
+        assert!(trace.events().contains(&TraceEvent::Apply {
+            position: 12,
+            word: "five".to_owned(),
+            decimal: true,
+            before: String::new(),
+            after: "5".to_owned(),
+            result: None,
+        }));
+        assert!(trace.events().contains(&TraceEvent::SequenceBreaker {
+            position: 2,
+            word: "apples".to_owned(),
+        }));
+
+        let mut numbers = find_numbers_iter(tokenize("the first and a half"), &en, 0.0).traced();
+        assert_eq!(numbers.by_ref().count(), 1);
+        let trace = numbers.trace().unwrap();
+        assert!(
+            !trace
+                .events()
+                .iter()
+                .any(|event| matches!(event, TraceEvent::FractionLink { .. }))
+        );
+        assert!(
+            find_numbers_iter(tokenize("one"), &en, 0.0)
+                .trace()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_kinds() {
+        let fr = Language::french();
+        let ocs = find_numbers(
+            tokenize(
+                "vingt-deux, zéro, vingt-deuxième, zéro virgule cinq, trois quarts, zéro zéro trente",
+            ),
+            &fr,
+            ConversionOptions::from(10.0).convert_fractions(true),
+        );
+        let kinds: Vec<OccurenceKind> = ocs.iter().map(|occurence| occurence.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                OccurenceKind::Cardinal,
+                OccurenceKind::Cardinal,
+                OccurenceKind::Ordinal,
+                OccurenceKind::Decimal,
+                OccurenceKind::Fraction,
+                OccurenceKind::DigitSequence
+            ]
+        );
+        let es = Language::spanish();
+        let ocs = find_numbers(tokenize("doceavo"), &es, 0.0);
+        assert_eq!(ocs[0].kind, OccurenceKind::Fraction);
+    }
+
+    #[test]
+    fn test_replace_occurence() {
+        struct Annotated {
+            text: String,
+            kind: Option<OccurenceKind>,
+        }
+
+        impl Token for &Annotated {
+            fn text(&self) -> &str {
+                &self.text
+            }
+
+            fn text_lowercase(&self) -> &str {
+                &self.text
+            }
+        }
+
+        impl Replace for Annotated {
+            fn replace<I: Iterator<Item = Self>>(_replaced: I, text: String) -> Self {
+                Self { text, kind: None }
+            }
+
+            fn replace_occurence<I: Iterator<Item = Self>>(
+                _replaced: I,
+                occurence: &Occurence,
+            ) -> Self {
+                Self {
+                    text: occurence.text.clone(),
+                    kind: Some(occurence.kind),
+                }
+            }
+        }
+
+        let en = Language::english();
+        let tokens = ["call", "zero", "nine", "at", "five", "point", "five"]
+            .into_iter()
+            .map(|text| Annotated {
+                text: text.to_owned(),
+                kind: None,
+            })
+            .collect();
+        let replaced = replace_numbers_in_stream(tokens, &en, 10.0);
+        assert_eq!(replaced.len(), 4);
+        assert_eq!(replaced[1].text, "09");
+        assert_eq!(replaced[1].kind, Some(OccurenceKind::DigitSequence));
+        assert_eq!(replaced[3].text, "5.5");
+        assert_eq!(replaced[3].kind, Some(OccurenceKind::Decimal));
+    }
+
+    #[test]
+    fn test_exact_value() {
+        let en = Language::english();
+        let ocs = find_numbers(
+            tokenize(
+                "fifty-three billion two hundred forty-three million seven hundred twenty-four thousand one hundred eleven point zero zero one nine",
+            ),
+            &en,
+            10.0,
+        );
+        assert_eq!(ocs.len(), 1);
+        let exact = ocs[0].exact_value.as_ref().unwrap();
+        assert_eq!(exact.to_string(), "53243724111.0019");
+        assert_eq!(exact.scale(), 4);
+        let ocs = find_numbers(tokenize("zéro zéro trente"), &Language::french(), 10.0);
+        assert_eq!(ocs[0].text, "0030");
+        assert_eq!(ocs[0].exact_value.as_ref().unwrap().to_i128(), Some(30));
+        let ocs = find_numbers(tokenize("ciento veintiochoavos"), &Language::spanish(), 0.0);
+        assert_eq!(ocs[0].text, "1/128");
+        assert_eq!(
+            ocs[0].exact_value.as_ref().unwrap().to_string(),
+            "0.0078125"
+        );
+        let ocs = find_numbers(tokenize("doceavo"), &Language::spanish(), 0.0);
+        assert_eq!(ocs[0].text, "1/12");
+        assert!(ocs[0].exact_value.is_none());
+    }
+
+    #[test]
+    fn test_find_in_text_spans() {
+        let fr = Language::french();
+        let text = "À l'étape numéro neuf, zéro virgule cinq élève sur vingt-deux.";
+        let ocs = find_numbers_in_text(text, &fr, 10.0);
+        dbg!(&ocs);
+        assert_eq!(ocs.len(), 3);
+        assert_eq!(ocs[0].text, "9");
+        assert_eq!(&text[ocs[0].byte_start..ocs[0].byte_end], "neuf");
+        assert_eq!((ocs[0].char_start, ocs[0].char_end), (17, 21));
+        assert_eq!(ocs[1].text, "0,5");
+        assert_eq!(
+            &text[ocs[1].byte_start..ocs[1].byte_end],
+            "zéro virgule cinq"
+        );
+        assert_eq!((ocs[1].char_start, ocs[1].char_end), (23, 40));
+        assert_eq!(ocs[2].text, "22");
+        assert_eq!(&text[ocs[2].byte_start..ocs[2].byte_end], "vingt-deux");
+        assert_eq!((ocs[2].char_start, ocs[2].char_end), (51, 61));
+    }
+
     #[test]
     fn bench() {
         let fr = Language::french();